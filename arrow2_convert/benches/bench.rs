@@ -25,6 +25,40 @@ pub struct VecU8Struct(Vec<u8>);
 #[arrow_field(transparent)]
 pub struct VecU32Struct(Vec<u32>);
 
+// `bytes::Bytes` is deserialized by sharing the buffer of the `arrow2::array::BinaryArray`
+#[cfg(feature = "bytes")]
+#[derive(ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[arrow_field(transparent)]
+pub struct BytesStruct(bytes::Bytes);
+
+// Arrow stores structs as `arrow2::array::StructArray`
+#[derive(ArrowField, ArrowSerialize, ArrowDeserialize)]
+pub struct Row {
+    id: u64,
+    name: String,
+    score: Option<f64>,
+    tags: Vec<u32>,
+}
+
+pub fn bench_struct_serialize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("serialize");
+    let size = 100_000;
+    group.throughput(Throughput::Elements(size as u64));
+    group.bench_with_input(BenchmarkId::new("Struct", size), &size, |b, &size| {
+        let data: Vec<Row> = (0..size as u64)
+            .map(|i| Row {
+                id: i,
+                name: format!("row {i}"),
+                score: (i % 3 != 0).then_some(i as f64),
+                tags: (0..(i % 4) as u32).collect(),
+            })
+            .collect();
+        b.iter(|| {
+            let _: Box<dyn Array> = TryIntoArrow::try_into_arrow(black_box(&data)).unwrap();
+        });
+    });
+}
+
 pub fn bench_buffer_serialize(c: &mut Criterion) {
     let mut group = c.benchmark_group("serialize");
     for size in [1, 10, 100, 1000, 10000].iter() {
@@ -85,6 +119,20 @@ pub fn bench_buffer_deserialize(c: &mut Criterion) {
                 criterion::BatchSize::SmallInput,
             );
         });
+        #[cfg(feature = "bytes")]
+        group.bench_with_input(BenchmarkId::new("Bytes", size), size, |b, &size| {
+            let data: Box<dyn Array> = [BytesStruct((0..size as u8).collect())]
+                .try_into_arrow()
+                .unwrap();
+            b.iter_batched(
+                || data.clone(),
+                |data| {
+                    let _: Vec<BytesStruct> =
+                        TryIntoCollection::try_into_collection(black_box(data)).unwrap();
+                },
+                criterion::BatchSize::SmallInput,
+            );
+        });
         group.bench_with_input(BenchmarkId::new("BufferU32", size), size, |b, &size| {
             let data: Box<dyn Array> = [BufU32Struct((0..size as u32).into_iter().collect())]
                 .try_into_arrow()
@@ -114,5 +162,10 @@ pub fn bench_buffer_deserialize(c: &mut Criterion) {
     }
 }
 
-criterion_group!(benches, bench_buffer_serialize, bench_buffer_deserialize);
+criterion_group!(
+    benches,
+    bench_buffer_serialize,
+    bench_struct_serialize,
+    bench_buffer_deserialize
+);
 criterion_main!(benches);
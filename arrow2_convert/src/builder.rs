@@ -0,0 +1,73 @@
+//! Assembling struct arrays from columns when there is no Rust struct describing the rows.
+
+use arrow2::{
+    array::{Array, StructArray},
+    chunk::Chunk,
+    datatypes::{DataType, Field, Schema},
+};
+
+use crate::error::{Error, Result};
+
+/// Builds a `StructArray` or a `Chunk` from named columns of equal length, for schemas that are only known at
+/// runtime. This is the dynamic counterpart of deriving `ArrowSerialize` on a struct.
+#[derive(Debug, Default)]
+pub struct StructBuilder {
+    fields: Vec<Field>,
+    columns: Vec<Box<dyn Array>>,
+}
+
+impl StructBuilder {
+    /// Creates a builder without any columns
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a column named `name`. The field is nullable if the column contains any nulls.
+    pub fn column(self, name: &str, array: Box<dyn Array>) -> Self {
+        let field = Field::new(name, array.data_type().clone(), array.null_count() > 0);
+        self.field(field, array)
+    }
+
+    /// Adds a column described by `field`, for example to declare a column without nulls as nullable.
+    pub fn field(mut self, field: Field, array: Box<dyn Array>) -> Self {
+        self.fields.push(field);
+        self.columns.push(array);
+        self
+    }
+
+    fn validate(&self) -> Result<()> {
+        let Some(first) = self.columns.first() else {
+            return Err(Error::InvalidArgument(
+                "A struct requires at least one column".to_string(),
+            ));
+        };
+        for (field, column) in self.fields.iter().zip(&self.columns) {
+            if column.len() != first.len() {
+                return Err(Error::InvalidArgument(format!(
+                    "Column `{}` has {} rows, expected {} rows like column `{}`",
+                    field.name,
+                    column.len(),
+                    first.len(),
+                    self.fields[0].name
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Builds a `StructArray` with one field per column, in the order they were added.
+    pub fn build(self) -> Result<StructArray> {
+        self.validate()?;
+        Ok(StructArray::try_new(
+            DataType::Struct(self.fields),
+            self.columns,
+            None,
+        )?)
+    }
+
+    /// Builds a `Chunk` of the columns along with the `Schema` describing them, for writing to IPC or parquet.
+    pub fn build_chunk(self) -> Result<(Chunk<Box<dyn Array>>, Schema)> {
+        self.validate()?;
+        Ok((Chunk::try_new(self.columns)?, Schema::from(self.fields)))
+    }
+}
@@ -0,0 +1,90 @@
+//! Conversions between arrow layouts that don't require deserializing the values.
+
+use arrow2::{
+    array::{growable::make_growable, Array, UnionArray},
+    buffer::Buffer,
+    datatypes::{DataType, UnionMode},
+};
+
+use crate::error::{Error, Result};
+
+/// Returns `data_type` with the mode of its union set to `mode`, keeping any extension type around it.
+fn with_union_mode(data_type: &DataType, mode: UnionMode) -> Result<DataType> {
+    match data_type {
+        DataType::Union(fields, ids, _) => Ok(DataType::Union(fields.clone(), ids.clone(), mode)),
+        DataType::Extension(name, inner, metadata) => Ok(DataType::Extension(
+            name.clone(),
+            Box::new(with_union_mode(inner, mode)?),
+            metadata.clone(),
+        )),
+        data_type => Err(Error::InvalidArgument(format!(
+            "Expected a union data type, found {data_type:?}"
+        ))),
+    }
+}
+
+/// Converts a sparse `UnionArray` into a dense one, keeping only the value of the selected field in each row.
+pub fn sparse_to_dense(array: &UnionArray) -> Result<UnionArray> {
+    if array.offsets().is_some() {
+        return Err(Error::InvalidArgument(
+            "Expected a sparse union array, found a dense one".to_string(),
+        ));
+    }
+    let data_type = with_union_mode(array.data_type(), UnionMode::Dense)?;
+
+    let mut growables = array
+        .fields()
+        .iter()
+        .map(|field| make_growable(&[field.as_ref()], false, 0))
+        .collect::<Vec<_>>();
+    let mut offsets = Vec::with_capacity(array.len());
+    for row in 0..array.len() {
+        let (field, slot) = array.index(row);
+        let growable = &mut growables[field];
+        offsets.push(growable.len() as i32);
+        growable.extend(0, slot, 1);
+    }
+    let fields = growables.iter_mut().map(|g| g.as_box()).collect();
+
+    Ok(UnionArray::try_new(
+        data_type,
+        array.types().clone(),
+        fields,
+        Some(Buffer::from(offsets)),
+    )?)
+}
+
+/// Converts a dense `UnionArray` into a sparse one. Each field is padded with nulls in the rows where it
+/// isn't selected.
+pub fn dense_to_sparse(array: &UnionArray) -> Result<UnionArray> {
+    if array.offsets().is_none() {
+        return Err(Error::InvalidArgument(
+            "Expected a dense union array, found a sparse one".to_string(),
+        ));
+    }
+    let data_type = with_union_mode(array.data_type(), UnionMode::Sparse)?;
+
+    let mut growables = array
+        .fields()
+        .iter()
+        .map(|field| make_growable(&[field.as_ref()], true, array.len()))
+        .collect::<Vec<_>>();
+    for row in 0..array.len() {
+        let (field, slot) = array.index(row);
+        for (i, growable) in growables.iter_mut().enumerate() {
+            if i == field {
+                growable.extend(0, slot, 1);
+            } else {
+                growable.extend_validity(1);
+            }
+        }
+    }
+    let fields = growables.iter_mut().map(|g| g.as_box()).collect();
+
+    Ok(UnionArray::try_new(
+        data_type,
+        array.types().clone(),
+        fields,
+        None,
+    )?)
+}
@@ -2,7 +2,12 @@
 
 use arrow2::{array::*, buffer::Buffer, types::NativeType};
 use chrono::{NaiveDate, NaiveDateTime};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::sync::Arc;
 
+use crate::error::{Error, Result};
 use crate::field::*;
 
 /// Implemented by [`ArrowField`] that can be deserialized from arrow
@@ -32,6 +37,45 @@ where
     ) -> <Self as ArrowField>::Type {
         Self::arrow_deserialize(v).unwrap()
     }
+
+    #[inline]
+    #[doc(hidden)]
+    /// For internal use only
+    ///
+    /// Returns the path and data types of the first mismatch between the data type of this field and
+    /// `found`, or `None` if `found` can be deserialized. Reimplemented by structs that match fields by name.
+    fn data_type_mismatch(found: &arrow2::datatypes::DataType) -> Option<DataTypeMismatch> {
+        find_data_type_mismatch(&<Self as ArrowField>::data_type(), found)
+    }
+
+    #[inline]
+    #[doc(hidden)]
+    /// For internal use only
+    ///
+    /// Checks the values of `array`, whose data type was already checked, for rows that can't be deserialized,
+    /// for example lists that don't fit in an `ArrayVec`. Reimplemented by containers to check their children,
+    /// and by derived types to check the physical type of their arrays before `iter_from_array_ref` downcasts them.
+    fn validate_array(_array: &dyn Array) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Implemented by enums that can be deserialized from an arrow union.
+///
+/// The derive macro generates implementations for enums.
+pub trait ArrowDeserializeUnion: ArrowField<Type = Self> + Sized {
+    /// Checks that the payloads of a union array can be deserialized into the variants, returning an
+    /// error that names the variant otherwise. This is done once per array, before deserializing its rows.
+    fn validate_union(arr: &UnionArray) -> Result<()>;
+
+    /// Deserialize the row at `index` of a union array, returning an error that names the
+    /// variant and row if the payload can't be deserialized. The array must have been checked with
+    /// [`ArrowDeserializeUnion::validate_union`].
+    fn try_deserialize_union_row(arr: &UnionArray, index: usize) -> Result<Self>;
+
+    /// Same as [`ArrowDeserializeUnion::try_deserialize_union_row`], except that a null payload is read as a
+    /// null row, which is how `None` rows are serialized.
+    fn try_deserialize_nullable_union_row(arr: &UnionArray, index: usize) -> Result<Option<Self>>;
 }
 
 /// Internal trait used to support deserialization and iteration of structs, and nested struct lists
@@ -104,6 +148,50 @@ where
     ) -> <Self as ArrowField>::Type {
         <T as ArrowDeserialize>::arrow_deserialize(v)
     }
+
+    #[inline]
+    fn data_type_mismatch(found: &arrow2::datatypes::DataType) -> Option<DataTypeMismatch> {
+        <T as ArrowDeserialize>::data_type_mismatch(found)
+    }
+
+    #[inline]
+    fn validate_array(array: &dyn Array) -> Result<()> {
+        <T as ArrowDeserialize>::validate_array(array)
+    }
+}
+
+// blanket implementation for boxed fields
+impl<T> ArrowDeserialize for Box<T>
+where
+    T: ArrowDeserialize,
+    T::ArrayType: 'static + ArrowArray,
+    for<'a> &'a T::ArrayType: IntoIterator,
+{
+    type ArrayType = <T as ArrowDeserialize>::ArrayType;
+
+    #[inline]
+    fn arrow_deserialize(
+        v: <&Self::ArrayType as IntoIterator>::Item,
+    ) -> Option<<Self as ArrowField>::Type> {
+        <T as ArrowDeserialize>::arrow_deserialize(v).map(Box::new)
+    }
+
+    #[inline]
+    fn arrow_deserialize_internal(
+        v: <&Self::ArrayType as IntoIterator>::Item,
+    ) -> <Self as ArrowField>::Type {
+        Box::new(<T as ArrowDeserialize>::arrow_deserialize_internal(v))
+    }
+
+    #[inline]
+    fn data_type_mismatch(found: &arrow2::datatypes::DataType) -> Option<DataTypeMismatch> {
+        <T as ArrowDeserialize>::data_type_mismatch(found)
+    }
+
+    #[inline]
+    fn validate_array(array: &dyn Array) -> Result<()> {
+        <T as ArrowDeserialize>::validate_array(array)
+    }
 }
 
 impl_arrow_deserialize_primitive!(u8);
@@ -117,6 +205,7 @@ impl_arrow_deserialize_primitive!(i64);
 impl_arrow_deserialize_primitive!(arrow2::types::f16);
 impl_arrow_deserialize_primitive!(f32);
 impl_arrow_deserialize_primitive!(f64);
+impl_arrow_deserialize_primitive!(arrow2::types::i256);
 
 impl<const PRECISION: usize, const SCALE: usize> ArrowDeserialize for I128<PRECISION, SCALE> {
     type ArrayType = PrimitiveArray<i128>;
@@ -129,6 +218,37 @@ impl<const PRECISION: usize, const SCALE: usize> ArrowDeserialize for I128<PRECI
 
 impl_arrow_array!(PrimitiveArray<i128>);
 
+impl<const PRECISION: usize, const SCALE: usize> ArrowDeserialize
+    for DecimalBinary<PRECISION, SCALE>
+{
+    type ArrayType = FixedSizeBinaryArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<&[u8]>) -> Option<i128> {
+        v.and_then(|t| <[u8; 16]>::try_from(t).ok())
+            .map(i128::from_be_bytes)
+    }
+}
+
+#[cfg(feature = "bigdecimal")]
+impl<const PRECISION: usize, const SCALE: usize> ArrowDeserialize for Decimal256<PRECISION, SCALE> {
+    type ArrayType = PrimitiveArray<arrow2::types::i256>;
+
+    #[inline]
+    fn arrow_deserialize<'a>(v: Option<&arrow2::types::i256>) -> Option<bigdecimal::BigDecimal> {
+        v.map(|t| {
+            let (hi, lo) = t.0.into_words();
+            let mut bytes = [0; 32];
+            bytes[..16].copy_from_slice(&lo.to_le_bytes());
+            bytes[16..].copy_from_slice(&hi.to_le_bytes());
+            bigdecimal::BigDecimal::new(
+                bigdecimal::num_bigint::BigInt::from_signed_bytes_le(&bytes),
+                SCALE as i64,
+            )
+        })
+    }
+}
+
 impl ArrowDeserialize for String {
     type ArrayType = Utf8Array<i32>;
 
@@ -138,6 +258,15 @@ impl ArrowDeserialize for String {
     }
 }
 
+impl ArrowDeserialize for Arc<str> {
+    type ArrayType = Utf8Array<i32>;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<&str>) -> Option<Self> {
+        v.map(Arc::from)
+    }
+}
+
 impl ArrowDeserialize for LargeString {
     type ArrayType = Utf8Array<i64>;
 
@@ -147,6 +276,43 @@ impl ArrowDeserialize for LargeString {
     }
 }
 
+// Returns the only character of `s`, or `None` if it's empty or has more than one
+fn single_char(s: &str) -> Option<char> {
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Some(c),
+        _ => None,
+    }
+}
+
+impl ArrowDeserialize for char {
+    type ArrayType = Utf8Array<i32>;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<&str>) -> Option<Self> {
+        v.map(|t| {
+            single_char(t).unwrap_or_else(|| panic!("Expected a single character, found {t:?}"))
+        })
+    }
+
+    fn validate_array(array: &dyn Array) -> Result<()> {
+        let values = array
+            .as_any()
+            .downcast_ref::<Utf8Array<i32>>()
+            .ok_or_else(|| Error::Deserialize("Expected a utf8 array".to_string()))?;
+        match values
+            .iter()
+            .enumerate()
+            .find_map(|(index, v)| v.filter(|v| single_char(v).is_none()).map(|v| (index, v)))
+        {
+            Some((index, v)) => Err(Error::Deserialize(format!(
+                "Expected a single character at row {index}, found {v:?}"
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
 impl ArrowDeserialize for bool {
     type ArrayType = BooleanArray;
 
@@ -165,6 +331,24 @@ impl ArrowDeserialize for NaiveDateTime {
     }
 }
 
+impl ArrowDeserialize for chrono::Duration {
+    type ArrayType = PrimitiveArray<i64>;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<&i64>) -> Option<Self> {
+        v.map(|t| chrono::Duration::nanoseconds(*t))
+    }
+}
+
+impl<U: TimestampUnit> ArrowDeserialize for Timestamp<U> {
+    type ArrayType = PrimitiveArray<i64>;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<&i64>) -> Option<NaiveDateTime> {
+        v.map(|t| arrow2::temporal_conversions::timestamp_to_naive_datetime(*t, U::UNIT))
+    }
+}
+
 impl ArrowDeserialize for NaiveDate {
     type ArrayType = PrimitiveArray<i32>;
 
@@ -174,6 +358,121 @@ impl ArrowDeserialize for NaiveDate {
     }
 }
 
+impl ArrowDeserialize for Date64 {
+    type ArrayType = PrimitiveArray<i64>;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<&i64>) -> Option<NaiveDate> {
+        v.map(|t| arrow2::temporal_conversions::date64_to_date(*t))
+    }
+}
+
+impl ArrowDeserialize for IntervalMonthDayNano {
+    type ArrayType = PrimitiveArray<arrow2::types::months_days_ns>;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<&arrow2::types::months_days_ns>) -> Option<Self> {
+        v.map(|t| IntervalMonthDayNano {
+            months: t.months(),
+            days: t.days(),
+            nanos: t.ns(),
+        })
+    }
+}
+
+impl_arrow_array!(PrimitiveArray<arrow2::types::months_days_ns>);
+
+/// Iterator for [`NullColumnArray`]
+pub struct NullColumnArrayIter {
+    index: usize,
+    len: usize,
+}
+
+impl Iterator for NullColumnArrayIter {
+    type Item = Option<()>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            None
+        } else {
+            self.index += 1;
+            Some(None)
+        }
+    }
+}
+
+/// Internal `ArrowArray` helper to iterate over a `NullArray`
+pub struct NullColumnArray;
+
+impl IntoIterator for &NullColumnArray {
+    type Item = Option<()>;
+
+    type IntoIter = NullColumnArrayIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        unimplemented!("Use iter_from_array_ref");
+    }
+}
+
+impl ArrowArray for NullColumnArray {
+    type BaseArrayType = NullArray;
+    #[inline]
+    fn iter_from_array_ref(a: &dyn Array) -> <&Self as IntoIterator>::IntoIter {
+        NullColumnArrayIter {
+            index: 0,
+            len: a.len(),
+        }
+    }
+}
+
+impl ArrowDeserialize for NullColumn {
+    type ArrayType = NullColumnArray;
+
+    // every value is null, so this can't distinguish a missing value
+    #[inline]
+    fn arrow_deserialize(_: Option<()>) -> Option<Self> {
+        Some(NullColumn)
+    }
+}
+
+impl ArrowDeserialize for NaiveDateAsDays {
+    type ArrayType = PrimitiveArray<i32>;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<&i32>) -> Option<NaiveDate> {
+        <NaiveDate as ArrowDeserialize>::arrow_deserialize(v)
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl<T> ArrowDeserialize for Json<T>
+where
+    T: serde::de::DeserializeOwned + 'static,
+{
+    type ArrayType = Utf8Array<i32>;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<&str>) -> Option<T> {
+        v.map(|t| serde_json::from_str(t).unwrap_or_else(|e| panic!("Invalid JSON: {e}")))
+    }
+
+    // The syntax of the values is checked up front, so that malformed JSON is reported as an error instead of
+    // a panic. Only `arrow_deserialize` builds the values, so a value that doesn't match `T` still panics.
+    fn validate_array(array: &dyn Array) -> Result<()> {
+        let values = array
+            .as_any()
+            .downcast_ref::<Utf8Array<i32>>()
+            .ok_or_else(|| Error::Deserialize("Expected a utf8 array".to_string()))?;
+        for (index, value) in values.iter().enumerate() {
+            if let Some(value) = value {
+                serde_json::from_str::<serde::de::IgnoredAny>(value)
+                    .map_err(|e| Error::Deserialize(format!("Invalid JSON at row {index}: {e}")))?;
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Iterator for for [`BufferBinaryArray`]
 pub struct BufferBinaryArrayIter<'a> {
     index: usize,
@@ -232,6 +531,70 @@ impl ArrowDeserialize for Buffer<u8> {
     }
 }
 
+#[cfg(feature = "ordered-float")]
+impl<T> ArrowDeserialize for ordered_float::OrderedFloat<T>
+where
+    T: ArrowDeserialize + ArrowField<Type = T> + ordered_float::FloatCore,
+    T::ArrayType: ArrowArray,
+    for<'a> &'a T::ArrayType: IntoIterator,
+{
+    type ArrayType = <T as ArrowDeserialize>::ArrayType;
+
+    #[inline]
+    fn arrow_deserialize(v: <&Self::ArrayType as IntoIterator>::Item) -> Option<Self> {
+        <T as ArrowDeserialize>::arrow_deserialize(v).map(ordered_float::OrderedFloat)
+    }
+}
+
+#[cfg(feature = "glam")]
+macro_rules! impl_glam_deserialize {
+    ($t:ty, $from_array:ident) => {
+        impl ArrowDeserialize for $t {
+            type ArrayType = FixedSizeListArray;
+
+            #[inline]
+            fn arrow_deserialize(v: Option<Box<dyn Array>>) -> Option<Self> {
+                v.map(|t| {
+                    let values = t
+                        .as_any()
+                        .downcast_ref::<PrimitiveArray<f32>>()
+                        .unwrap()
+                        .values();
+                    <$t>::$from_array(values.as_slice().try_into().unwrap())
+                })
+            }
+        }
+    };
+}
+
+#[cfg(feature = "glam")]
+impl_glam_deserialize!(glam::Vec3, from_array);
+#[cfg(feature = "glam")]
+impl_glam_deserialize!(glam::Vec4, from_array);
+#[cfg(feature = "glam")]
+impl_glam_deserialize!(glam::Mat4, from_cols_array);
+
+/// Owner of an arrow buffer slice, used to construct a [`bytes::Bytes`] without copying.
+#[cfg(feature = "bytes")]
+struct BufferOwner(Buffer<u8>);
+
+#[cfg(feature = "bytes")]
+impl AsRef<[u8]> for BufferOwner {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl ArrowDeserialize for bytes::Bytes {
+    type ArrayType = BufferBinaryArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<Buffer<u8>>) -> Option<Self> {
+        v.map(|t| bytes::Bytes::from_owner(BufferOwner(t)))
+    }
+}
+
 impl ArrowDeserialize for Vec<u8> {
     type ArrayType = BinaryArray<i32>;
 
@@ -241,6 +604,25 @@ impl ArrowDeserialize for Vec<u8> {
     }
 }
 
+impl ArrowDeserialize for Arc<[u8]> {
+    type ArrayType = BinaryArray<i32>;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<&[u8]>) -> Option<Self> {
+        v.map(Arc::from)
+    }
+}
+
+impl ArrowDeserialize for U8List {
+    type ArrayType = ListArray<i32>;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<Box<dyn Array>>) -> Option<Vec<u8>> {
+        use std::ops::Deref;
+        v.map(|t| arrow_array_deserialize_iterator_internal::<u8, u8>(t.deref()).collect())
+    }
+}
+
 impl ArrowDeserialize for LargeBinary {
     type ArrayType = BinaryArray<i64>;
 
@@ -259,91 +641,557 @@ impl<const SIZE: usize> ArrowDeserialize for FixedSizeBinary<SIZE> {
     }
 }
 
-fn arrow_deserialize_vec_helper<T>(
-    v: Option<Box<dyn Array>>,
-) -> Option<<Vec<T> as ArrowField>::Type>
-where
-    T: ArrowDeserialize + ArrowEnableVecForType + 'static,
-    for<'a> &'a T::ArrayType: IntoIterator,
-{
-    use std::ops::Deref;
-    v.map(|t| {
-        arrow_array_deserialize_iterator_internal::<<T as ArrowField>::Type, T>(t.deref())
-            .collect::<Vec<<T as ArrowField>::Type>>()
-    })
+impl ArrowDeserialize for Ipv4Addr {
+    type ArrayType = FixedSizeBinaryArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<&[u8]>) -> Option<Self> {
+        v.and_then(|t| <[u8; 4]>::try_from(t).ok())
+            .map(Ipv4Addr::from)
+    }
 }
 
-// Blanket implementation for Buffer
-impl<T> ArrowDeserialize for Buffer<T>
-where
-    T: ArrowDeserialize + NativeType + ArrowEnableVecForType,
-    for<'b> &'b <T as ArrowDeserialize>::ArrayType: IntoIterator,
-{
-    type ArrayType = ListArray<i32>;
+impl ArrowDeserialize for Ipv6Addr {
+    type ArrayType = FixedSizeBinaryArray;
 
     #[inline]
-    fn arrow_deserialize(
-        v: <&Self::ArrayType as IntoIterator>::Item,
-    ) -> Option<<Self as ArrowField>::Type> {
-        v.map(|t| {
-            t.as_any()
-                .downcast_ref::<PrimitiveArray<T>>()
-                .unwrap()
-                .values()
-                .clone()
-        })
+    fn arrow_deserialize(v: Option<&[u8]>) -> Option<Self> {
+        v.and_then(|t| <[u8; 16]>::try_from(t).ok())
+            .map(Ipv6Addr::from)
     }
 }
 
-// Blanket implementation for Vec
-impl<T> ArrowDeserialize for Vec<T>
-where
-    T: ArrowDeserialize + ArrowEnableVecForType + 'static,
-    <T as ArrowDeserialize>::ArrayType: 'static,
-    for<'b> &'b <T as ArrowDeserialize>::ArrayType: IntoIterator,
-{
-    type ArrayType = ListArray<i32>;
+/// A row of a dense [`UnionArray`] with two variants, holding the deserialized value of the variant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DenseUnionValue<A, B> {
+    /// A row of the first variant
+    First(A),
+    /// A row of the second variant
+    Second(B),
+}
 
-    fn arrow_deserialize(v: Option<Box<dyn Array>>) -> Option<<Self as ArrowField>::Type> {
-        arrow_deserialize_vec_helper::<T>(v)
-    }
+// Deserializes the values of the array of a union variant
+struct DenseUnionChild<'a, T> {
+    array: &'a dyn Array,
+    iter: Box<dyn Iterator<Item = Option<T>> + 'a>,
+    position: usize,
+    new_iter: fn(&'a dyn Array) -> Box<dyn Iterator<Item = Option<T>> + 'a>,
 }
 
-impl<T> ArrowDeserialize for LargeVec<T>
-where
-    T: ArrowDeserialize + ArrowEnableVecForType + 'static,
-    <T as ArrowDeserialize>::ArrayType: 'static,
-    for<'b> &'b <T as ArrowDeserialize>::ArrayType: IntoIterator,
-{
-    type ArrayType = ListArray<i64>;
+impl<'a, T> DenseUnionChild<'a, T> {
+    fn new(
+        array: &'a dyn Array,
+        new_iter: fn(&'a dyn Array) -> Box<dyn Iterator<Item = Option<T>> + 'a>,
+    ) -> Self {
+        Self {
+            array,
+            iter: new_iter(array),
+            position: 0,
+            new_iter,
+        }
+    }
 
-    fn arrow_deserialize(v: Option<Box<dyn Array>>) -> Option<<Self as ArrowField>::Type> {
-        arrow_deserialize_vec_helper::<T>(v)
+    // The rows of each variant are normally stored in order, so the iterator only moves forward, and is
+    // only restarted for an offset before its position
+    fn get(&mut self, offset: usize) -> Option<T> {
+        if offset < self.position {
+            self.iter = (self.new_iter)(self.array);
+            self.position = 0;
+        }
+        let item = self.iter.nth(offset - self.position);
+        self.position = offset + 1;
+        item.flatten()
     }
 }
 
-impl<T, const SIZE: usize> ArrowDeserialize for FixedSizeVec<T, SIZE>
+fn deserialize_values<'a, T>(
+    array: &'a dyn Array,
+) -> Box<dyn Iterator<Item = Option<<T as ArrowField>::Type>> + 'a>
 where
-    T: ArrowDeserialize + ArrowEnableVecForType + 'static,
-    <T as ArrowDeserialize>::ArrayType: 'static,
+    T: ArrowDeserialize + 'static,
     for<'b> &'b <T as ArrowDeserialize>::ArrayType: IntoIterator,
 {
-    type ArrayType = FixedSizeListArray;
+    Box::new(
+        <<T as ArrowDeserialize>::ArrayType as ArrowArray>::iter_from_array_ref(array)
+            .map(<T as ArrowDeserialize>::arrow_deserialize),
+    )
+}
 
-    fn arrow_deserialize(v: Option<Box<dyn Array>>) -> Option<<Self as ArrowField>::Type> {
-        arrow_deserialize_vec_helper::<T>(v)
-    }
+/// Iterator for [`DenseUnionArray`]
+pub struct DenseUnionArrayIter<'a, A, B> {
+    index: usize,
+    array: &'a UnionArray,
+    first: DenseUnionChild<'a, A>,
+    second: DenseUnionChild<'a, B>,
 }
 
-impl_arrow_array!(BooleanArray);
-impl_arrow_array!(Utf8Array<i32>);
-impl_arrow_array!(Utf8Array<i64>);
+impl<'a, A, B> Iterator for DenseUnionArrayIter<'a, A, B> {
+    type Item = Option<DenseUnionValue<A, B>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.array.len() {
+            return None;
+        }
+        let (field, offset) = self.array.index(self.index);
+        self.index += 1;
+        Some(match field {
+            0 => self.first.get(offset).map(DenseUnionValue::First),
+            _ => self.second.get(offset).map(DenseUnionValue::Second),
+        })
+    }
+}
+
+/// Internal `ArrowArray` helper to iterate over the rows of a dense `UnionArray` with two variants, whose
+/// values are deserialized as `T` and `E`
+pub struct DenseUnionArray<T, E>(std::marker::PhantomData<(T, E)>);
+
+impl<'a, T, E> IntoIterator for &'a DenseUnionArray<T, E>
+where
+    T: ArrowField,
+    E: ArrowField,
+{
+    type Item = Option<DenseUnionValue<<T as ArrowField>::Type, <E as ArrowField>::Type>>;
+
+    type IntoIter = DenseUnionArrayIter<'a, <T as ArrowField>::Type, <E as ArrowField>::Type>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        unimplemented!("Use iter_from_array_ref");
+    }
+}
+
+impl<T, E> ArrowArray for DenseUnionArray<T, E>
+where
+    T: ArrowDeserialize + 'static,
+    E: ArrowDeserialize + 'static,
+    for<'b> &'b <T as ArrowDeserialize>::ArrayType: IntoIterator,
+    for<'b> &'b <E as ArrowDeserialize>::ArrayType: IntoIterator,
+{
+    type BaseArrayType = UnionArray;
+    #[inline]
+    fn iter_from_array_ref(a: &dyn Array) -> <&Self as IntoIterator>::IntoIter {
+        let b = a.as_any().downcast_ref::<Self::BaseArrayType>().unwrap();
+
+        DenseUnionArrayIter {
+            index: 0,
+            array: b,
+            first: DenseUnionChild::new(b.fields()[0].as_ref(), deserialize_values::<T>),
+            second: DenseUnionChild::new(b.fields()[1].as_ref(), deserialize_values::<E>),
+        }
+    }
+}
+
+/// Internal `ArrowArray` helper to iterate over the `UnionArray` of [`IpAddr`] values
+pub type IpAddrArray = DenseUnionArray<Ipv4Addr, Ipv6Addr>;
+
+impl ArrowDeserialize for IpAddr {
+    type ArrayType = IpAddrArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<DenseUnionValue<Ipv4Addr, Ipv6Addr>>) -> Option<Self> {
+        v.map(|v| match v {
+            DenseUnionValue::First(v) => IpAddr::V4(v),
+            DenseUnionValue::Second(v) => IpAddr::V6(v),
+        })
+    }
+}
+
+// Deserializes the single value of a slice of length 1
+fn arrow_deserialize_single_row<T>(slice: &dyn Array) -> Option<<T as ArrowField>::Type>
+where
+    T: ArrowDeserialize + 'static,
+    for<'b> &'b <T as ArrowDeserialize>::ArrayType: IntoIterator,
+{
+    <<T as ArrowDeserialize>::ArrayType as ArrowArray>::iter_from_array_ref(slice)
+        .next()
+        .and_then(<T as ArrowDeserialize>::arrow_deserialize)
+}
+
+impl<T, E> ArrowDeserialize for std::result::Result<T, E>
+where
+    T: ArrowDeserialize + 'static,
+    E: ArrowDeserialize + 'static,
+    for<'b> &'b <T as ArrowDeserialize>::ArrayType: IntoIterator,
+    for<'b> &'b <E as ArrowDeserialize>::ArrayType: IntoIterator,
+{
+    type ArrayType = DenseUnionArray<T, E>;
+
+    #[inline]
+    fn arrow_deserialize(
+        v: Option<DenseUnionValue<<T as ArrowField>::Type, <E as ArrowField>::Type>>,
+    ) -> Option<<Self as ArrowField>::Type> {
+        v.map(|v| match v {
+            DenseUnionValue::First(v) => Ok(v),
+            DenseUnionValue::Second(e) => Err(e),
+        })
+    }
+}
+
+/// Iterator for [`DoubleOptionArray`]
+pub struct DoubleOptionArrayIter<'a> {
+    index: usize,
+    array: &'a StructArray,
+}
+
+impl<'a> Iterator for DoubleOptionArrayIter<'a> {
+    type Item = Option<Box<dyn Array>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.array.len() {
+            return None;
+        }
+        let index = self.index;
+        self.index += 1;
+        Some(
+            self.array
+                .is_valid(index)
+                .then(|| self.array.values()[0].sliced(index, 1)),
+        )
+    }
+}
+
+/// Internal `ArrowArray` helper to iterate over the `StructArray` of [`DoubleOption`] values, as a
+/// slice of length 1 of the `value` field for each valid row
+pub struct DoubleOptionArray;
+
+impl<'a> IntoIterator for &'a DoubleOptionArray {
+    type Item = Option<Box<dyn Array>>;
+
+    type IntoIter = DoubleOptionArrayIter<'a>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        unimplemented!("Use iter_from_array_ref");
+    }
+}
+
+impl ArrowArray for DoubleOptionArray {
+    type BaseArrayType = StructArray;
+    #[inline]
+    fn iter_from_array_ref(a: &dyn Array) -> <&Self as IntoIterator>::IntoIter {
+        let b = a.as_any().downcast_ref::<Self::BaseArrayType>().unwrap();
+
+        DoubleOptionArrayIter { index: 0, array: b }
+    }
+}
+
+impl<T> ArrowDeserialize for DoubleOption<T>
+where
+    T: ArrowDeserialize + 'static,
+    for<'b> &'b <T as ArrowDeserialize>::ArrayType: IntoIterator,
+{
+    type ArrayType = DoubleOptionArray;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<Box<dyn Array>>) -> Option<<Self as ArrowField>::Type> {
+        Some(v.map(|slice| arrow_deserialize_single_row::<T>(slice.as_ref())))
+    }
+}
+
+// Deserializes the values of a single list into a collection
+fn arrow_deserialize_list_values<T, C>(v: Option<Box<dyn Array>>) -> Option<C>
+where
+    T: ArrowDeserialize + ArrowEnableVecForType + 'static,
+    for<'a> &'a T::ArrayType: IntoIterator,
+    C: FromIterator<<T as ArrowField>::Type>,
+{
+    use std::ops::Deref;
+    v.map(|t| {
+        arrow_array_deserialize_iterator_internal::<<T as ArrowField>::Type, T>(t.deref()).collect()
+    })
+}
+
+// Checks the values of a list with the hook of its item type
+fn validate_list_values<T>(array: &dyn Array) -> Result<()>
+where
+    T: ArrowDeserialize,
+    for<'b> &'b <T as ArrowDeserialize>::ArrayType: IntoIterator,
+{
+    let any = array.as_any();
+    let values = if let Some(list) = any.downcast_ref::<ListArray<i32>>() {
+        list.values()
+    } else if let Some(list) = any.downcast_ref::<ListArray<i64>>() {
+        list.values()
+    } else if let Some(list) = any.downcast_ref::<FixedSizeListArray>() {
+        list.values()
+    } else {
+        return Err(Error::Deserialize("Expected a list array".to_string()));
+    };
+    <T as ArrowDeserialize>::validate_array(values.as_ref())
+}
+
+// Checks the keys and values of a map with the hooks of their types
+fn validate_map_entries<K, V>(array: &dyn Array) -> Result<()>
+where
+    K: ArrowDeserialize,
+    V: ArrowDeserialize,
+    for<'b> &'b <K as ArrowDeserialize>::ArrayType: IntoIterator,
+    for<'b> &'b <V as ArrowDeserialize>::ArrayType: IntoIterator,
+{
+    let entries = array
+        .as_any()
+        .downcast_ref::<MapArray>()
+        .and_then(|map| map.field().as_any().downcast_ref::<StructArray>())
+        .ok_or_else(|| Error::Deserialize("Expected a map array".to_string()))?;
+    <K as ArrowDeserialize>::validate_array(entries.values()[0].as_ref())?;
+    <V as ArrowDeserialize>::validate_array(entries.values()[1].as_ref())
+}
+
+// Blanket implementation for Buffer
+impl<T> ArrowDeserialize for Buffer<T>
+where
+    T: ArrowDeserialize + NativeType + ArrowEnableVecForType,
+    for<'b> &'b <T as ArrowDeserialize>::ArrayType: IntoIterator,
+{
+    type ArrayType = ListArray<i32>;
+
+    #[inline]
+    fn arrow_deserialize(
+        v: <&Self::ArrayType as IntoIterator>::Item,
+    ) -> Option<<Self as ArrowField>::Type> {
+        v.map(|t| {
+            t.as_any()
+                .downcast_ref::<PrimitiveArray<T>>()
+                .unwrap()
+                .values()
+                .clone()
+        })
+    }
+}
+
+// Lists are deserialized into any collection of their items. `$validate` checks the list array, for example
+// that its lists fit in the collection.
+macro_rules! impl_arrow_deserialize_list {
+    ($(#[$attr:meta])* [$($generics:tt)*] $collection:ty, $array:ty, $validate:expr $(, where $($bounds:tt)+)?) => {
+        $(#[$attr])*
+        impl<T, $($generics)*> ArrowDeserialize for $collection
+        where
+            T: ArrowDeserialize + ArrowEnableVecForType + 'static,
+            <T as ArrowDeserialize>::ArrayType: 'static,
+            for<'b> &'b <T as ArrowDeserialize>::ArrayType: IntoIterator,
+            $($($bounds)+)?
+        {
+            type ArrayType = $array;
+
+            fn arrow_deserialize(v: Option<Box<dyn Array>>) -> Option<<Self as ArrowField>::Type> {
+                arrow_deserialize_list_values::<T, _>(v)
+            }
+
+            fn validate_array(array: &dyn Array) -> Result<()> {
+                $validate(array)
+            }
+
+            #[inline]
+            fn data_type_mismatch(found: &arrow2::datatypes::DataType) -> Option<DataTypeMismatch> {
+                find_nested_mismatch(
+                    &<Self as ArrowField>::data_type(),
+                    found,
+                    &[<T as ArrowDeserialize>::data_type_mismatch],
+                )
+            }
+        }
+    };
+}
+
+// Lists that are longer than the capacity of the `ArrayVec` are rejected before deserializing
+#[cfg(feature = "arrayvec")]
+fn validate_array_vec<T, const CAP: usize>(array: &dyn Array) -> Result<()>
+where
+    T: ArrowDeserialize,
+    for<'b> &'b <T as ArrowDeserialize>::ArrayType: IntoIterator,
+{
+    let list = array
+        .as_any()
+        .downcast_ref::<ListArray<i32>>()
+        .ok_or_else(|| Error::Deserialize("Expected a list array".to_string()))?;
+    if let Some((index, len)) = list
+        .offsets()
+        .lengths()
+        .enumerate()
+        .find(|(_, len)| *len > CAP)
+    {
+        return Err(Error::Deserialize(format!(
+            "The list of {len} items at row {index} doesn't fit in an `ArrayVec` with a capacity of {CAP}"
+        )));
+    }
+    <T as ArrowDeserialize>::validate_array(list.values().as_ref())
+}
+
+impl_arrow_deserialize_list!([] Vec<T>, ListArray<i32>, validate_list_values::<T>);
+impl_arrow_deserialize_list!([] VecDeque<T>, ListArray<i32>, validate_list_values::<T>);
+impl_arrow_deserialize_list!([] LargeVec<T>, ListArray<i64>, validate_list_values::<T>);
+impl_arrow_deserialize_list!([const SIZE: usize] FixedSizeVec<T, SIZE>, FixedSizeListArray, validate_list_values::<T>);
+// Duplicate values are removed when collecting into a set
+impl_arrow_deserialize_list!([] BTreeSet<T>, ListArray<i32>, validate_list_values::<T>, where <T as ArrowField>::Type: Ord);
+impl_arrow_deserialize_list!([] HashSet<T>, ListArray<i32>, validate_list_values::<T>, where <T as ArrowField>::Type: Hash + Eq);
+impl_arrow_deserialize_list!(#[cfg(feature = "arrayvec")] [const CAP: usize] arrayvec::ArrayVec<T, CAP>, ListArray<i32>, validate_array_vec::<T, CAP>);
+// Lists longer than `N` items spill to the heap
+impl_arrow_deserialize_list!(#[cfg(feature = "smallvec")] [const N: usize] smallvec::SmallVec<[T; N]>, ListArray<i32>, validate_list_values::<T>);
+
+// Deserializes the entries of a single map value, in the order they are stored
+fn arrow_deserialize_map_entries<K, V, C>(v: Box<dyn Array>) -> C
+where
+    K: ArrowDeserialize + 'static,
+    V: ArrowDeserialize + 'static,
+    <K as ArrowDeserialize>::ArrayType: 'static,
+    <V as ArrowDeserialize>::ArrayType: 'static,
+    for<'b> &'b <K as ArrowDeserialize>::ArrayType: IntoIterator,
+    for<'b> &'b <V as ArrowDeserialize>::ArrayType: IntoIterator,
+    C: FromIterator<(<K as ArrowField>::Type, <V as ArrowField>::Type)>,
+{
+    let entries = v.as_any().downcast_ref::<StructArray>().unwrap();
+    let keys = arrow_array_deserialize_iterator_internal::<<K as ArrowField>::Type, K>(
+        entries.values()[0].as_ref(),
+    );
+    let values = arrow_array_deserialize_iterator_internal::<<V as ArrowField>::Type, V>(
+        entries.values()[1].as_ref(),
+    );
+    keys.zip(values).collect()
+}
+
+// Maps are deserialized from their entries, in the order they are stored
+macro_rules! impl_arrow_deserialize_map {
+    ($collection:ty $(, where $($bounds:tt)+)?) => {
+        impl_arrow_deserialize_map!($collection, |entries| entries $(, where $($bounds)+)?);
+    };
+    ($collection:ty, $wrap:expr $(, where $($bounds:tt)+)?) => {
+        impl<K, V> ArrowDeserialize for $collection
+        where
+            K: ArrowDeserialize + 'static,
+            V: ArrowDeserialize + 'static,
+            <K as ArrowDeserialize>::ArrayType: 'static,
+            <V as ArrowDeserialize>::ArrayType: 'static,
+            for<'b> &'b <K as ArrowDeserialize>::ArrayType: IntoIterator,
+            for<'b> &'b <V as ArrowDeserialize>::ArrayType: IntoIterator,
+            $($($bounds)+)?
+        {
+            type ArrayType = MapArray;
+
+            fn arrow_deserialize(v: Option<Box<dyn Array>>) -> Option<<Self as ArrowField>::Type> {
+                v.map(|t| $wrap(arrow_deserialize_map_entries::<K, V, _>(t)))
+            }
+
+            fn validate_array(array: &dyn Array) -> Result<()> {
+                validate_map_entries::<K, V>(array)
+            }
+
+            #[inline]
+            fn data_type_mismatch(found: &arrow2::datatypes::DataType) -> Option<DataTypeMismatch> {
+                find_nested_mismatch(
+                    &<Self as ArrowField>::data_type(),
+                    found,
+                    &[
+                        <K as ArrowDeserialize>::data_type_mismatch,
+                        <V as ArrowDeserialize>::data_type_mismatch,
+                    ],
+                )
+            }
+        }
+    };
+}
+
+impl_arrow_deserialize_map!(HashMap<K, V>, where <K as ArrowField>::Type: Eq + Hash);
+impl_arrow_deserialize_map!(BTreeMap<K, V>, where <K as ArrowField>::Type: Ord);
+impl_arrow_deserialize_map!(MapEntries<K, V>, MapEntries);
+impl_arrow_deserialize_map!(Map<K, V>);
+
+/// Iterator for [`DictionaryFieldArray`], which looks up the value of each key
+pub struct DictionaryFieldArrayIter<'a, K, T>
+where
+    K: DictionaryKey,
+{
+    keys: <&'a PrimitiveArray<K> as IntoIterator>::IntoIter,
+    values: Vec<Option<T>>,
+}
+
+impl<'a, K, T> Iterator for DictionaryFieldArrayIter<'a, K, T>
+where
+    K: DictionaryKey,
+    T: Clone,
+{
+    type Item = Option<T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.keys.next().map(|key| {
+            let index: usize = (*key?).try_into().ok()?;
+            self.values.get(index)?.clone()
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.keys.size_hint()
+    }
+}
+
+/// Internal `ArrowArray` helper to iterate over the values of a `DictionaryArray` with keys of type `K`,
+/// whose values are deserialized as `V`. Each distinct value is only deserialized once.
+pub struct DictionaryFieldArray<K, V> {
+    d: std::marker::PhantomData<(K, V)>,
+}
+
+impl<'a, K, V> IntoIterator for &'a DictionaryFieldArray<K, V>
+where
+    K: DictionaryKey,
+    V: ArrowField,
+    <V as ArrowField>::Type: Clone,
+{
+    type Item = Option<<V as ArrowField>::Type>;
+    type IntoIter = DictionaryFieldArrayIter<'a, K, <V as ArrowField>::Type>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        unimplemented!("Use iter_from_array_ref");
+    }
+}
+
+impl<K, V> ArrowArray for DictionaryFieldArray<K, V>
+where
+    K: DictionaryKey,
+    V: ArrowDeserialize + 'static,
+    <V as ArrowField>::Type: Clone,
+    for<'b> &'b <V as ArrowDeserialize>::ArrayType: IntoIterator,
+{
+    type BaseArrayType = DictionaryArray<K>;
+
+    #[inline]
+    fn iter_from_array_ref(b: &dyn Array) -> <&Self as IntoIterator>::IntoIter {
+        let array = b.as_any().downcast_ref::<DictionaryArray<K>>().unwrap();
+        let values = <<V as ArrowDeserialize>::ArrayType as ArrowArray>::iter_from_array_ref(
+            array.values().as_ref(),
+        )
+        .map(<V as ArrowDeserialize>::arrow_deserialize)
+        .collect();
+        DictionaryFieldArrayIter {
+            keys: array.keys().iter(),
+            values,
+        }
+    }
+}
+
+impl<K, V, const ORDERED: bool> ArrowDeserialize for Dictionary<K, V, ORDERED>
+where
+    K: DictionaryKey,
+    V: ArrowDeserialize + 'static,
+    <V as ArrowField>::Type: Clone,
+    for<'b> &'b <V as ArrowDeserialize>::ArrayType: IntoIterator,
+{
+    type ArrayType = DictionaryFieldArray<K, V>;
+
+    #[inline]
+    fn arrow_deserialize(v: Option<<V as ArrowField>::Type>) -> Option<<V as ArrowField>::Type> {
+        v
+    }
+}
+
+impl_arrow_array!(BooleanArray);
+impl_arrow_array!(Utf8Array<i32>);
+impl_arrow_array!(Utf8Array<i64>);
 impl_arrow_array!(BinaryArray<i32>);
 impl_arrow_array!(BinaryArray<i64>);
 impl_arrow_array!(FixedSizeBinaryArray);
 impl_arrow_array!(ListArray<i32>);
 impl_arrow_array!(ListArray<i64>);
 impl_arrow_array!(FixedSizeListArray);
+impl_arrow_array!(MapArray);
 
 /// Top-level API to deserialize from Arrow
 pub trait TryIntoCollection<Collection, Element>
@@ -352,14 +1200,200 @@ where
     Collection: FromIterator<Element>,
 {
     /// Convert from a `arrow2::Array` to any collection that implements the `FromIterator` trait
-    fn try_into_collection(self) -> arrow2::error::Result<Collection>;
+    fn try_into_collection(self) -> Result<Collection>;
 
     /// Same as `try_into_collection` except can coerce the conversion to a specific Arrow type. This is
     /// useful when the same rust type maps to one or more Arrow types for example `LargeString`.
-    fn try_into_collection_as_type<ArrowType>(self) -> arrow2::error::Result<Collection>
+    fn try_into_collection_as_type<ArrowType>(self) -> Result<Collection>
     where
         ArrowType: ArrowDeserialize + ArrowField<Type = Element> + 'static,
         for<'b> &'b <ArrowType as ArrowDeserialize>::ArrayType: IntoIterator;
+
+    /// Same as `try_into_collection`, except that numeric columns, including those nested in structs and
+    /// lists, are first widened to the data type of the target field when this can't lose information,
+    /// for example to read an `Int32` column written by another tool into an `i64` field.
+    ///
+    /// The allowed widenings are:
+    /// - signed integers to wider signed integers, for example `Int32` to `Int64`
+    /// - unsigned integers to wider unsigned or signed integers, for example `UInt16` to `UInt32` or `Int32`
+    /// - `Int8`, `Int16`, `UInt8`, `UInt16` and `Float32` to `Float32` or `Float64`, and `Int32` and `UInt32` to `Float64`
+    ///
+    /// Dictionary-encoded columns, for example a `Dictionary(Int32, Utf8)` column read from parquet, are also
+    /// decoded to their values when the target field isn't a dictionary, and the time zone of timestamp columns
+    /// is ignored when the target field has none, such as [`chrono::NaiveDateTime`]. The timestamps are
+    /// read as is, without converting them to the time zone. `Date64` columns holding whole days are converted to
+    /// `Date32`, so that they can be read into [`chrono::NaiveDate`] fields.
+    ///
+    /// Any other difference in data types is still reported as a schema mismatch.
+    fn try_into_collection_lenient(self) -> Result<Collection>;
+}
+
+/// Rebuilds a primitive array with each value converted to a wider type.
+fn widen_primitive<F, T>(
+    array: &dyn Array,
+    data_type: &arrow2::datatypes::DataType,
+) -> Box<dyn Array>
+where
+    F: NativeType,
+    T: NativeType + From<F>,
+{
+    let array = array.as_any().downcast_ref::<PrimitiveArray<F>>().unwrap();
+    let values = array
+        .values()
+        .iter()
+        .map(|v| T::from(*v))
+        .collect::<Vec<_>>();
+    PrimitiveArray::new(data_type.clone(), values.into(), array.validity().cloned()).boxed()
+}
+
+/// Decodes a dictionary array to an array of its values, keeping the nulls of both the keys and the values.
+fn decode_dictionary<K: DictionaryKey>(array: &dyn Array) -> Box<dyn Array> {
+    let array = array.as_any().downcast_ref::<DictionaryArray<K>>().unwrap();
+    let mut growable = growable::make_growable(&[array.values().as_ref()], true, array.len());
+    for index in 0..array.len() {
+        if array.keys().is_null(index) {
+            growable.extend_validity(1);
+        } else {
+            growable.extend(0, array.key_value(index), 1);
+        }
+    }
+    growable.as_box()
+}
+
+// Generates the match of the lossless primitive widenings, from each source type to its wider target types.
+macro_rules! match_widenings {
+    ($array:expr, $data_type:expr; $($from_type:ident: $from:ty => [$($to_type:ident: $to:ty),*],)*) => {
+        match ($array.data_type(), $data_type) {
+            $($(
+                (DataType::$from_type, DataType::$to_type) => Some(widen_primitive::<$from, $to>($array, $data_type)),
+            )*)*
+            _ => None,
+        }
+    };
+}
+
+/// Widens the numeric columns of `array` to match `data_type` where this is lossless, descending into structs
+/// by field name and into lists. Columns that can't be widened are returned unchanged, so that the data type
+/// check reports the mismatch.
+fn widen_array(array: &dyn Array, data_type: &arrow2::datatypes::DataType) -> Box<dyn Array> {
+    use arrow2::datatypes::{DataType, Field};
+
+    if array.data_type() == data_type {
+        return array.to_boxed();
+    }
+
+    let widened = match_widenings!(array, data_type;
+        Int8: i8 => [Int16: i16, Int32: i32, Int64: i64, Float32: f32, Float64: f64],
+        Int16: i16 => [Int32: i32, Int64: i64, Float32: f32, Float64: f64],
+        Int32: i32 => [Int64: i64, Float64: f64],
+        UInt8: u8 => [UInt16: u16, UInt32: u32, UInt64: u64, Int16: i16, Int32: i32, Int64: i64, Float32: f32, Float64: f64],
+        UInt16: u16 => [UInt32: u32, UInt64: u64, Int32: i32, Int64: i64, Float32: f32, Float64: f64],
+        UInt32: u32 => [UInt64: u64, Int64: i64, Float64: f64],
+        Float32: f32 => [Float64: f64],
+    );
+    if let Some(widened) = widened {
+        return widened;
+    }
+
+    if let (DataType::Timestamp(unit, Some(_)), DataType::Timestamp(target_unit, None)) =
+        (array.data_type(), data_type)
+    {
+        if unit == target_unit {
+            let array = array
+                .as_any()
+                .downcast_ref::<PrimitiveArray<i64>>()
+                .unwrap();
+            return array.clone().to(data_type.clone()).boxed();
+        }
+    }
+
+    // `Date64` holds milliseconds since the epoch, which are converted to the days of a `Date32` if they are
+    // whole days, since a time of day would be lost
+    if let (DataType::Date64, DataType::Date32) = (array.data_type(), data_type) {
+        use arrow2::temporal_conversions::MILLISECONDS_IN_DAY;
+
+        let array = array
+            .as_any()
+            .downcast_ref::<PrimitiveArray<i64>>()
+            .unwrap();
+        let days = array
+            .iter()
+            .map(|ms| match ms {
+                Some(ms) if ms % MILLISECONDS_IN_DAY == 0 => {
+                    i32::try_from(ms / MILLISECONDS_IN_DAY).ok()
+                }
+                Some(_) => None,
+                None => Some(0),
+            })
+            .collect::<Option<Vec<_>>>();
+        if let Some(days) = days {
+            return PrimitiveArray::new(DataType::Date32, days.into(), array.validity().cloned())
+                .boxed();
+        }
+    }
+
+    if let DataType::Dictionary(key_type, _, _) = array.data_type() {
+        if !matches!(data_type, DataType::Dictionary(..)) {
+            use arrow2::datatypes::IntegerType;
+            let decoded = match key_type {
+                IntegerType::Int8 => decode_dictionary::<i8>(array),
+                IntegerType::Int16 => decode_dictionary::<i16>(array),
+                IntegerType::Int32 => decode_dictionary::<i32>(array),
+                IntegerType::Int64 => decode_dictionary::<i64>(array),
+                IntegerType::UInt8 => decode_dictionary::<u8>(array),
+                IntegerType::UInt16 => decode_dictionary::<u16>(array),
+                IntegerType::UInt32 => decode_dictionary::<u32>(array),
+                IntegerType::UInt64 => decode_dictionary::<u64>(array),
+            };
+            return widen_array(decoded.as_ref(), data_type);
+        }
+    }
+
+    let with_data_type = |field: &Field, data_type: &DataType| {
+        Field::new(field.name.clone(), data_type.clone(), field.is_nullable)
+    };
+    match (array.data_type(), data_type) {
+        (DataType::Struct(fields), DataType::Struct(target_fields)) => {
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            let (fields, values): (Vec<_>, Vec<_>) = fields
+                .iter()
+                .zip(array.values())
+                .map(
+                    |(field, value)| match target_fields.iter().find(|t| t.name == field.name) {
+                        Some(target) => {
+                            let value = widen_array(value.as_ref(), &target.data_type);
+                            (with_data_type(field, value.data_type()), value)
+                        }
+                        None => (field.clone(), value.clone()),
+                    },
+                )
+                .unzip();
+            StructArray::new(DataType::Struct(fields), values, array.validity().cloned()).boxed()
+        }
+        (DataType::List(field), DataType::List(target)) => {
+            let array = array.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+            let values = widen_array(array.values().as_ref(), &target.data_type);
+            ListArray::new(
+                DataType::List(Box::new(with_data_type(field, values.data_type()))),
+                array.offsets().clone(),
+                values,
+                array.validity().cloned(),
+            )
+            .boxed()
+        }
+        (DataType::LargeList(field), DataType::LargeList(target)) => {
+            let array = array.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            let values = widen_array(array.values().as_ref(), &target.data_type);
+            ListArray::new(
+                DataType::LargeList(Box::new(with_data_type(field, values.data_type()))),
+                array.offsets().clone(),
+                values,
+                array.validity().cloned(),
+            )
+            .boxed()
+        }
+        _ => array.to_boxed(),
+    }
 }
 
 /// Helper to return an iterator for elements from a [`arrow2::array::Array`].
@@ -374,31 +1408,396 @@ where
         .map(<Field as ArrowDeserialize>::arrow_deserialize_internal)
 }
 
+/// The path, expected data type and found data type of a schema mismatch.
+#[doc(hidden)]
+pub type DataTypeMismatch = (
+    String,
+    arrow2::datatypes::DataType,
+    arrow2::datatypes::DataType,
+);
+
+/// Compares two data types and returns the path and data types of the most deeply nested mismatch,
+/// or `None` if the data types are equal.
+#[doc(hidden)]
+pub fn find_data_type_mismatch(
+    expected: &arrow2::datatypes::DataType,
+    found: &arrow2::datatypes::DataType,
+) -> Option<DataTypeMismatch> {
+    let mut path = vec![];
+    find_data_type_mismatch_internal(expected, found, &mut path)
+        .map(|(expected, found)| (path.join("."), expected, found))
+}
+
+fn find_data_type_mismatch_internal(
+    expected: &arrow2::datatypes::DataType,
+    found: &arrow2::datatypes::DataType,
+    path: &mut Vec<String>,
+) -> Option<(arrow2::datatypes::DataType, arrow2::datatypes::DataType)> {
+    use arrow2::datatypes::DataType;
+    use std::slice::from_ref;
+
+    if expected == found {
+        return None;
+    }
+
+    let children = match (expected, found) {
+        // extension types with the same name are compared by their storage type
+        (DataType::Extension(e_name, e, _), DataType::Extension(f_name, f, _))
+            if e_name == f_name =>
+        {
+            return find_data_type_mismatch_internal(e, f, path);
+        }
+        (DataType::Struct(e), DataType::Struct(f)) => Some((e.as_slice(), f.as_slice())),
+        (DataType::Union(e, _, _), DataType::Union(f, _, _)) => Some((e.as_slice(), f.as_slice())),
+        (DataType::List(e), DataType::List(f))
+        | (DataType::LargeList(e), DataType::LargeList(f))
+        | (DataType::Map(e, _), DataType::Map(f, _)) => {
+            Some((from_ref(e.as_ref()), from_ref(f.as_ref())))
+        }
+        (DataType::FixedSizeList(e, e_size), DataType::FixedSizeList(f, f_size))
+            if e_size == f_size =>
+        {
+            Some((from_ref(e.as_ref()), from_ref(f.as_ref())))
+        }
+        _ => None,
+    };
+
+    // Descend into children with matching names. If none of the children mismatch,
+    // the difference is at this level (for example the number of fields or nullability).
+    if let Some((expected_fields, found_fields)) = children {
+        if expected_fields.len() == found_fields.len() {
+            for (e, f) in expected_fields.iter().zip(found_fields.iter()) {
+                if e.name != f.name {
+                    continue;
+                }
+                path.push(e.name.clone());
+                if let Some(mismatch) =
+                    find_data_type_mismatch_internal(&e.data_type, &f.data_type, path)
+                {
+                    return Some(mismatch);
+                }
+                path.pop();
+            }
+        }
+    }
+
+    Some((expected.clone(), found.clone()))
+}
+
+/// For internal use by the derive macro.
+///
+/// Compares the fields of a struct that is deserialized by name with the fields of `found`, ignoring
+/// their order and any extra fields. `children` checks the data type of each expected field in turn, and
+/// `defaults` indicates which fields may be missing. With `deny_unknown_fields`, a field of `found` that
+/// isn't expected is also reported as a mismatch, with its name as the path.
+#[doc(hidden)]
+pub fn find_struct_mismatch_by_name(
+    expected: &arrow2::datatypes::DataType,
+    found: &arrow2::datatypes::DataType,
+    children: &[fn(&arrow2::datatypes::DataType) -> Option<DataTypeMismatch>],
+    defaults: &[bool],
+    deny_unknown_fields: bool,
+) -> Option<DataTypeMismatch> {
+    use arrow2::datatypes::DataType;
+
+    let (expected, found) = match (expected, found) {
+        (DataType::Extension(e_name, e, _), DataType::Extension(f_name, f, _))
+            if e_name == f_name =>
+        {
+            (e.as_ref(), f.as_ref())
+        }
+        _ => (expected, found),
+    };
+    let (DataType::Struct(expected_fields), DataType::Struct(found_fields)) = (expected, found)
+    else {
+        return Some((String::new(), expected.clone(), found.clone()));
+    };
+    for ((e, child), default) in expected_fields.iter().zip(children).zip(defaults) {
+        match found_fields.iter().find(|f| f.name == e.name) {
+            // a nullable column can't be read into a non-nullable field
+            Some(f) if e.is_nullable || !f.is_nullable => {
+                if let Some((path, expected, found)) = child(&f.data_type) {
+                    let path = if path.is_empty() {
+                        e.name.clone()
+                    } else {
+                        format!("{}.{}", e.name, path)
+                    };
+                    return Some((path, expected, found));
+                }
+            }
+            None if *default => {}
+            _ => return Some((String::new(), expected.clone(), found.clone())),
+        }
+    }
+    if deny_unknown_fields {
+        if let Some(f) = found_fields
+            .iter()
+            .find(|f| !expected_fields.iter().any(|e| e.name == f.name))
+        {
+            return Some((f.name.clone(), expected.clone(), found.clone()));
+        }
+    }
+    None
+}
+
+/// For internal use by the derive macro.
+///
+/// Compares a struct, list or map with `found`, except for the data types of its children, which are checked
+/// by `children` in turn: one per field of a struct, the item of a list, or the key and value of a map. This
+/// lets children with their own `data_type_mismatch`, such as structs that match fields by name, be nested.
+#[doc(hidden)]
+pub fn find_nested_mismatch(
+    expected: &arrow2::datatypes::DataType,
+    found: &arrow2::datatypes::DataType,
+    children: &[fn(&arrow2::datatypes::DataType) -> Option<DataTypeMismatch>],
+) -> Option<DataTypeMismatch> {
+    use arrow2::datatypes::DataType;
+
+    let (expected, found) = match (expected, found) {
+        (DataType::Extension(e_name, e, _), DataType::Extension(f_name, f, _))
+            if e_name == f_name =>
+        {
+            (e.as_ref(), f.as_ref())
+        }
+        _ => (expected, found),
+    };
+    if without_children(expected) != without_children(found) {
+        return Some((String::new(), expected.clone(), found.clone()));
+    }
+    for ((name, data_type), child) in nested_children(found).into_iter().zip(children) {
+        if let Some((path, expected, found)) = child(data_type) {
+            let path = if path.is_empty() {
+                name
+            } else {
+                format!("{name}.{path}")
+            };
+            return Some((path, expected, found));
+        }
+    }
+    None
+}
+
+// The names and data types of the children of a struct, list or map, in the order of `find_nested_mismatch`
+fn nested_children(
+    data_type: &arrow2::datatypes::DataType,
+) -> Vec<(String, &arrow2::datatypes::DataType)> {
+    use arrow2::datatypes::DataType;
+
+    match data_type {
+        DataType::Struct(fields) => fields
+            .iter()
+            .map(|f| (f.name.clone(), &f.data_type))
+            .collect(),
+        DataType::List(f) | DataType::LargeList(f) | DataType::FixedSizeList(f, _) => {
+            vec![(f.name.clone(), &f.data_type)]
+        }
+        DataType::Map(entries, _) => match &entries.data_type {
+            DataType::Struct(fields) => fields
+                .iter()
+                .map(|f| (format!("{}.{}", entries.name, f.name), &f.data_type))
+                .collect(),
+            _ => vec![],
+        },
+        _ => vec![],
+    }
+}
+
+// Replaces the data types of the children of a struct, list or map with `Null`
+fn without_children(data_type: &arrow2::datatypes::DataType) -> arrow2::datatypes::DataType {
+    use arrow2::datatypes::{DataType, Field};
+
+    let erase = |f: &Field| Field {
+        data_type: DataType::Null,
+        ..f.clone()
+    };
+    match data_type {
+        DataType::Struct(fields) => DataType::Struct(fields.iter().map(erase).collect()),
+        DataType::List(f) => DataType::List(Box::new(erase(f))),
+        DataType::LargeList(f) => DataType::LargeList(Box::new(erase(f))),
+        DataType::FixedSizeList(f, size) => DataType::FixedSizeList(Box::new(erase(f)), *size),
+        DataType::Map(entries, sorted) => DataType::Map(
+            Box::new(Field {
+                data_type: without_children(&entries.data_type),
+                ..entries.as_ref().clone()
+            }),
+            *sorted,
+        ),
+        data_type => data_type.clone(),
+    }
+}
+
+/// For internal use by the derive macro.
+///
+/// Reassembles the struct array of a flattened field with the given `data_type` from the columns of
+/// its parent with the same names. The parent's validity is used, so that the rows of a null parent
+/// are null.
+#[doc(hidden)]
+pub fn flattened_struct_array(
+    parent: &StructArray,
+    data_type: arrow2::datatypes::DataType,
+) -> StructArray {
+    let values = struct_fields(data_type.clone())
+        .iter()
+        .map(|field| {
+            let index = parent
+                .fields()
+                .iter()
+                .position(|f| f.name == field.name)
+                .expect("the data type of the parent was checked");
+            parent.values()[index].clone()
+        })
+        .collect();
+    StructArray::new(data_type, values, parent.validity().cloned())
+}
+
+/// Returns an error describing the first mismatch if `array` can't be deserialized as `T`, for
+/// example because its data type or the data type of a nested field differs.
+pub fn assert_compatible<T>(array: &dyn Array) -> Result<()>
+where
+    T: ArrowDeserialize,
+    for<'b> &'b <T as ArrowDeserialize>::ArrayType: IntoIterator,
+{
+    check_field_names(&<T as ArrowField>::data_type())?;
+    match <T as ArrowDeserialize>::data_type_mismatch(array.data_type()) {
+        Some((path, expected, found)) => Err(Error::SchemaMismatch {
+            path,
+            expected: Box::new(expected),
+            found: Box::new(found),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Returns whether `array` can be deserialized as `T`. See [`assert_compatible`] for the reason
+/// of a mismatch.
+pub fn is_compatible<T>(array: &dyn Array) -> bool
+where
+    T: ArrowDeserialize,
+    for<'b> &'b <T as ArrowDeserialize>::ArrayType: IntoIterator,
+{
+    assert_compatible::<T>(array).is_ok()
+}
+
 /// Returns a typed iterator to a target type from an `arrow2::Array`
 pub fn arrow_array_deserialize_iterator_as_type<'a, Element, ArrowType>(
     arr: &'a dyn arrow2::array::Array,
-) -> arrow2::error::Result<impl Iterator<Item = Element> + 'a>
+) -> Result<impl Iterator<Item = Element> + 'a>
 where
     Element: 'static,
     ArrowType: ArrowDeserialize + ArrowField<Type = Element> + 'static,
     for<'b> &'b <ArrowType as ArrowDeserialize>::ArrayType: IntoIterator,
 {
-    if &<ArrowType as ArrowField>::data_type() != arr.data_type() {
-        Err(arrow2::error::Error::InvalidArgumentError(
-            "Data type mismatch".to_string(),
-        ))
-    } else {
-        Ok(arrow_array_deserialize_iterator_internal::<
-            Element,
-            ArrowType,
-        >(arr))
+    assert_compatible::<ArrowType>(arr)?;
+    <ArrowType as ArrowDeserialize>::validate_array(arr)?;
+    Ok(arrow_array_deserialize_iterator_internal::<
+        Element,
+        ArrowType,
+    >(arr))
+}
+
+/// Deserialize a union [`Array`] into a collection of enums.
+///
+/// Unlike [`TryIntoCollection`], this doesn't panic if a variant's payload fails to deserialize,
+/// and instead returns an error for the first row that fails.
+pub fn try_union_into_collection<Collection, T>(arr: &dyn Array) -> Result<Collection>
+where
+    T: ArrowDeserializeUnion + 'static,
+    Collection: FromIterator<T>,
+{
+    let expected = <T as ArrowField>::data_type();
+    check_field_names(&expected)?;
+    if let Some((path, expected, found)) = find_data_type_mismatch(&expected, arr.data_type()) {
+        return Err(Error::SchemaMismatch {
+            path,
+            expected: Box::new(expected),
+            found: Box::new(found),
+        });
+    }
+    let arr = arr
+        .as_any()
+        .downcast_ref::<UnionArray>()
+        .ok_or_else(|| Error::Deserialize("Expected a union array".to_string()))?;
+    T::validate_union(arr)?;
+    (0..arr.len())
+        .map(|index| T::try_deserialize_union_row(arr, index))
+        .collect()
+}
+
+/// API to reassemble a `Chunk` of columns into an `arrow2::array::StructArray`. This is the inverse of
+/// [`crate::serialize::FlattenChunk`].
+pub trait UnflattenChunk {
+    /// Build a `StructArray` matching the struct `T` from the columns of the `Chunk`, one column per field
+    /// in declaration order. Returns an error if the number of columns or their data types don't match `T`.
+    fn unflatten<T: ArrowField>(self) -> Result<Box<dyn Array>>;
+
+    /// Same as `unflatten` followed by deserializing the resulting `StructArray` into a collection of `T`.
+    fn try_unflatten_into_collection<Collection, T>(self) -> Result<Collection>
+    where
+        T: ArrowDeserialize + ArrowField<Type = T> + 'static,
+        for<'b> &'b <T as ArrowDeserialize>::ArrayType: IntoIterator,
+        Collection: FromIterator<T>;
+}
+
+impl<A> UnflattenChunk for arrow2::chunk::Chunk<A>
+where
+    A: AsRef<dyn Array>,
+{
+    fn unflatten<T: ArrowField>(self) -> Result<Box<dyn Array>> {
+        let data_type = <T as ArrowField>::data_type();
+        check_field_names(&data_type)?;
+        let fields = match &data_type {
+            arrow2::datatypes::DataType::Struct(fields) => fields,
+            data_type => {
+                return Err(Error::InvalidArgument(format!(
+                    "Expected a struct data type to unflatten a chunk, found {data_type:?}"
+                )))
+            }
+        };
+
+        let arrays = self.into_arrays();
+        if arrays.len() != fields.len() {
+            return Err(Error::InvalidArgument(format!(
+                "Chunk has {} columns, but the struct has {} fields",
+                arrays.len(),
+                fields.len()
+            )));
+        }
+
+        for (array, field) in arrays.iter().zip(fields.iter()) {
+            if let Some((path, expected, found)) =
+                find_data_type_mismatch(&field.data_type, array.as_ref().data_type())
+            {
+                let path = if path.is_empty() {
+                    field.name.clone()
+                } else {
+                    format!("{}.{}", field.name, path)
+                };
+                return Err(Error::SchemaMismatch {
+                    path,
+                    expected: Box::new(expected),
+                    found: Box::new(found),
+                });
+            }
+        }
+
+        let values = arrays.iter().map(|a| a.as_ref().to_boxed()).collect();
+        Ok(StructArray::try_new(data_type, values, None)?.boxed())
+    }
+
+    fn try_unflatten_into_collection<Collection, T>(self) -> Result<Collection>
+    where
+        T: ArrowDeserialize + ArrowField<Type = T> + 'static,
+        for<'b> &'b <T as ArrowDeserialize>::ArrayType: IntoIterator,
+        Collection: FromIterator<T>,
+    {
+        self.unflatten::<T>()?.try_into_collection()
     }
 }
 
 /// Return an iterator that deserializes an [`Array`] to an element of type T
 pub fn arrow_array_deserialize_iterator<'a, T>(
     arr: &'a dyn arrow2::array::Array,
-) -> arrow2::error::Result<impl Iterator<Item = T> + 'a>
+) -> Result<impl Iterator<Item = T> + 'a>
 where
     T: ArrowDeserialize + ArrowField<Type = T> + 'static,
     for<'b> &'b <T as ArrowDeserialize>::ArrayType: IntoIterator,
@@ -406,6 +1805,47 @@ where
     arrow_array_deserialize_iterator_as_type::<T, T>(arr)
 }
 
+/// Same as [`arrow_array_deserialize_iterator`], but also yields the index of each row, for example to
+/// report validation errors precisely.
+pub fn arrow_array_deserialize_indexed_iterator<'a, T>(
+    arr: &'a dyn arrow2::array::Array,
+) -> Result<impl Iterator<Item = (usize, T)> + 'a>
+where
+    T: ArrowDeserialize + ArrowField<Type = T> + 'static,
+    for<'b> &'b <T as ArrowDeserialize>::ArrayType: IntoIterator,
+{
+    Ok(arrow_array_deserialize_iterator::<T>(arr)?.enumerate())
+}
+
+/// Deserializes each row of `arr` as `T` and passes it to `f`, without collecting the rows, for example
+/// to validate a large array. Returns an error before calling `f` if the data type doesn't match `T`.
+pub fn for_each_deserialized<T, F>(arr: &dyn Array, f: F) -> Result<()>
+where
+    T: ArrowDeserialize + ArrowField<Type = T> + 'static,
+    for<'b> &'b <T as ArrowDeserialize>::ArrayType: IntoIterator,
+    F: FnMut(T),
+{
+    arrow_array_deserialize_iterator::<T>(arr)?.for_each(f);
+    Ok(())
+}
+
+/// Deserializes the column at `index` of a `Chunk`, for example one read from IPC or parquet, into a
+/// `Vec<T>`. Returns an error if the index is out of bounds or the column's data type doesn't match `T`.
+pub fn deserialize_column<T, A>(chunk: &arrow2::chunk::Chunk<A>, index: usize) -> Result<Vec<T>>
+where
+    T: ArrowDeserialize + ArrowField<Type = T> + 'static,
+    for<'b> &'b <T as ArrowDeserialize>::ArrayType: IntoIterator,
+    A: AsRef<dyn Array>,
+{
+    let array = chunk.arrays().get(index).ok_or_else(|| {
+        Error::InvalidArgument(format!(
+            "Column index {index} is out of bounds for a chunk with {} columns",
+            chunk.arrays().len()
+        ))
+    })?;
+    Ok(arrow_array_deserialize_iterator::<T>(array.as_ref())?.collect())
+}
+
 impl<Collection, Element, ArrowArray> TryIntoCollection<Collection, Element> for ArrowArray
 where
     Element: ArrowDeserialize + ArrowField<Type = Element> + 'static,
@@ -413,11 +1853,11 @@ where
     ArrowArray: std::borrow::Borrow<dyn Array>,
     Collection: FromIterator<Element>,
 {
-    fn try_into_collection(self) -> arrow2::error::Result<Collection> {
+    fn try_into_collection(self) -> Result<Collection> {
         Ok(arrow_array_deserialize_iterator::<Element>(self.borrow())?.collect())
     }
 
-    fn try_into_collection_as_type<ArrowType>(self) -> arrow2::error::Result<Collection>
+    fn try_into_collection_as_type<ArrowType>(self) -> Result<Collection>
     where
         ArrowType: ArrowDeserialize + ArrowField<Type = Element> + 'static,
         for<'b> &'b <ArrowType as ArrowDeserialize>::ArrayType: IntoIterator,
@@ -427,4 +1867,9 @@ where
                 .collect(),
         )
     }
+    fn try_into_collection_lenient(self) -> Result<Collection> {
+        let widened = widen_array(self.borrow(), &<Element as ArrowField>::data_type());
+        let collection = arrow_array_deserialize_iterator::<Element>(widened.as_ref())?.collect();
+        Ok(collection)
+    }
 }
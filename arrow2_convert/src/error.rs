@@ -0,0 +1,63 @@
+//! Error types returned by the serialization and deserialization APIs.
+
+// err-derive wraps its impls in a `const` item, which newer compilers warn about
+#![allow(unknown_lints, non_local_definitions)]
+
+use arrow2::datatypes::DataType;
+
+/// Errors returned by `arrow2_convert`.
+#[derive(Debug, err_derive::Error)]
+#[error(no_from)]
+pub enum Error {
+    /// The data type of an arrow array does not match the data type of the target type.
+    #[error(
+        display = "Data type mismatch{}: expected {:?}, found {:?}",
+        mismatch_location(self),
+        expected,
+        found
+    )]
+    SchemaMismatch {
+        /// The path to the first nested field that doesn't match, for example `child.items.item.a1`.
+        /// Empty if the mismatch is at the top level.
+        path: String,
+        /// The data type of the target type
+        expected: Box<DataType>,
+        /// The data type of the arrow array
+        found: Box<DataType>,
+    },
+    /// An error occurred while serializing a value to arrow.
+    #[error(display = "Serialize error: {}", _0)]
+    Serialize(#[error(source)] arrow2::error::Error),
+    /// An error occurred while deserializing a value from arrow.
+    #[error(display = "Deserialize error: {}", _0)]
+    Deserialize(String),
+    /// An argument passed to the API is invalid.
+    #[error(display = "Invalid argument: {}", _0)]
+    InvalidArgument(String),
+    /// An error returned by arrow2.
+    #[error(display = "{}", _0)]
+    Arrow(#[error(source, from)] arrow2::error::Error),
+}
+
+/// A specialized `Result` for `arrow2_convert` errors.
+pub type Result<T> = std::result::Result<T, Error>;
+
+// The location of a schema mismatch in its message, which is omitted at the top level
+fn mismatch_location(error: &Error) -> String {
+    match error {
+        Error::SchemaMismatch { path, .. } if !path.is_empty() => format!(" at `{path}`"),
+        _ => String::new(),
+    }
+}
+
+impl From<Error> for arrow2::error::Error {
+    fn from(e: Error) -> Self {
+        match e {
+            Error::Serialize(e) | Error::Arrow(e) => e,
+            Error::SchemaMismatch { .. } | Error::InvalidArgument(_) => {
+                arrow2::error::Error::InvalidArgumentError(e.to_string())
+            }
+            Error::Deserialize(_) => arrow2::error::Error::ExternalFormat(e.to_string()),
+        }
+    }
+}
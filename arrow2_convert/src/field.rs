@@ -6,13 +6,87 @@ use arrow2::{
     types::NativeType,
 };
 use chrono::{NaiveDate, NaiveDateTime};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::sync::Arc;
+
+/// Name of the child field of the list types produced by [`Vec<T>`], [`LargeVec<T>`]
+/// and [`FixedSizeVec<T, SIZE>`].
+pub const LIST_ITEM_NAME: &str = "item";
+
+/// Returns `data_type` with the nullability of its list child set to `nullable`, as used by the
+/// `item_nullable` attribute of the derive macro. Data types other than lists are returned unchanged.
+pub fn list_with_item_nullable(data_type: DataType, nullable: bool) -> DataType {
+    let with_nullable =
+        |item: Box<Field>| Box::new(Field::new(item.name, item.data_type, nullable));
+    match data_type {
+        DataType::List(item) => DataType::List(with_nullable(item)),
+        DataType::LargeList(item) => DataType::LargeList(with_nullable(item)),
+        DataType::FixedSizeList(item, size) => DataType::FixedSizeList(with_nullable(item), size),
+        data_type => data_type,
+    }
+}
+
+/// For internal use by the derive macro.
+///
+/// Returns the fields of a struct data type, looking through any extension type, so that they can be
+/// inlined into a parent struct by the `flatten` attribute.
+///
+/// # Panics
+///
+/// Panics if `data_type` isn't a struct.
+#[doc(hidden)]
+pub fn struct_fields(data_type: DataType) -> Vec<Field> {
+    match data_type.to_logical_type() {
+        DataType::Struct(fields) => fields.clone(),
+        data_type => panic!("'flatten' requires a struct field, found {data_type:?}"),
+    }
+}
+
+/// For internal use by the derive macro.
+///
+/// Concatenates the fields of a struct with flattened fields, where each group holds either a single
+/// field or the fields of a flattened struct. Name collisions are reported by [`check_field_names`].
+#[doc(hidden)]
+pub fn flatten_fields(groups: Vec<Vec<Field>>) -> Vec<Field> {
+    groups.into_iter().flatten().collect()
+}
+
+/// Returns an error if a struct in `data_type`, at any level of nesting, has two fields with the same
+/// name, for example because a field inlined by the `flatten` attribute collides with another field.
+pub fn check_field_names(data_type: &DataType) -> crate::error::Result<()> {
+    match data_type.to_logical_type() {
+        DataType::Struct(fields) => {
+            let mut names = HashSet::with_capacity(fields.len());
+            for field in fields {
+                if !names.insert(field.name.as_str()) {
+                    return Err(crate::error::Error::InvalidArgument(format!(
+                        "Duplicate arrow field name `{}` after flattening",
+                        field.name
+                    )));
+                }
+                check_field_names(&field.data_type)?;
+            }
+            Ok(())
+        }
+        DataType::Union(fields, _, _) => fields
+            .iter()
+            .try_for_each(|field| check_field_names(&field.data_type)),
+        DataType::List(field)
+        | DataType::LargeList(field)
+        | DataType::FixedSizeList(field, _)
+        | DataType::Map(field, _) => check_field_names(&field.data_type),
+        _ => Ok(()),
+    }
+}
 
 /// Trait implemented by all types that can be used as an Arrow field.
 ///
 /// Implementations are provided for types already supported by the arrow2 crate:
 /// - numeric types: [`u8`], [`u16`], [`u32`], [`u64`], [`i8`], [`i16`], [`i32`], [`i128`], [`i64`], [`f32`], [`f64`],
-/// - other types: [`bool`], [`String`]
-/// - temporal types: [`chrono::NaiveDate`], [`chrono::NaiveDateTime`]
+/// - other types: [`bool`], [`char`], [`String`]
+/// - temporal types: [`chrono::NaiveDate`], [`chrono::NaiveDateTime`], [`chrono::Duration`]
+/// - [`Option<T>`] and [`Box<T>`] if `T` implements [`ArrowField`]
 ///
 /// Custom implementations can be provided for other types.
 ///
@@ -47,6 +121,19 @@ pub trait ArrowField {
     }
 }
 
+/// Returns the [`Field`] named `name` for `T`, including its nullability, for example to build a
+/// [`arrow2::datatypes::Schema`] for a column serialized from `T`. `Option<T>` fields are nullable.
+pub fn to_field<T: ArrowField>(name: &str) -> Field {
+    T::field(name)
+}
+
+/// Returns whether `field`, for example read from an IPC schema, has the data type and nullability of
+/// [`to_field::<T>`](to_field). The name and the field level metadata are ignored, since `T` doesn't define them,
+/// while the name and metadata of an extension type are compared as part of the data type.
+pub fn matches_field<T: ArrowField>(field: &Field) -> bool {
+    field.data_type == T::data_type() && field.is_nullable == T::is_nullable()
+}
+
 /// Enables the blanket implementations of [`Vec<T>`] as an Arrow field
 /// if `T` is an Arrow field.
 ///
@@ -58,6 +145,54 @@ macro_rules! arrow_enable_vec_for_type {
         impl $crate::field::ArrowEnableVecForType for $t {}
     };
 }
+/// Implements [`ArrowField`], [`crate::serialize::ArrowSerialize`] and [`crate::deserialize::ArrowDeserialize`]
+/// for a type generated by the `bitflags` crate, for example `arrow_bitflags!(MyFlags, u32)`.
+///
+/// The flags are stored as their underlying integer type using `bits()`, and read back using
+/// `from_bits_truncate`, so unknown bits are dropped.
+#[macro_export]
+macro_rules! arrow_bitflags {
+    ($t:ty, $bits:ty) => {
+        impl $crate::field::ArrowField for $t {
+            type Type = $t;
+
+            #[inline]
+            fn data_type() -> arrow2::datatypes::DataType {
+                <$bits as $crate::field::ArrowField>::data_type()
+            }
+        }
+
+        $crate::arrow_enable_vec_for_type!($t);
+
+        impl $crate::serialize::ArrowSerialize for $t {
+            type MutableArrayType = <$bits as $crate::serialize::ArrowSerialize>::MutableArrayType;
+
+            #[inline]
+            fn new_array() -> Self::MutableArrayType {
+                <$bits as $crate::serialize::ArrowSerialize>::new_array()
+            }
+
+            #[inline]
+            fn arrow_serialize(
+                v: &$t,
+                array: &mut Self::MutableArrayType,
+            ) -> arrow2::error::Result<()> {
+                <$bits as $crate::serialize::ArrowSerialize>::arrow_serialize(&v.bits(), array)
+            }
+        }
+
+        impl $crate::deserialize::ArrowDeserialize for $t {
+            type ArrayType = <$bits as $crate::deserialize::ArrowDeserialize>::ArrayType;
+
+            #[inline]
+            fn arrow_deserialize(v: <&Self::ArrayType as IntoIterator>::Item) -> Option<$t> {
+                <$bits as $crate::deserialize::ArrowDeserialize>::arrow_deserialize(v)
+                    .map(<$t>::from_bits_truncate)
+            }
+        }
+    };
+}
+
 /// Marker used to allow [`Vec<T>`] to be used as a [`ArrowField`].
 #[doc(hidden)]
 pub trait ArrowEnableVecForType {}
@@ -101,6 +236,50 @@ where
     }
 }
 
+// blanket implementation for boxed fields
+impl<T> ArrowField for Box<T>
+where
+    T: ArrowField,
+{
+    type Type = Box<<T as ArrowField>::Type>;
+
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        <T as ArrowField>::data_type()
+    }
+
+    #[inline]
+    fn is_nullable() -> bool {
+        <T as ArrowField>::is_nullable()
+    }
+}
+
+/// Maps an `Option<Option<T>>` to a nullable Arrow `Struct` with a single nullable `value` field, so that
+/// the outer `None` (a null struct) and `Some(None)` (a null `value`) can be told apart.
+///
+/// Without this override, both levels of `Option<Option<T>>` are stored in the same validity, and
+/// `Some(None)` is deserialized as `None`.
+pub struct DoubleOption<T> {
+    d: std::marker::PhantomData<T>,
+}
+
+impl<T> ArrowField for DoubleOption<T>
+where
+    T: ArrowField,
+{
+    type Type = Option<Option<<T as ArrowField>::Type>>;
+
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        arrow2::datatypes::DataType::Struct(vec![<Option<T> as ArrowField>::field("value")])
+    }
+
+    #[inline]
+    fn is_nullable() -> bool {
+        true
+    }
+}
+
 // u8 does not get the full implementation since Vec<u8> and [u8] are considered binary.
 impl_numeric_type!(u8, UInt8);
 impl_numeric_type_full!(u16, UInt16);
@@ -126,6 +305,48 @@ impl<const PRECISION: usize, const SCALE: usize> ArrowField for I128<PRECISION,
     }
 }
 
+/// Maps a rust i128 decimal to an Arrow `FixedSizeBinary(16)` holding its big-endian bytes, for
+/// matching the schemas of systems that store decimals as binary. The precision and scale aren't part
+/// of the data type, but values with more than `PRECISION` digits are rejected like with [`I128`].
+pub struct DecimalBinary<const PRECISION: usize, const SCALE: usize> {}
+
+impl<const PRECISION: usize, const SCALE: usize> ArrowField for DecimalBinary<PRECISION, SCALE> {
+    type Type = i128;
+
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        arrow2::datatypes::DataType::FixedSizeBinary(16)
+    }
+}
+
+/// Maps an [`arrow2::types::i256`] to an Arrow `Decimal256(76, 0)`, the largest precision that fits in 256 bits.
+impl ArrowField for arrow2::types::i256 {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        arrow2::datatypes::DataType::Decimal256(76, 0)
+    }
+}
+
+arrow_enable_vec_for_type!(arrow2::types::i256);
+
+/// Maps a [`bigdecimal::BigDecimal`] to an Arrow Decimal256 with the given precision and scale.
+///
+/// Values are rescaled to `SCALE` when serializing, truncating any extra digits.
+#[cfg(feature = "bigdecimal")]
+pub struct Decimal256<const PRECISION: usize, const SCALE: usize> {}
+
+#[cfg(feature = "bigdecimal")]
+impl<const PRECISION: usize, const SCALE: usize> ArrowField for Decimal256<PRECISION, SCALE> {
+    type Type = bigdecimal::BigDecimal;
+
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        arrow2::datatypes::DataType::Decimal256(PRECISION, SCALE)
+    }
+}
+
 impl ArrowField for String {
     type Type = String;
 
@@ -135,6 +356,25 @@ impl ArrowField for String {
     }
 }
 
+/// Serialize only, since deserialized values can't borrow from the array.
+impl ArrowField for &str {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        arrow2::datatypes::DataType::Utf8
+    }
+}
+
+impl ArrowField for Arc<str> {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        arrow2::datatypes::DataType::Utf8
+    }
+}
+
 /// Represents the `LargeUtf8` Arrow type
 pub struct LargeString {}
 
@@ -147,6 +387,15 @@ impl ArrowField for LargeString {
     }
 }
 
+impl ArrowField for char {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        arrow2::datatypes::DataType::Utf8
+    }
+}
+
 impl ArrowField for bool {
     type Type = Self;
 
@@ -165,6 +414,63 @@ impl ArrowField for NaiveDateTime {
     }
 }
 
+/// Stored as a signed `Duration(Nanosecond)`, since a [`chrono::Duration`] can be negative.
+impl ArrowField for chrono::Duration {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        arrow2::datatypes::DataType::Duration(arrow2::datatypes::TimeUnit::Nanosecond)
+    }
+}
+
+/// The time unit of a [`Timestamp`] column.
+pub trait TimestampUnit {
+    /// The Arrow time unit.
+    const UNIT: arrow2::datatypes::TimeUnit;
+}
+
+/// Seconds [`TimestampUnit`].
+pub struct Second {}
+/// Milliseconds [`TimestampUnit`].
+pub struct Millisecond {}
+/// Microseconds [`TimestampUnit`].
+pub struct Microsecond {}
+/// Nanoseconds [`TimestampUnit`].
+pub struct Nanosecond {}
+
+impl TimestampUnit for Second {
+    const UNIT: arrow2::datatypes::TimeUnit = arrow2::datatypes::TimeUnit::Second;
+}
+
+impl TimestampUnit for Millisecond {
+    const UNIT: arrow2::datatypes::TimeUnit = arrow2::datatypes::TimeUnit::Millisecond;
+}
+
+impl TimestampUnit for Microsecond {
+    const UNIT: arrow2::datatypes::TimeUnit = arrow2::datatypes::TimeUnit::Microsecond;
+}
+
+impl TimestampUnit for Nanosecond {
+    const UNIT: arrow2::datatypes::TimeUnit = arrow2::datatypes::TimeUnit::Nanosecond;
+}
+
+/// Maps a [`chrono::NaiveDateTime`] to an Arrow `Timestamp` with the time unit `U` and no timezone.
+///
+/// Precision finer than `U` is truncated when serializing.
+pub struct Timestamp<U: TimestampUnit> {
+    d: std::marker::PhantomData<U>,
+}
+
+impl<U: TimestampUnit> ArrowField for Timestamp<U> {
+    type Type = NaiveDateTime;
+
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        arrow2::datatypes::DataType::Timestamp(U::UNIT, None)
+    }
+}
+
 impl ArrowField for NaiveDate {
     type Type = Self;
 
@@ -174,6 +480,71 @@ impl ArrowField for NaiveDate {
     }
 }
 
+/// Represents a [`chrono::NaiveDate`] stored as the raw number of days since the
+/// UNIX epoch in an `Int32` Arrow type, without the `Date32` logical type.
+pub struct NaiveDateAsDays {}
+
+impl ArrowField for NaiveDateAsDays {
+    type Type = NaiveDate;
+
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        arrow2::datatypes::DataType::Int32
+    }
+}
+
+/// Represents a [`chrono::NaiveDate`] stored as an Arrow `Date64`, the number of milliseconds since the
+/// UNIX epoch, for consumers that expect `Date64` instead of `Date32`.
+pub struct Date64 {}
+
+impl ArrowField for Date64 {
+    type Type = NaiveDate;
+
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        arrow2::datatypes::DataType::Date64
+    }
+}
+
+/// A calendar interval stored as an Arrow `Interval(MonthDayNano)`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct IntervalMonthDayNano {
+    /// The number of months
+    pub months: i32,
+    /// The number of days
+    pub days: i32,
+    /// The number of nanoseconds
+    pub nanos: i64,
+}
+
+impl ArrowField for IntervalMonthDayNano {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        arrow2::datatypes::DataType::Interval(arrow2::datatypes::IntervalUnit::MonthDayNano)
+    }
+}
+
+/// A column that only contains nulls, stored as an Arrow `Null`. Useful to match externally
+/// produced schemas.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct NullColumn;
+
+impl ArrowField for NullColumn {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        arrow2::datatypes::DataType::Null
+    }
+
+    #[inline]
+    fn is_nullable() -> bool {
+        true
+    }
+}
+
 impl ArrowField for Buffer<u8> {
     type Type = Self;
 
@@ -183,6 +554,56 @@ impl ArrowField for Buffer<u8> {
     }
 }
 
+#[cfg(feature = "bytes")]
+impl ArrowField for bytes::Bytes {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        arrow2::datatypes::DataType::Binary
+    }
+}
+
+/// Stored as the underlying float. NaN values are preserved as is.
+#[cfg(feature = "ordered-float")]
+impl<T> ArrowField for ordered_float::OrderedFloat<T>
+where
+    T: ArrowField<Type = T> + ordered_float::FloatCore,
+{
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        <T as ArrowField>::data_type()
+    }
+}
+
+// glam vectors and matrices are stored as a `FixedSizeList` of their `f32` components
+#[cfg(feature = "glam")]
+macro_rules! impl_glam_field {
+    ($t:ty, $size:expr) => {
+        impl ArrowField for $t {
+            type Type = Self;
+
+            #[inline]
+            fn data_type() -> arrow2::datatypes::DataType {
+                arrow2::datatypes::DataType::FixedSizeList(
+                    Box::new(<f32 as ArrowField>::field(LIST_ITEM_NAME)),
+                    $size,
+                )
+            }
+        }
+    };
+}
+
+#[cfg(feature = "glam")]
+impl_glam_field!(glam::Vec3, 3);
+#[cfg(feature = "glam")]
+impl_glam_field!(glam::Vec4, 4);
+// Matrices are stored in column-major order
+#[cfg(feature = "glam")]
+impl_glam_field!(glam::Mat4, 16);
+
 impl ArrowField for Vec<u8> {
     type Type = Self;
 
@@ -192,6 +613,73 @@ impl ArrowField for Vec<u8> {
     }
 }
 
+impl ArrowField for Arc<[u8]> {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        arrow2::datatypes::DataType::Binary
+    }
+}
+
+impl ArrowField for Ipv4Addr {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        arrow2::datatypes::DataType::FixedSizeBinary(4)
+    }
+}
+
+impl ArrowField for Ipv6Addr {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        arrow2::datatypes::DataType::FixedSizeBinary(16)
+    }
+}
+
+/// Represented as a dense union with an `Ok` and an `Err` variant. Unions don't have a validity, so the `Ok`
+/// child is nullable to hold the nulls of an `Option<Result<T, E>>`.
+impl<T, E> ArrowField for Result<T, E>
+where
+    T: ArrowField,
+    E: ArrowField,
+{
+    type Type = Result<<T as ArrowField>::Type, <E as ArrowField>::Type>;
+
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        arrow2::datatypes::DataType::Union(
+            vec![
+                Field::new("Ok", <T as ArrowField>::data_type(), true),
+                <E as ArrowField>::field("Err"),
+            ],
+            None,
+            arrow2::datatypes::UnionMode::Dense,
+        )
+    }
+}
+
+/// Represented as a dense union of [`Ipv4Addr`] and [`Ipv6Addr`]. Unions don't have a validity, so the
+/// `V4` child is nullable to hold the nulls of an `Option<IpAddr>`.
+impl ArrowField for IpAddr {
+    type Type = Self;
+
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        arrow2::datatypes::DataType::Union(
+            vec![
+                Field::new("V4", <Ipv4Addr as ArrowField>::data_type(), true),
+                <Ipv6Addr as ArrowField>::field("V6"),
+            ],
+            None,
+            arrow2::datatypes::UnionMode::Dense,
+        )
+    }
+}
+
 /// Represents the `LargeString` Arrow type.
 pub struct LargeBinary {}
 
@@ -204,6 +692,18 @@ impl ArrowField for LargeBinary {
     }
 }
 
+/// Represents a [`Vec<u8>`] stored as a `List(UInt8)` Arrow type, instead of the default `Binary`.
+pub struct U8List {}
+
+impl ArrowField for U8List {
+    type Type = Vec<u8>;
+
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        arrow2::datatypes::DataType::List(Box::new(<u8 as ArrowField>::field(LIST_ITEM_NAME)))
+    }
+}
+
 /// Represents the `FixedSizeBinary` Arrow type.
 pub struct FixedSizeBinary<const SIZE: usize> {}
 
@@ -225,7 +725,7 @@ where
 
     #[inline]
     fn data_type() -> DataType {
-        DataType::List(Box::new(<T as ArrowField>::field("item")))
+        DataType::List(Box::new(<T as ArrowField>::field(LIST_ITEM_NAME)))
     }
 }
 
@@ -238,7 +738,190 @@ where
 
     #[inline]
     fn data_type() -> arrow2::datatypes::DataType {
-        arrow2::datatypes::DataType::List(Box::new(<T as ArrowField>::field("item")))
+        arrow2::datatypes::DataType::List(Box::new(<T as ArrowField>::field(LIST_ITEM_NAME)))
+    }
+}
+
+impl<T> ArrowField for VecDeque<T>
+where
+    T: ArrowField + ArrowEnableVecForType,
+{
+    type Type = VecDeque<<T as ArrowField>::Type>;
+
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        <Vec<T> as ArrowField>::data_type()
+    }
+}
+
+/// Stored as a `List` in sorted order
+impl<T> ArrowField for BTreeSet<T>
+where
+    T: ArrowField + ArrowEnableVecForType,
+{
+    type Type = BTreeSet<<T as ArrowField>::Type>;
+
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        <Vec<T> as ArrowField>::data_type()
+    }
+}
+
+/// Stored as a `List` in the iteration order of the set, which is unspecified
+impl<T> ArrowField for HashSet<T>
+where
+    T: ArrowField + ArrowEnableVecForType,
+{
+    type Type = HashSet<<T as ArrowField>::Type>;
+
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        <Vec<T> as ArrowField>::data_type()
+    }
+}
+
+/// Stored as a variable-length `List`, since an `ArrayVec` holds up to `CAP` items
+#[cfg(feature = "arrayvec")]
+impl<T, const CAP: usize> ArrowField for arrayvec::ArrayVec<T, CAP>
+where
+    T: ArrowField + ArrowEnableVecForType,
+{
+    type Type = arrayvec::ArrayVec<<T as ArrowField>::Type, CAP>;
+
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        <Vec<T> as ArrowField>::data_type()
+    }
+}
+
+/// Stored as a variable-length `List`, since a `SmallVec` only keeps up to `N` items inline
+#[cfg(feature = "smallvec")]
+impl<T, const N: usize> ArrowField for smallvec::SmallVec<[T; N]>
+where
+    T: ArrowField + ArrowEnableVecForType,
+{
+    type Type = smallvec::SmallVec<[<T as ArrowField>::Type; N]>;
+
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        <Vec<T> as ArrowField>::data_type()
+    }
+}
+
+// The `Map` data type shared by all map-like types.
+fn map_data_type<K: ArrowField, V: ArrowField>() -> arrow2::datatypes::DataType {
+    arrow2::datatypes::DataType::Map(
+        Box::new(Field::new(
+            "entries",
+            arrow2::datatypes::DataType::Struct(vec![
+                <K as ArrowField>::field("key"),
+                <V as ArrowField>::field("value"),
+            ]),
+            false,
+        )),
+        false,
+    )
+}
+
+// Blanket implementation for HashMap.
+impl<K, V> ArrowField for HashMap<K, V>
+where
+    K: ArrowField,
+    V: ArrowField,
+{
+    type Type = HashMap<<K as ArrowField>::Type, <V as ArrowField>::Type>;
+
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        map_data_type::<K, V>()
+    }
+}
+
+// Blanket implementation for BTreeMap.
+impl<K, V> ArrowField for BTreeMap<K, V>
+where
+    K: ArrowField,
+    V: ArrowField,
+{
+    type Type = BTreeMap<<K as ArrowField>::Type, <V as ArrowField>::Type>;
+
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        map_data_type::<K, V>()
+    }
+}
+
+/// The entries of an Arrow `Map` in the order they are stored.
+///
+/// Unlike [`HashMap`] and [`BTreeMap`], deserializing to `MapEntries` preserves the physical order
+/// of the keys and values, as well as duplicate keys.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct MapEntries<K, V>(pub Vec<(K, V)>);
+
+impl<K, V> From<Vec<(K, V)>> for MapEntries<K, V> {
+    fn from(entries: Vec<(K, V)>) -> Self {
+        Self(entries)
+    }
+}
+
+impl<K, V> ArrowField for MapEntries<K, V>
+where
+    K: ArrowField,
+    V: ArrowField,
+{
+    type Type = MapEntries<<K as ArrowField>::Type, <V as ArrowField>::Type>;
+
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        map_data_type::<K, V>()
+    }
+}
+
+/// Maps an ordered `Vec<(K, V)>` of pairs to an Arrow `Map`, keeping the order of the pairs and duplicate keys.
+///
+/// This is the type override counterpart of [`MapEntries`], for fields declared as a `Vec` of pairs.
+pub struct Map<K, V> {
+    d: std::marker::PhantomData<(K, V)>,
+}
+
+impl<K, V> ArrowField for Map<K, V>
+where
+    K: ArrowField,
+    V: ArrowField,
+{
+    type Type = Vec<(<K as ArrowField>::Type, <V as ArrowField>::Type)>;
+
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        map_data_type::<K, V>()
+    }
+}
+
+/// Maps a value to an Arrow `Dictionary` with keys of type `K` and values stored as `V`, where each distinct
+/// value is only stored once. `ORDERED` sets the `is_ordered` flag of the data type, which marks the values
+/// as sorted, so that consumers can compare keys instead of values. The values of ordered dictionaries are
+/// sorted when serializing, which requires `V` to be `Ord`.
+///
+/// For example, `#[arrow_field(type = "Dictionary<i16, String, true>")]` on a `String` field maps to
+/// `Dictionary(Int16, Utf8, true)`.
+pub struct Dictionary<K, V, const ORDERED: bool = false> {
+    d: std::marker::PhantomData<(K, V)>,
+}
+
+impl<K, V, const ORDERED: bool> ArrowField for Dictionary<K, V, ORDERED>
+where
+    K: arrow2::array::DictionaryKey,
+    V: ArrowField,
+{
+    type Type = <V as ArrowField>::Type;
+
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        arrow2::datatypes::DataType::Dictionary(
+            K::KEY_TYPE,
+            Box::new(<V as ArrowField>::data_type()),
+            ORDERED,
+        )
     }
 }
 
@@ -255,7 +938,7 @@ where
 
     #[inline]
     fn data_type() -> arrow2::datatypes::DataType {
-        arrow2::datatypes::DataType::LargeList(Box::new(<T as ArrowField>::field("item")))
+        arrow2::datatypes::DataType::LargeList(Box::new(<T as ArrowField>::field(LIST_ITEM_NAME)))
     }
 }
 
@@ -272,28 +955,145 @@ where
 
     #[inline]
     fn data_type() -> arrow2::datatypes::DataType {
-        arrow2::datatypes::DataType::FixedSizeList(Box::new(<T as ArrowField>::field("item")), SIZE)
+        arrow2::datatypes::DataType::FixedSizeList(
+            Box::new(<T as ArrowField>::field(LIST_ITEM_NAME)),
+            SIZE,
+        )
+    }
+}
+
+/// Stores any `T` that implements `serde::Serialize` and `serde::Deserialize` as a JSON encoded `Utf8` column,
+/// tagged with the `json` extension type. Requires the `serde_json` feature.
+#[cfg(feature = "serde_json")]
+pub struct Json<T> {
+    d: std::marker::PhantomData<T>,
+}
+
+#[cfg(feature = "serde_json")]
+impl<T> ArrowField for Json<T> {
+    type Type = T;
+
+    #[inline]
+    fn data_type() -> arrow2::datatypes::DataType {
+        arrow2::datatypes::DataType::Extension(
+            "json".to_string(),
+            Box::new(arrow2::datatypes::DataType::Utf8),
+            None,
+        )
     }
 }
 
 arrow_enable_vec_for_type!(String);
+arrow_enable_vec_for_type!(Arc<str>);
+impl ArrowEnableVecForType for &str {}
 arrow_enable_vec_for_type!(LargeString);
 arrow_enable_vec_for_type!(bool);
+arrow_enable_vec_for_type!(char);
 arrow_enable_vec_for_type!(NaiveDateTime);
 arrow_enable_vec_for_type!(NaiveDate);
+arrow_enable_vec_for_type!(chrono::Duration);
+arrow_enable_vec_for_type!(NaiveDateAsDays);
+arrow_enable_vec_for_type!(Date64);
+arrow_enable_vec_for_type!(IntervalMonthDayNano);
+arrow_enable_vec_for_type!(NullColumn);
 arrow_enable_vec_for_type!(Vec<u8>);
+arrow_enable_vec_for_type!(Arc<[u8]>);
+arrow_enable_vec_for_type!(Ipv4Addr);
+arrow_enable_vec_for_type!(Ipv6Addr);
+arrow_enable_vec_for_type!(IpAddr);
 arrow_enable_vec_for_type!(Buffer<u8>);
+#[cfg(feature = "bytes")]
+arrow_enable_vec_for_type!(bytes::Bytes);
+#[cfg(feature = "ordered-float")]
+arrow_enable_vec_for_type!(ordered_float::OrderedFloat<f32>);
+#[cfg(feature = "ordered-float")]
+arrow_enable_vec_for_type!(ordered_float::OrderedFloat<f64>);
+#[cfg(feature = "glam")]
+arrow_enable_vec_for_type!(glam::Vec3);
+#[cfg(feature = "glam")]
+arrow_enable_vec_for_type!(glam::Vec4);
+#[cfg(feature = "glam")]
+arrow_enable_vec_for_type!(glam::Mat4);
 arrow_enable_vec_for_type!(LargeBinary);
+arrow_enable_vec_for_type!(U8List);
 impl<const SIZE: usize> ArrowEnableVecForType for FixedSizeBinary<SIZE> {}
 impl<const PRECISION: usize, const SCALE: usize> ArrowEnableVecForType for I128<PRECISION, SCALE> {}
+impl<const PRECISION: usize, const SCALE: usize> ArrowEnableVecForType
+    for DecimalBinary<PRECISION, SCALE>
+{
+}
+impl<U: TimestampUnit> ArrowEnableVecForType for Timestamp<U> {}
+#[cfg(feature = "bigdecimal")]
+impl<const PRECISION: usize, const SCALE: usize> ArrowEnableVecForType
+    for Decimal256<PRECISION, SCALE>
+{
+}
 
 // Blanket implementation for Vec<Option<T>> if vectors are enabled for T
 impl<T> ArrowEnableVecForType for Option<T> where T: ArrowField + ArrowEnableVecForType {}
 
+// Blanket implementation for Vec<Result<T, E>> if vectors are enabled for T and E
+impl<T, E> ArrowEnableVecForType for Result<T, E>
+where
+    T: ArrowField + ArrowEnableVecForType,
+    E: ArrowField + ArrowEnableVecForType,
+{
+}
+
+impl<T> ArrowEnableVecForType for DoubleOption<T> where T: ArrowField + ArrowEnableVecForType {}
+
+// Blanket implementation for Vec<Box<T>> if vectors are enabled for T
+impl<T> ArrowEnableVecForType for Box<T> where T: ArrowField + ArrowEnableVecForType {}
+
 // Blanket implementation for Vec<Vec<T>> and Vec<Buffer<T>> if vectors or buffers are enabled for T
 impl<T> ArrowEnableVecForType for Vec<T> where T: ArrowField + ArrowEnableVecForType {}
+impl<T> ArrowEnableVecForType for VecDeque<T> where T: ArrowField + ArrowEnableVecForType {}
+impl<T> ArrowEnableVecForType for BTreeSet<T> where T: ArrowField + ArrowEnableVecForType {}
+impl<T> ArrowEnableVecForType for HashSet<T> where T: ArrowField + ArrowEnableVecForType {}
+#[cfg(feature = "arrayvec")]
+impl<T, const CAP: usize> ArrowEnableVecForType for arrayvec::ArrayVec<T, CAP> where
+    T: ArrowField + ArrowEnableVecForType
+{
+}
+#[cfg(feature = "smallvec")]
+impl<T, const N: usize> ArrowEnableVecForType for smallvec::SmallVec<[T; N]> where
+    T: ArrowField + ArrowEnableVecForType
+{
+}
 impl<T> ArrowEnableVecForType for Buffer<T> where T: ArrowField + ArrowEnableVecForType {}
+impl<K, V> ArrowEnableVecForType for HashMap<K, V>
+where
+    K: ArrowField,
+    V: ArrowField,
+{
+}
+impl<K, V> ArrowEnableVecForType for BTreeMap<K, V>
+where
+    K: ArrowField,
+    V: ArrowField,
+{
+}
+impl<K, V> ArrowEnableVecForType for MapEntries<K, V>
+where
+    K: ArrowField,
+    V: ArrowField,
+{
+}
+impl<K, V> ArrowEnableVecForType for Map<K, V>
+where
+    K: ArrowField,
+    V: ArrowField,
+{
+}
+impl<K, V, const ORDERED: bool> ArrowEnableVecForType for Dictionary<K, V, ORDERED>
+where
+    K: arrow2::array::DictionaryKey,
+    V: ArrowField,
+{
+}
 impl<T> ArrowEnableVecForType for LargeVec<T> where T: ArrowField + ArrowEnableVecForType {}
+#[cfg(feature = "serde_json")]
+impl<T> ArrowEnableVecForType for Json<T> {}
 impl<T, const SIZE: usize> ArrowEnableVecForType for FixedSizeVec<T, SIZE> where
     T: ArrowField + ArrowEnableVecForType
 {
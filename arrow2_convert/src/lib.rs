@@ -2,7 +2,10 @@
 #![deny(missing_docs)]
 #![forbid(unsafe_code)]
 
+pub mod builder;
+pub mod convert;
 pub mod deserialize;
+pub mod error;
 pub mod field;
 pub mod serialize;
 
@@ -1,12 +1,19 @@
 //! Implementation and traits for serializing to Arrow.
 
 use arrow2::array::*;
+use arrow2::bitmap::MutableBitmap;
 use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Field, Schema};
+use arrow2::offset::Offsets;
 use arrow2::types::NativeType;
 use arrow2::{array::Array, buffer::Buffer};
 use chrono::{NaiveDate, NaiveDateTime};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 use std::sync::Arc;
 
+use crate::error::{Error, Result};
 use crate::field::*;
 
 /// Trait that is implemented by all types that are serializable to Arrow.
@@ -78,6 +85,47 @@ where
     }
 }
 
+// blanket implementation for boxed fields
+impl<T> ArrowSerialize for Box<T>
+where
+    T: ArrowSerialize,
+{
+    type MutableArrayType = <T as ArrowSerialize>::MutableArrayType;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        <T as ArrowSerialize>::new_array()
+    }
+
+    #[inline]
+    fn arrow_serialize(
+        v: &<Self as ArrowField>::Type,
+        array: &mut Self::MutableArrayType,
+    ) -> arrow2::error::Result<()> {
+        <T as ArrowSerialize>::arrow_serialize(v, array)
+    }
+}
+
+impl<T> ArrowSerialize for DoubleOption<T>
+where
+    T: ArrowSerialize + 'static,
+{
+    type MutableArrayType = MutableDoubleOptionArray<T>;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        Self::MutableArrayType::default()
+    }
+
+    #[inline]
+    fn arrow_serialize(
+        v: &<Self as ArrowField>::Type,
+        array: &mut Self::MutableArrayType,
+    ) -> arrow2::error::Result<()> {
+        array.try_push(v)
+    }
+}
+
 impl_numeric_type!(u8);
 impl_numeric_type!(u16);
 impl_numeric_type!(u32);
@@ -100,10 +148,90 @@ impl<const PRECISION: usize, const SCALE: usize> ArrowSerialize for I128<PRECISI
 
     #[inline]
     fn arrow_serialize(v: &i128, array: &mut Self::MutableArrayType) -> arrow2::error::Result<()> {
+        // every i128 fits in more than 38 digits
+        if let Some(max) = 10_u128.checked_pow(PRECISION as u32) {
+            if v.unsigned_abs() >= max {
+                return Err(arrow2::error::Error::InvalidArgumentError(format!(
+                    "I128<{PRECISION}, {SCALE}>: {v} exceeds the precision"
+                )));
+            }
+        }
+        array.try_push(Some(*v))
+    }
+}
+
+impl<const PRECISION: usize, const SCALE: usize> ArrowSerialize
+    for DecimalBinary<PRECISION, SCALE>
+{
+    type MutableArrayType = MutableFixedSizeBinaryArray;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        Self::MutableArrayType::new(16)
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &i128, array: &mut Self::MutableArrayType) -> arrow2::error::Result<()> {
+        if let Some(max) = 10_u128.checked_pow(PRECISION as u32) {
+            if v.unsigned_abs() >= max {
+                return Err(arrow2::error::Error::InvalidArgumentError(format!(
+                    "DecimalBinary<{PRECISION}, {SCALE}>: {v} exceeds the precision"
+                )));
+            }
+        }
+        array.try_push(Some(v.to_be_bytes()))
+    }
+}
+
+impl ArrowSerialize for arrow2::types::i256 {
+    type MutableArrayType = MutablePrimitiveArray<arrow2::types::i256>;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        Self::MutableArrayType::from(<Self as ArrowField>::data_type())
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::MutableArrayType) -> arrow2::error::Result<()> {
         array.try_push(Some(*v))
     }
 }
 
+#[cfg(feature = "bigdecimal")]
+impl<const PRECISION: usize, const SCALE: usize> ArrowSerialize for Decimal256<PRECISION, SCALE> {
+    type MutableArrayType = MutablePrimitiveArray<arrow2::types::i256>;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        Self::MutableArrayType::from(<Self as ArrowField>::data_type())
+    }
+
+    fn arrow_serialize(
+        v: &bigdecimal::BigDecimal,
+        array: &mut Self::MutableArrayType,
+    ) -> arrow2::error::Result<()> {
+        // the mantissa of the rescaled value is the stored integer
+        let (mantissa, _) = v.with_scale(SCALE as i64).into_bigint_and_exponent();
+        let bytes = mantissa.to_signed_bytes_le();
+        if mantissa.magnitude().to_string().len() > PRECISION || bytes.len() > 32 {
+            return Err(arrow2::error::Error::InvalidArgumentError(format!(
+                "Decimal256<{PRECISION}, {SCALE}>: {v} exceeds the precision"
+            )));
+        }
+        // sign extend to 256 bits
+        let fill = if mantissa.sign() == bigdecimal::num_bigint::Sign::Minus {
+            0xff
+        } else {
+            0
+        };
+        let mut words = [fill; 32];
+        words[..bytes.len()].copy_from_slice(&bytes);
+        let lo = i128::from_le_bytes(words[..16].try_into().unwrap());
+        let hi = i128::from_le_bytes(words[16..].try_into().unwrap());
+        array.try_push(Some(arrow2::types::i256::from_words(hi, lo)))
+    }
+}
+
 impl ArrowSerialize for String {
     type MutableArrayType = MutableUtf8Array<i32>;
 
@@ -118,6 +246,34 @@ impl ArrowSerialize for String {
     }
 }
 
+impl ArrowSerialize for &str {
+    type MutableArrayType = MutableUtf8Array<i32>;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        Self::MutableArrayType::default()
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::MutableArrayType) -> arrow2::error::Result<()> {
+        array.try_push(Some(*v))
+    }
+}
+
+impl ArrowSerialize for Arc<str> {
+    type MutableArrayType = MutableUtf8Array<i32>;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        Self::MutableArrayType::default()
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::MutableArrayType) -> arrow2::error::Result<()> {
+        array.try_push(Some(v.as_ref()))
+    }
+}
+
 impl ArrowSerialize for LargeString {
     type MutableArrayType = MutableUtf8Array<i64>;
 
@@ -135,6 +291,20 @@ impl ArrowSerialize for LargeString {
     }
 }
 
+impl ArrowSerialize for char {
+    type MutableArrayType = MutableUtf8Array<i32>;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        Self::MutableArrayType::default()
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::MutableArrayType) -> arrow2::error::Result<()> {
+        array.try_push(Some(v.encode_utf8(&mut [0; 4])))
+    }
+}
+
 impl ArrowSerialize for bool {
     type MutableArrayType = MutableBooleanArray;
 
@@ -163,6 +333,49 @@ impl ArrowSerialize for NaiveDateTime {
     }
 }
 
+// Durations of more than about 292 years don't fit in an i64 of nanoseconds
+impl ArrowSerialize for chrono::Duration {
+    type MutableArrayType = MutablePrimitiveArray<i64>;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        Self::MutableArrayType::from(<Self as ArrowField>::data_type())
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::MutableArrayType) -> arrow2::error::Result<()> {
+        let nanos = v.num_nanoseconds().ok_or_else(|| {
+            arrow2::error::Error::InvalidArgumentError(format!(
+                "Duration {v} overflows an i64 of nanoseconds"
+            ))
+        })?;
+        array.try_push(Some(nanos))
+    }
+}
+
+impl<U: TimestampUnit> ArrowSerialize for Timestamp<U> {
+    type MutableArrayType = MutablePrimitiveArray<i64>;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        Self::MutableArrayType::from(<Self as ArrowField>::data_type())
+    }
+
+    #[inline]
+    fn arrow_serialize(
+        v: &NaiveDateTime,
+        array: &mut Self::MutableArrayType,
+    ) -> arrow2::error::Result<()> {
+        let t = match U::UNIT {
+            arrow2::datatypes::TimeUnit::Second => v.timestamp(),
+            arrow2::datatypes::TimeUnit::Millisecond => v.timestamp_millis(),
+            arrow2::datatypes::TimeUnit::Microsecond => v.timestamp_micros(),
+            arrow2::datatypes::TimeUnit::Nanosecond => v.timestamp_nanos(),
+        };
+        array.try_push(Some(t))
+    }
+}
+
 impl ArrowSerialize for NaiveDate {
     type MutableArrayType = MutablePrimitiveArray<i32>;
 
@@ -180,179 +393,1190 @@ impl ArrowSerialize for NaiveDate {
     }
 }
 
-impl ArrowSerialize for Buffer<u8> {
-    type MutableArrayType = MutableBinaryArray<i32>;
+impl ArrowSerialize for Date64 {
+    type MutableArrayType = MutablePrimitiveArray<i64>;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        Self::MutableArrayType::from(<Self as ArrowField>::data_type())
+    }
+
+    #[inline]
+    fn arrow_serialize(
+        v: &NaiveDate,
+        array: &mut Self::MutableArrayType,
+    ) -> arrow2::error::Result<()> {
+        let days = chrono::Datelike::num_days_from_ce(v)
+            - arrow2::temporal_conversions::EPOCH_DAYS_FROM_CE;
+        array.try_push(Some(
+            days as i64 * arrow2::temporal_conversions::MILLISECONDS_IN_DAY,
+        ))
+    }
+}
+
+impl ArrowSerialize for IntervalMonthDayNano {
+    type MutableArrayType = MutablePrimitiveArray<arrow2::types::months_days_ns>;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        Self::MutableArrayType::from(<Self as ArrowField>::data_type())
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::MutableArrayType) -> arrow2::error::Result<()> {
+        array.try_push(Some(arrow2::types::months_days_ns::new(
+            v.months, v.days, v.nanos,
+        )))
+    }
+}
+
+impl ArrowSerialize for NullColumn {
+    type MutableArrayType = MutableNullArray;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        Self::MutableArrayType::new(<Self as ArrowField>::data_type(), 0)
+    }
+
+    #[inline]
+    fn arrow_serialize(_: &Self, array: &mut Self::MutableArrayType) -> arrow2::error::Result<()> {
+        array.push_null();
+        Ok(())
+    }
+}
+
+impl ArrowSerialize for NaiveDateAsDays {
+    type MutableArrayType = MutablePrimitiveArray<i32>;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        Self::MutableArrayType::default()
+    }
+
+    #[inline]
+    fn arrow_serialize(
+        v: &NaiveDate,
+        array: &mut Self::MutableArrayType,
+    ) -> arrow2::error::Result<()> {
+        <NaiveDate as ArrowSerialize>::arrow_serialize(v, array)
+    }
+}
+
+#[cfg(feature = "serde_json")]
+impl<T> ArrowSerialize for Json<T>
+where
+    T: serde::Serialize,
+{
+    type MutableArrayType = MutableUtf8Array<i32>;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        MutableUtf8Array::try_new(
+            <Self as ArrowField>::data_type(),
+            Offsets::new(),
+            vec![],
+            None,
+        )
+        .unwrap()
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &T, array: &mut Self::MutableArrayType) -> arrow2::error::Result<()> {
+        let json = serde_json::to_string(v)
+            .map_err(|e| arrow2::error::Error::ExternalFormat(e.to_string()))?;
+        array.try_push(Some(json))
+    }
+}
+
+impl ArrowSerialize for Buffer<u8> {
+    type MutableArrayType = MutableBinaryArray<i32>;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        Self::MutableArrayType::default()
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::MutableArrayType) -> arrow2::error::Result<()> {
+        array.try_push(Some(v.as_slice()))
+    }
+}
+
+#[cfg(feature = "ordered-float")]
+impl<T> ArrowSerialize for ordered_float::OrderedFloat<T>
+where
+    T: ArrowSerialize + ArrowField<Type = T> + ordered_float::FloatCore,
+{
+    type MutableArrayType = <T as ArrowSerialize>::MutableArrayType;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        <T as ArrowSerialize>::new_array()
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::MutableArrayType) -> arrow2::error::Result<()> {
+        <T as ArrowSerialize>::arrow_serialize(&v.0, array)
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl ArrowSerialize for bytes::Bytes {
+    type MutableArrayType = MutableBinaryArray<i32>;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        Self::MutableArrayType::default()
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::MutableArrayType) -> arrow2::error::Result<()> {
+        array.try_push(Some(v.as_ref()))
+    }
+}
+
+#[cfg(feature = "glam")]
+macro_rules! impl_glam_serialize {
+    ($t:ty, $size:expr, $to_array:ident) => {
+        impl ArrowSerialize for $t {
+            type MutableArrayType = MutableFixedSizeListArray<MutablePrimitiveArray<f32>>;
+
+            #[inline]
+            fn new_array() -> Self::MutableArrayType {
+                Self::MutableArrayType::new_with_field(
+                    MutablePrimitiveArray::new(),
+                    LIST_ITEM_NAME,
+                    <f32 as ArrowField>::is_nullable(),
+                    $size,
+                )
+            }
+
+            #[inline]
+            fn arrow_serialize(
+                v: &Self,
+                array: &mut Self::MutableArrayType,
+            ) -> arrow2::error::Result<()> {
+                array.mut_values().extend_from_slice(&v.$to_array());
+                array.try_push_valid()
+            }
+        }
+    };
+}
+
+#[cfg(feature = "glam")]
+impl_glam_serialize!(glam::Vec3, 3, to_array);
+#[cfg(feature = "glam")]
+impl_glam_serialize!(glam::Vec4, 4, to_array);
+#[cfg(feature = "glam")]
+impl_glam_serialize!(glam::Mat4, 16, to_cols_array);
+
+impl ArrowSerialize for Vec<u8> {
+    type MutableArrayType = MutableBinaryArray<i32>;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        Self::MutableArrayType::default()
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::MutableArrayType) -> arrow2::error::Result<()> {
+        array.try_push(Some(v))
+    }
+}
+
+impl ArrowSerialize for Arc<[u8]> {
+    type MutableArrayType = MutableBinaryArray<i32>;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        Self::MutableArrayType::default()
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::MutableArrayType) -> arrow2::error::Result<()> {
+        array.try_push(Some(v.as_ref()))
+    }
+}
+
+impl ArrowSerialize for U8List {
+    type MutableArrayType = MutableListArray<i32, MutablePrimitiveArray<u8>>;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        Self::MutableArrayType::new_with_field(
+            MutablePrimitiveArray::new(),
+            LIST_ITEM_NAME,
+            <u8 as ArrowField>::is_nullable(),
+        )
+    }
+
+    #[inline]
+    fn arrow_serialize(
+        v: &Vec<u8>,
+        array: &mut Self::MutableArrayType,
+    ) -> arrow2::error::Result<()> {
+        array.mut_values().extend_from_slice(v);
+        array.try_push_valid()
+    }
+}
+
+impl ArrowSerialize for LargeBinary {
+    type MutableArrayType = MutableBinaryArray<i64>;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        Self::MutableArrayType::default()
+    }
+
+    #[inline]
+    fn arrow_serialize(
+        v: &Vec<u8>,
+        array: &mut Self::MutableArrayType,
+    ) -> arrow2::error::Result<()> {
+        array.try_push(Some(v))
+    }
+}
+
+impl<const SIZE: usize> ArrowSerialize for FixedSizeBinary<SIZE> {
+    type MutableArrayType = MutableFixedSizeBinaryArray;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        Self::MutableArrayType::new(SIZE)
+    }
+
+    #[inline]
+    fn arrow_serialize(
+        v: &Vec<u8>,
+        array: &mut Self::MutableArrayType,
+    ) -> arrow2::error::Result<()> {
+        if v.len() != SIZE {
+            return Err(arrow2::error::Error::InvalidArgumentError(format!(
+                "FixedSizeBinary<{SIZE}>: expected {SIZE} bytes, got {}",
+                v.len()
+            )));
+        }
+        array.try_push(Some(v))
+    }
+}
+
+impl ArrowSerialize for Ipv4Addr {
+    type MutableArrayType = MutableFixedSizeBinaryArray;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        Self::MutableArrayType::new(4)
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::MutableArrayType) -> arrow2::error::Result<()> {
+        array.try_push(Some(v.octets()))
+    }
+}
+
+impl ArrowSerialize for Ipv6Addr {
+    type MutableArrayType = MutableFixedSizeBinaryArray;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        Self::MutableArrayType::new(16)
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::MutableArrayType) -> arrow2::error::Result<()> {
+        array.try_push(Some(v.octets()))
+    }
+}
+
+impl<T, E> ArrowSerialize for std::result::Result<T, E>
+where
+    T: ArrowSerialize + 'static,
+    E: ArrowSerialize + 'static,
+{
+    type MutableArrayType = MutableResultArray<T, E>;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        MutableDenseUnionArray::new(
+            <Self as ArrowField>::data_type(),
+            <T as ArrowSerialize>::new_array(),
+            <E as ArrowSerialize>::new_array(),
+            |ok| ok.push_null(),
+        )
+    }
+
+    #[inline]
+    fn arrow_serialize(
+        v: &<Self as ArrowField>::Type,
+        array: &mut Self::MutableArrayType,
+    ) -> arrow2::error::Result<()> {
+        match v {
+            Ok(v) => array.try_push_first(|ok| <T as ArrowSerialize>::arrow_serialize(v, ok)),
+            Err(e) => array.try_push_second(|err| <E as ArrowSerialize>::arrow_serialize(e, err)),
+        }
+    }
+}
+
+impl ArrowSerialize for IpAddr {
+    type MutableArrayType = MutableIpAddrArray;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        MutableDenseUnionArray::new(
+            <Self as ArrowField>::data_type(),
+            <Ipv4Addr as ArrowSerialize>::new_array(),
+            <Ipv6Addr as ArrowSerialize>::new_array(),
+            |v4| v4.push_null(),
+        )
+    }
+
+    #[inline]
+    fn arrow_serialize(v: &Self, array: &mut Self::MutableArrayType) -> arrow2::error::Result<()> {
+        match v {
+            IpAddr::V4(addr) => {
+                array.try_push_first(|v4| <Ipv4Addr as ArrowSerialize>::arrow_serialize(addr, v4))
+            }
+            IpAddr::V6(addr) => {
+                array.try_push_second(|v6| <Ipv6Addr as ArrowSerialize>::arrow_serialize(addr, v6))
+            }
+        }
+    }
+}
+
+// Blanket implementation for Buffer
+impl<T> ArrowSerialize for Buffer<T>
+where
+    T: NativeType + ArrowSerialize + ArrowEnableVecForType,
+{
+    type MutableArrayType = MutableListArray<i32, MutablePrimitiveArray<T>>;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        Self::MutableArrayType::new_with_field(
+            MutablePrimitiveArray::new(),
+            LIST_ITEM_NAME,
+            <T as ArrowField>::is_nullable(),
+        )
+    }
+
+    #[inline]
+    fn arrow_serialize(
+        v: &<Self as ArrowField>::Type,
+        array: &mut Self::MutableArrayType,
+    ) -> arrow2::error::Result<()> {
+        let values = array.mut_values();
+        values.reserve(v.len());
+        values.extend_from_slice(v.as_slice());
+        array.try_push_valid()
+    }
+}
+
+// Blanket implementation for Vec
+impl<T> ArrowSerialize for Vec<T>
+where
+    T: ArrowSerialize + ArrowEnableVecForType + 'static,
+{
+    type MutableArrayType = MutableListArray<i32, <T as ArrowSerialize>::MutableArrayType>;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        Self::MutableArrayType::new_with_field(
+            <T as ArrowSerialize>::new_array(),
+            LIST_ITEM_NAME,
+            <T as ArrowField>::is_nullable(),
+        )
+    }
+
+    fn arrow_serialize(
+        v: &<Self as ArrowField>::Type,
+        array: &mut Self::MutableArrayType,
+    ) -> arrow2::error::Result<()> {
+        let values = array.mut_values();
+        values.reserve(v.len());
+        for i in v.iter() {
+            <T as ArrowSerialize>::arrow_serialize(i, values)?;
+        }
+        array.try_push_valid()
+    }
+}
+
+// Other collections are serialized like a `Vec`, as a list of their items in iteration order
+macro_rules! impl_arrow_serialize_vec_like {
+    ($(#[$attr:meta])* [$($generics:tt)*] $collection:ty) => {
+        $(#[$attr])*
+        impl<T, $($generics)*> ArrowSerialize for $collection
+        where
+            T: ArrowSerialize + ArrowEnableVecForType + 'static,
+        {
+            type MutableArrayType = MutableListArray<i32, <T as ArrowSerialize>::MutableArrayType>;
+
+            #[inline]
+            fn new_array() -> Self::MutableArrayType {
+                <Vec<T> as ArrowSerialize>::new_array()
+            }
+
+            fn arrow_serialize(
+                v: &<Self as ArrowField>::Type,
+                array: &mut Self::MutableArrayType,
+            ) -> arrow2::error::Result<()> {
+                let values = array.mut_values();
+                values.reserve(v.len());
+                for i in v.iter() {
+                    <T as ArrowSerialize>::arrow_serialize(i, values)?;
+                }
+                array.try_push_valid()
+            }
+        }
+    };
+}
+
+impl_arrow_serialize_vec_like!([] VecDeque<T>);
+impl_arrow_serialize_vec_like!([] BTreeSet<T>);
+impl_arrow_serialize_vec_like!([] HashSet<T>);
+impl_arrow_serialize_vec_like!(#[cfg(feature = "arrayvec")] [const CAP: usize] arrayvec::ArrayVec<T, CAP>);
+impl_arrow_serialize_vec_like!(#[cfg(feature = "smallvec")] [const N: usize] smallvec::SmallVec<[T; N]>);
+
+impl<T> ArrowSerialize for LargeVec<T>
+where
+    T: ArrowSerialize + ArrowEnableVecForType + 'static,
+{
+    type MutableArrayType = MutableListArray<i64, <T as ArrowSerialize>::MutableArrayType>;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        Self::MutableArrayType::new_with_field(
+            <T as ArrowSerialize>::new_array(),
+            LIST_ITEM_NAME,
+            <T as ArrowField>::is_nullable(),
+        )
+    }
+
+    fn arrow_serialize(
+        v: &<Self as ArrowField>::Type,
+        array: &mut Self::MutableArrayType,
+    ) -> arrow2::error::Result<()> {
+        let values = array.mut_values();
+        values.reserve(v.len());
+        for i in v.iter() {
+            <T as ArrowSerialize>::arrow_serialize(i, values)?;
+        }
+        array.try_push_valid()
+    }
+}
+
+impl<T, const SIZE: usize> ArrowSerialize for FixedSizeVec<T, SIZE>
+where
+    T: ArrowSerialize + ArrowEnableVecForType + 'static,
+{
+    type MutableArrayType = MutableFixedSizeListArray<<T as ArrowSerialize>::MutableArrayType>;
+
+    #[inline]
+    fn new_array() -> Self::MutableArrayType {
+        Self::MutableArrayType::new_with_field(
+            <T as ArrowSerialize>::new_array(),
+            LIST_ITEM_NAME,
+            <T as ArrowField>::is_nullable(),
+            SIZE,
+        )
+    }
+
+    fn arrow_serialize(
+        v: &<Self as ArrowField>::Type,
+        array: &mut Self::MutableArrayType,
+    ) -> arrow2::error::Result<()> {
+        let values = array.mut_values();
+        values.reserve(v.len());
+        for i in v.iter() {
+            <T as ArrowSerialize>::arrow_serialize(i, values)?;
+        }
+        array.try_push_valid()
+    }
+}
+
+/// A [`MutableArray`] used to build an [`arrow2::array::MapArray`] from mutable key and value arrays.
+///
+/// arrow2 does not provide a mutable map array, so this is the `MutableArrayType` of the
+/// blanket [`HashMap`] implementation.
+#[derive(Debug)]
+pub struct MutableMapArray<K: MutableArray, V: MutableArray> {
+    data_type: DataType,
+    entries_data_type: DataType,
+    offsets: Offsets<i32>,
+    keys: K,
+    values: V,
+    validity: Option<MutableBitmap>,
+}
+
+impl<K: MutableArray, V: MutableArray> MutableMapArray<K, V> {
+    /// Creates a new [`MutableMapArray`] from the map `data_type` and the key and value arrays.
+    /// # Panics
+    /// Panics if `data_type` is not a [`DataType::Map`].
+    pub fn new_with_data_type(data_type: DataType, keys: K, values: V) -> Self {
+        let entries_data_type = match data_type.to_logical_type() {
+            DataType::Map(field, _) => field.data_type().clone(),
+            _ => panic!("MutableMapArray expects DataType::Map"),
+        };
+        Self {
+            data_type,
+            entries_data_type,
+            offsets: Offsets::new(),
+            keys,
+            values,
+            validity: None,
+        }
+    }
+
+    /// The keys
+    pub fn mut_keys(&mut self) -> &mut K {
+        &mut self.keys
+    }
+
+    /// The values
+    pub fn mut_values(&mut self) -> &mut V {
+        &mut self.values
+    }
+
+    /// Needs to be called when a valid map was extended to this array.
+    pub fn try_push_valid(&mut self) -> arrow2::error::Result<()> {
+        if self.keys.len() != self.values.len() {
+            return Err(arrow2::error::Error::InvalidArgumentError(
+                "MutableMapArray keys and values must have the same length".to_string(),
+            ));
+        }
+        let length = self
+            .keys
+            .len()
+            .checked_sub(*self.offsets.last() as usize)
+            .ok_or(arrow2::error::Error::Overflow)?;
+        self.offsets.try_push_usize(length)?;
+        if let Some(validity) = &mut self.validity {
+            validity.push(true)
+        }
+        Ok(())
+    }
+
+    fn init_validity(&mut self) {
+        let len = self.offsets.len_proxy();
+        let mut validity = MutableBitmap::with_capacity(self.offsets.capacity());
+        validity.extend_constant(len, true);
+        validity.set(len - 1, false);
+        self.validity = Some(validity)
+    }
+
+    fn take_map_array(&mut self) -> MapArray {
+        let entries = StructArray::new(
+            self.entries_data_type.clone(),
+            vec![self.keys.as_box(), self.values.as_box()],
+            None,
+        );
+        MapArray::new(
+            self.data_type.clone(),
+            std::mem::take(&mut self.offsets).into(),
+            entries.boxed(),
+            std::mem::take(&mut self.validity).map(|x| x.into()),
+        )
+    }
+}
+
+impl<K: MutableArray + 'static, V: MutableArray + 'static> MutableArray for MutableMapArray<K, V> {
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn len(&self) -> usize {
+        self.offsets.len_proxy()
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        self.validity.as_ref()
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        self.take_map_array().boxed()
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        self.take_map_array().arced()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn push_null(&mut self) {
+        self.offsets.extend_constant(1);
+        match &mut self.validity {
+            Some(validity) => validity.push(false),
+            None => self.init_validity(),
+        }
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.offsets.reserve(additional);
+        if let Some(x) = self.validity.as_mut() {
+            x.reserve(additional)
+        }
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.keys.shrink_to_fit();
+        self.values.shrink_to_fit();
+        self.offsets.shrink_to_fit();
+        if let Some(validity) = &mut self.validity {
+            validity.shrink_to_fit();
+        }
+    }
+}
+
+// Blanket implementation for HashMap
+// Serializes the entries of a single map value
+fn arrow_serialize_map_entries<'a, K, V>(
+    entries: impl Iterator<Item = (&'a <K as ArrowField>::Type, &'a <V as ArrowField>::Type)>,
+    array: &mut MutableMapArray<
+        <K as ArrowSerialize>::MutableArrayType,
+        <V as ArrowSerialize>::MutableArrayType,
+    >,
+) -> arrow2::error::Result<()>
+where
+    K: ArrowSerialize + 'static,
+    V: ArrowSerialize + 'static,
+{
+    for (key, value) in entries {
+        <K as ArrowSerialize>::arrow_serialize(key, array.mut_keys())?;
+        <V as ArrowSerialize>::arrow_serialize(value, array.mut_values())?;
+    }
+    array.try_push_valid()
+}
+
+macro_rules! impl_arrow_serialize_map {
+    ($map:ident) => {
+        impl<K, V> ArrowSerialize for $map<K, V>
+        where
+            K: ArrowSerialize + 'static,
+            V: ArrowSerialize + 'static,
+        {
+            type MutableArrayType = MutableMapArray<
+                <K as ArrowSerialize>::MutableArrayType,
+                <V as ArrowSerialize>::MutableArrayType,
+            >;
+
+            #[inline]
+            fn new_array() -> Self::MutableArrayType {
+                Self::MutableArrayType::new_with_data_type(
+                    <Self as ArrowField>::data_type(),
+                    <K as ArrowSerialize>::new_array(),
+                    <V as ArrowSerialize>::new_array(),
+                )
+            }
+
+            fn arrow_serialize(
+                v: &<Self as ArrowField>::Type,
+                array: &mut Self::MutableArrayType,
+            ) -> arrow2::error::Result<()> {
+                arrow_serialize_map_entries::<K, V>(v.iter(), array)
+            }
+        }
+    };
+}
+
+impl_arrow_serialize_map!(HashMap);
+impl_arrow_serialize_map!(BTreeMap);
+
+/// A [`MutableArray`] used to build a dense [`UnionArray`] with two variants, whose values are pushed to
+/// the mutable arrays `A` and `B`.
+pub struct MutableDenseUnionArray<A: MutableArray, B: MutableArray> {
+    data_type: DataType,
+    types: Vec<i8>,
+    offsets: Vec<i32>,
+    first: A,
+    second: B,
+    // unions don't have a validity, so nulls are stored as a row of the first variant
+    push_null: fn(&mut A),
+}
+
+impl<A: MutableArray, B: MutableArray> std::fmt::Debug for MutableDenseUnionArray<A, B> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MutableDenseUnionArray")
+            .field("data_type", &self.data_type)
+            .field("types", &self.types)
+            .field("offsets", &self.offsets)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: MutableArray, B: MutableArray> MutableDenseUnionArray<A, B> {
+    /// Creates an empty union array of the given data type, where `push_null` pushes the row of the
+    /// first variant that a null is stored as.
+    pub fn new(data_type: DataType, first: A, second: B, push_null: fn(&mut A)) -> Self {
+        Self {
+            data_type,
+            types: vec![],
+            offsets: vec![],
+            first,
+            second,
+            push_null,
+        }
+    }
+
+    /// Pushes a row of the first variant, whose value is pushed to its array by `push`.
+    pub fn try_push_first(
+        &mut self,
+        push: impl FnOnce(&mut A) -> arrow2::error::Result<()>,
+    ) -> arrow2::error::Result<()> {
+        let offset = self.first.len() as i32;
+        push(&mut self.first)?;
+        self.offsets.push(offset);
+        self.types.push(0);
+        Ok(())
+    }
+
+    /// Pushes a row of the second variant, whose value is pushed to its array by `push`.
+    pub fn try_push_second(
+        &mut self,
+        push: impl FnOnce(&mut B) -> arrow2::error::Result<()>,
+    ) -> arrow2::error::Result<()> {
+        let offset = self.second.len() as i32;
+        push(&mut self.second)?;
+        self.offsets.push(offset);
+        self.types.push(1);
+        Ok(())
+    }
+
+    fn take_union_array(&mut self) -> UnionArray {
+        UnionArray::new(
+            self.data_type.clone(),
+            std::mem::take(&mut self.types).into(),
+            vec![self.first.as_box(), self.second.as_box()],
+            Some(std::mem::take(&mut self.offsets).into()),
+        )
+    }
+}
+
+impl<A, B> MutableArray for MutableDenseUnionArray<A, B>
+where
+    A: MutableArray + 'static,
+    B: MutableArray + 'static,
+{
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn len(&self) -> usize {
+        self.types.len()
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        None
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        self.take_union_array().boxed()
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        self.take_union_array().arced()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn push_null(&mut self) {
+        self.offsets.push(self.first.len() as i32);
+        (self.push_null)(&mut self.first);
+        self.types.push(0);
+    }
+
+    // it isn't known which variant the rows belong to, so both are reserved
+    fn reserve(&mut self, additional: usize) {
+        self.types.reserve(additional);
+        self.offsets.reserve(additional);
+        self.first.reserve(additional);
+        self.second.reserve(additional);
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.types.shrink_to_fit();
+        self.offsets.shrink_to_fit();
+        self.first.shrink_to_fit();
+        self.second.shrink_to_fit();
+    }
+}
+
+/// A [`MutableArray`] used to build the dense [`UnionArray`] of [`IpAddr`] values.
+pub type MutableIpAddrArray =
+    MutableDenseUnionArray<MutableFixedSizeBinaryArray, MutableFixedSizeBinaryArray>;
+
+/// A [`MutableArray`] used to build the dense [`UnionArray`] of [`Result`] values.
+pub type MutableResultArray<T, E> = MutableDenseUnionArray<
+    <T as ArrowSerialize>::MutableArrayType,
+    <E as ArrowSerialize>::MutableArrayType,
+>;
+
+/// A [`MutableArray`] used to build the [`StructArray`] of [`DoubleOption`] values.
+pub struct MutableDoubleOptionArray<T: ArrowSerialize> {
+    data_type: DataType,
+    validity: MutableBitmap,
+    value: <T as ArrowSerialize>::MutableArrayType,
+}
+
+impl<T> std::fmt::Debug for MutableDoubleOptionArray<T>
+where
+    T: ArrowSerialize,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MutableDoubleOptionArray")
+            .field("data_type", &self.data_type)
+            .field("validity", &self.validity)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> Default for MutableDoubleOptionArray<T>
+where
+    T: ArrowSerialize,
+{
+    fn default() -> Self {
+        Self {
+            data_type: <DoubleOption<T> as ArrowField>::data_type(),
+            validity: MutableBitmap::new(),
+            value: <T as ArrowSerialize>::new_array(),
+        }
+    }
+}
+
+impl<T> MutableDoubleOptionArray<T>
+where
+    T: ArrowSerialize,
+{
+    /// Pushes a value, storing the outer `None` as a null struct and `Some(None)` as a null `value`.
+    pub fn try_push(
+        &mut self,
+        v: &Option<Option<<T as ArrowField>::Type>>,
+    ) -> arrow2::error::Result<()> {
+        match v {
+            Some(Some(v)) => <T as ArrowSerialize>::arrow_serialize(v, &mut self.value)?,
+            Some(None) | None => self.value.push_null(),
+        }
+        self.validity.push(v.is_some());
+        Ok(())
+    }
+
+    fn take_struct_array(&mut self) -> StructArray {
+        StructArray::new(
+            self.data_type.clone(),
+            vec![self.value.as_box()],
+            Some(std::mem::take(&mut self.validity).into()),
+        )
+    }
+}
+
+impl<T> MutableArray for MutableDoubleOptionArray<T>
+where
+    T: ArrowSerialize + 'static,
+{
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn len(&self) -> usize {
+        self.validity.len()
+    }
 
-    #[inline]
-    fn new_array() -> Self::MutableArrayType {
-        Self::MutableArrayType::default()
+    fn validity(&self) -> Option<&MutableBitmap> {
+        Some(&self.validity)
     }
 
-    #[inline]
-    fn arrow_serialize(v: &Self, array: &mut Self::MutableArrayType) -> arrow2::error::Result<()> {
-        array.try_push(Some(v.as_slice()))
+    fn as_box(&mut self) -> Box<dyn Array> {
+        self.take_struct_array().boxed()
     }
-}
 
-impl ArrowSerialize for Vec<u8> {
-    type MutableArrayType = MutableBinaryArray<i32>;
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        self.take_struct_array().arced()
+    }
 
-    #[inline]
-    fn new_array() -> Self::MutableArrayType {
-        Self::MutableArrayType::default()
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
     }
 
-    #[inline]
-    fn arrow_serialize(v: &Self, array: &mut Self::MutableArrayType) -> arrow2::error::Result<()> {
-        array.try_push(Some(v))
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
     }
-}
 
-impl ArrowSerialize for LargeBinary {
-    type MutableArrayType = MutableBinaryArray<i64>;
+    fn push_null(&mut self) {
+        self.value.push_null();
+        self.validity.push(false);
+    }
 
-    #[inline]
-    fn new_array() -> Self::MutableArrayType {
-        Self::MutableArrayType::default()
+    fn reserve(&mut self, additional: usize) {
+        self.validity.reserve(additional);
+        self.value.reserve(additional);
     }
 
-    #[inline]
-    fn arrow_serialize(
-        v: &Vec<u8>,
-        array: &mut Self::MutableArrayType,
-    ) -> arrow2::error::Result<()> {
-        array.try_push(Some(v))
+    fn shrink_to_fit(&mut self) {
+        self.validity.shrink_to_fit();
+        self.value.shrink_to_fit();
     }
 }
 
-impl<const SIZE: usize> ArrowSerialize for FixedSizeBinary<SIZE> {
-    type MutableArrayType = MutableFixedSizeBinaryArray;
+impl<K, V> ArrowSerialize for MapEntries<K, V>
+where
+    K: ArrowSerialize + 'static,
+    V: ArrowSerialize + 'static,
+{
+    type MutableArrayType = MutableMapArray<
+        <K as ArrowSerialize>::MutableArrayType,
+        <V as ArrowSerialize>::MutableArrayType,
+    >;
 
     #[inline]
     fn new_array() -> Self::MutableArrayType {
-        Self::MutableArrayType::new(SIZE)
+        Self::MutableArrayType::new_with_data_type(
+            <Self as ArrowField>::data_type(),
+            <K as ArrowSerialize>::new_array(),
+            <V as ArrowSerialize>::new_array(),
+        )
     }
 
-    #[inline]
     fn arrow_serialize(
-        v: &Vec<u8>,
+        v: &<Self as ArrowField>::Type,
         array: &mut Self::MutableArrayType,
     ) -> arrow2::error::Result<()> {
-        array.try_push(Some(v))
+        arrow_serialize_map_entries::<K, V>(v.0.iter().map(|(k, v)| (k, v)), array)
     }
 }
 
-// Blanket implementation for Buffer
-impl<T> ArrowSerialize for Buffer<T>
+impl<K, V> ArrowSerialize for Map<K, V>
 where
-    T: NativeType + ArrowSerialize + ArrowEnableVecForType,
+    K: ArrowSerialize + 'static,
+    V: ArrowSerialize + 'static,
 {
-    type MutableArrayType = MutableListArray<i32, MutablePrimitiveArray<T>>;
+    type MutableArrayType = MutableMapArray<
+        <K as ArrowSerialize>::MutableArrayType,
+        <V as ArrowSerialize>::MutableArrayType,
+    >;
 
     #[inline]
     fn new_array() -> Self::MutableArrayType {
-        Self::MutableArrayType::new_with_field(
-            MutablePrimitiveArray::new(),
-            "item",
-            <T as ArrowField>::is_nullable(),
+        Self::MutableArrayType::new_with_data_type(
+            <Self as ArrowField>::data_type(),
+            <K as ArrowSerialize>::new_array(),
+            <V as ArrowSerialize>::new_array(),
         )
     }
 
-    #[inline]
     fn arrow_serialize(
         v: &<Self as ArrowField>::Type,
         array: &mut Self::MutableArrayType,
     ) -> arrow2::error::Result<()> {
-        let values = array.mut_values();
-        values.reserve(v.len());
-        values.extend_from_slice(v.as_slice());
-        array.try_push_valid()
+        arrow_serialize_map_entries::<K, V>(v.iter().map(|(k, v)| (k, v)), array)
     }
 }
 
-// Blanket implementation for Vec
-impl<T> ArrowSerialize for Vec<T>
+// Compares the values of an ordered dictionary
+type CompareFn<T> = fn(&T, &T) -> std::cmp::Ordering;
+
+/// The mutable array of a [`Dictionary`] field. The first occurrence of each distinct value is serialized
+/// into the values array, and every row pushes the key of its value. Ordered dictionaries sort their values
+/// and remap the keys when the array is finished.
+pub struct MutableDictionaryFieldArray<K, V>
 where
-    T: ArrowSerialize + ArrowEnableVecForType + 'static,
-    <T as ArrowSerialize>::MutableArrayType: Default,
+    K: arrow2::array::DictionaryKey,
+    V: ArrowSerialize,
 {
-    type MutableArrayType = MutableListArray<i32, <T as ArrowSerialize>::MutableArrayType>;
+    data_type: DataType,
+    keys: MutablePrimitiveArray<K>,
+    map: HashMap<<V as ArrowField>::Type, K>,
+    values: <V as ArrowSerialize>::MutableArrayType,
+    compare: Option<CompareFn<<V as ArrowField>::Type>>,
+}
 
-    #[inline]
-    fn new_array() -> Self::MutableArrayType {
-        Self::MutableArrayType::new_with_field(
-            <T as ArrowSerialize>::new_array(),
-            "item",
-            <T as ArrowField>::is_nullable(),
-        )
+impl<K, V> MutableDictionaryFieldArray<K, V>
+where
+    K: arrow2::array::DictionaryKey,
+    V: ArrowSerialize,
+    <V as ArrowField>::Type: Hash + Eq + Clone,
+{
+    /// Creates an empty array of the dictionary `data_type`, whose values are serialized as `V`.
+    pub fn new(data_type: DataType) -> Self {
+        Self {
+            data_type,
+            keys: MutablePrimitiveArray::new(),
+            map: HashMap::new(),
+            values: <V as ArrowSerialize>::new_array(),
+            compare: None,
+        }
     }
 
-    fn arrow_serialize(
-        v: &<Self as ArrowField>::Type,
-        array: &mut Self::MutableArrayType,
-    ) -> arrow2::error::Result<()> {
-        let values = array.mut_values();
-        for i in v.iter() {
-            <T as ArrowSerialize>::arrow_serialize(i, values)?;
+    /// Same as `new`, except that the values are sorted with `compare` when the array is finished.
+    pub fn new_sorted(data_type: DataType, compare: CompareFn<<V as ArrowField>::Type>) -> Self {
+        Self {
+            compare: Some(compare),
+            ..Self::new(data_type)
         }
-        array.try_push_valid()
+    }
+
+    /// Pushes the key of `v`, serializing `v` into the values if it's the first occurrence.
+    pub fn try_push_value(&mut self, v: &<V as ArrowField>::Type) -> arrow2::error::Result<()> {
+        let key = match self.map.get(v) {
+            Some(key) => *key,
+            None => {
+                let key =
+                    K::try_from(self.map.len()).map_err(|_| arrow2::error::Error::Overflow)?;
+                <V as ArrowSerialize>::arrow_serialize(v, &mut self.values)?;
+                self.map.insert(v.clone(), key);
+                key
+            }
+        };
+        self.keys.push(Some(key));
+        Ok(())
     }
 }
 
-impl<T> ArrowSerialize for LargeVec<T>
+impl<K, V> std::fmt::Debug for MutableDictionaryFieldArray<K, V>
 where
-    T: ArrowSerialize + ArrowEnableVecForType + 'static,
-    <T as ArrowSerialize>::MutableArrayType: Default,
+    K: arrow2::array::DictionaryKey,
+    V: ArrowSerialize,
 {
-    type MutableArrayType = MutableListArray<i64, <T as ArrowSerialize>::MutableArrayType>;
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MutableDictionaryFieldArray")
+            .field("data_type", &self.data_type)
+            .field("keys", &self.keys)
+            .field("values", &self.values)
+            .finish()
+    }
+}
+
+impl<K, V> MutableArray for MutableDictionaryFieldArray<K, V>
+where
+    K: arrow2::array::DictionaryKey,
+    V: ArrowSerialize + 'static,
+    <V as ArrowField>::Type: Send + Sync + 'static,
+{
+    fn data_type(&self) -> &DataType {
+        &self.data_type
+    }
+
+    fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    fn validity(&self) -> Option<&MutableBitmap> {
+        self.keys.validity()
+    }
+
+    fn as_box(&mut self) -> Box<dyn Array> {
+        let keys: PrimitiveArray<K> = std::mem::take(&mut self.keys).into();
+        let mut values = self.values.as_box();
+        // the values are taken along with the keys, so the next keys start from an empty dictionary
+        let map = std::mem::take(&mut self.map);
+        let (keys, values) = match self.compare {
+            Some(compare) => {
+                let to_index = |key: K| match key.try_into() {
+                    Ok(index) => index,
+                    Err(_) => unreachable!("keys are created from the index of their value"),
+                };
+                let mut entries = map.into_iter().collect::<Vec<_>>();
+                entries.sort_by(|(a, _), (b, _)| compare(a, b));
+                // the new key of each value is its position once sorted
+                let mut remap = vec![K::default(); entries.len()];
+                for (new_key, (value, old_key)) in entries.iter().enumerate() {
+                    remap[to_index(*old_key)] = K::try_from(new_key)
+                        .unwrap_or_else(|_| unreachable!("there are as many keys as values"));
+                    <V as ArrowSerialize>::arrow_serialize(value, &mut self.values)
+                        .expect("the value was serialized when it was pushed");
+                }
+                values = self.values.as_box();
+                let keys = keys
+                    .iter()
+                    .map(|key| key.map(|key| remap[to_index(*key)]))
+                    .collect::<PrimitiveArray<K>>()
+                    .to(keys.data_type().clone());
+                (keys, values)
+            }
+            None => (keys, values),
+        };
+        DictionaryArray::<K>::try_new(self.data_type.clone(), keys, values)
+            .unwrap()
+            .boxed()
+    }
+
+    fn as_arc(&mut self) -> Arc<dyn Array> {
+        self.as_box().into()
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn push_null(&mut self) {
+        self.keys.push(None)
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.keys.reserve(additional)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.keys.shrink_to_fit();
+        self.values.shrink_to_fit();
+    }
+}
+
+impl<K, V> ArrowSerialize for Dictionary<K, V, false>
+where
+    K: arrow2::array::DictionaryKey,
+    V: ArrowSerialize + 'static,
+    <V as ArrowField>::Type: Hash + Eq + Clone + Send + Sync + 'static,
+{
+    type MutableArrayType = MutableDictionaryFieldArray<K, V>;
 
     #[inline]
     fn new_array() -> Self::MutableArrayType {
-        Self::MutableArrayType::new_with_field(
-            <T as ArrowSerialize>::new_array(),
-            "item",
-            <T as ArrowField>::is_nullable(),
-        )
+        MutableDictionaryFieldArray::new(<Self as ArrowField>::data_type())
     }
 
+    #[inline]
     fn arrow_serialize(
         v: &<Self as ArrowField>::Type,
         array: &mut Self::MutableArrayType,
     ) -> arrow2::error::Result<()> {
-        let values = array.mut_values();
-        for i in v.iter() {
-            <T as ArrowSerialize>::arrow_serialize(i, values)?;
-        }
-        array.try_push_valid()
+        array.try_push_value(v)
     }
 }
 
-impl<T, const SIZE: usize> ArrowSerialize for FixedSizeVec<T, SIZE>
+// The values of ordered dictionaries are sorted, as the `is_ordered` flag promises
+impl<K, V> ArrowSerialize for Dictionary<K, V, true>
 where
-    T: ArrowSerialize + ArrowEnableVecForType + 'static,
-    <T as ArrowSerialize>::MutableArrayType: Default,
+    K: arrow2::array::DictionaryKey,
+    V: ArrowSerialize + 'static,
+    <V as ArrowField>::Type: Hash + Ord + Clone + Send + Sync + 'static,
 {
-    type MutableArrayType = MutableFixedSizeListArray<<T as ArrowSerialize>::MutableArrayType>;
+    type MutableArrayType = MutableDictionaryFieldArray<K, V>;
 
     #[inline]
     fn new_array() -> Self::MutableArrayType {
-        Self::MutableArrayType::new_with_field(
-            <T as ArrowSerialize>::new_array(),
-            "item",
-            <T as ArrowField>::is_nullable(),
-            SIZE,
-        )
+        MutableDictionaryFieldArray::new_sorted(<Self as ArrowField>::data_type(), Ord::cmp)
     }
 
+    #[inline]
     fn arrow_serialize(
         v: &<Self as ArrowField>::Type,
         array: &mut Self::MutableArrayType,
     ) -> arrow2::error::Result<()> {
-        let values = array.mut_values();
-        for i in v.iter() {
-            <T as ArrowSerialize>::arrow_serialize(i, values)?;
-        }
-        array.try_push_valid()
+        array.try_push_value(v)
     }
 }
 
@@ -365,15 +1589,60 @@ fn arrow_serialize_extend_internal<
 >(
     into_iter: I,
     array: &mut <T as ArrowSerialize>::MutableArrayType,
-) -> arrow2::error::Result<()> {
+) -> Result<()> {
     let iter = into_iter.into_iter();
     array.reserve(iter.size_hint().0);
     for i in iter {
-        <T as ArrowSerialize>::arrow_serialize(i, array)?;
+        <T as ArrowSerialize>::arrow_serialize(i, array).map_err(Error::Serialize)?;
     }
     Ok(())
 }
 
+/// For internal use by the derive macro.
+///
+/// Rebuilds a list array with the nullability of its child set to `nullable`, without copying its buffers.
+/// Arrays other than lists are returned unchanged.
+#[doc(hidden)]
+pub fn arrow_array_with_item_nullable(array: Box<dyn Array>, nullable: bool) -> Box<dyn Array> {
+    let data_type = list_with_item_nullable(array.data_type().clone(), nullable);
+    let any = array.as_any();
+    if let Some(list) = any.downcast_ref::<ListArray<i32>>() {
+        ListArray::new(
+            data_type,
+            list.offsets().clone(),
+            list.values().clone(),
+            list.validity().cloned(),
+        )
+        .boxed()
+    } else if let Some(list) = any.downcast_ref::<ListArray<i64>>() {
+        ListArray::new(
+            data_type,
+            list.offsets().clone(),
+            list.values().clone(),
+            list.validity().cloned(),
+        )
+        .boxed()
+    } else if let Some(list) = any.downcast_ref::<FixedSizeListArray>() {
+        FixedSizeListArray::new(data_type, list.values().clone(), list.validity().cloned()).boxed()
+    } else {
+        array
+    }
+}
+
+/// For internal use by the derive macro.
+///
+/// Returns the child arrays of a struct array, so that a flattened field can be inlined into the
+/// arrays of its parent.
+#[doc(hidden)]
+pub fn struct_array_values(array: Box<dyn Array>) -> Vec<Box<dyn Array>> {
+    array
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .expect("'flatten' requires a struct field")
+        .values()
+        .to_vec()
+}
+
 /// Serializes an iterator into an `arrow2::MutableArray`
 pub fn arrow_serialize_to_mutable_array<
     'a,
@@ -382,30 +1651,217 @@ pub fn arrow_serialize_to_mutable_array<
     I: IntoIterator<Item = &'a A>,
 >(
     into_iter: I,
-) -> arrow2::error::Result<<T as ArrowSerialize>::MutableArrayType> {
+) -> Result<<T as ArrowSerialize>::MutableArrayType> {
+    check_field_names(&<T as ArrowField>::data_type())?;
     let mut arr = <T as ArrowSerialize>::new_array();
     arrow_serialize_extend_internal::<A, T, I>(into_iter, &mut arr)?;
     Ok(arr)
 }
 
+/// Serializes an iterator into a sequence of arrays of at most `batch_size` rows each, so that large inputs
+/// can be written incrementally. The final array may be smaller.
+///
+/// # Panics
+///
+/// Panics if `batch_size` is 0.
+pub fn try_into_arrow_chunks<
+    'a,
+    A: 'static,
+    T: ArrowSerialize + ArrowField<Type = A> + 'static,
+    I: IntoIterator<Item = &'a A>,
+>(
+    into_iter: I,
+    batch_size: usize,
+) -> impl Iterator<Item = Result<Box<dyn Array>>> + 'a
+where
+    I::IntoIter: 'a,
+{
+    assert!(batch_size > 0, "batch_size must be greater than 0");
+    let mut iter = into_iter.into_iter().peekable();
+    std::iter::from_fn(move || {
+        iter.peek()?;
+        Some(
+            arrow_serialize_to_mutable_array::<A, T, _>(iter.by_ref().take(batch_size))
+                .map(|mut array| array.as_box()),
+        )
+    })
+}
+
+/// Serializes an iterator of key/value pairs into two arrays of the same length, one containing the
+/// keys and one containing the values.
+pub fn try_into_columns<
+    'a,
+    K: ArrowSerialize + ArrowField<Type = K> + 'static,
+    V: ArrowSerialize + ArrowField<Type = V> + 'static,
+    I: IntoIterator<Item = &'a (K, V)>,
+>(
+    into_iter: I,
+) -> Result<(Box<dyn Array>, Box<dyn Array>)> {
+    let iter = into_iter.into_iter();
+    let mut keys = <K as ArrowSerialize>::new_array();
+    let mut values = <V as ArrowSerialize>::new_array();
+    keys.reserve(iter.size_hint().0);
+    values.reserve(iter.size_hint().0);
+    for (k, v) in iter {
+        <K as ArrowSerialize>::arrow_serialize(k, &mut keys).map_err(Error::Serialize)?;
+        <V as ArrowSerialize>::arrow_serialize(v, &mut values).map_err(Error::Serialize)?;
+    }
+    Ok((keys.as_box(), values.as_box()))
+}
+
+/// Serializes an iterator of structs into an `arrow2::array::StructArray` containing only the fields
+/// named in `field_names`, in the given order.
+///
+/// Returns an error if `T` is not a struct or if any of the requested fields doesn't exist.
+pub fn serialize_subset<
+    'a,
+    A: 'static,
+    T: ArrowSerialize + ArrowField<Type = A> + 'static,
+    I: IntoIterator<Item = &'a A>,
+>(
+    into_iter: I,
+    field_names: &[&str],
+) -> Result<Box<dyn Array>> {
+    let fields = match <T as ArrowField>::data_type() {
+        DataType::Struct(fields) => fields,
+        data_type => {
+            return Err(Error::InvalidArgument(format!(
+                "Expected a struct data type to serialize a subset of fields, found {data_type:?}"
+            )))
+        }
+    };
+    let indices = field_names
+        .iter()
+        .map(|name| {
+            fields
+                .iter()
+                .position(|f| f.name == *name)
+                .ok_or_else(|| Error::InvalidArgument(format!("Field `{name}` does not exist")))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let array = arrow_serialize_to_mutable_array::<A, T, I>(into_iter)?.as_box();
+    let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+    Ok(StructArray::new(
+        DataType::Struct(indices.iter().map(|i| fields[*i].clone()).collect()),
+        indices.iter().map(|i| array.values()[*i].clone()).collect(),
+        array.validity().cloned(),
+    )
+    .boxed())
+}
+
+/// Serializes several collections of the same length into a `Chunk` with one column per collection, in
+/// the given order. Each collection can have a different element type, for example two `Vec`s of
+/// different structs. Evaluates to a `Result` that is an error if a collection fails to serialize or the
+/// lengths of the collections differ.
+///
+/// ```
+/// # use arrow2_convert::try_into_chunk;
+/// let ids = vec![1_i64, 2];
+/// let names = vec!["a".to_string(), "b".to_string()];
+/// let chunk = try_into_chunk!(&ids, &names).unwrap();
+/// assert_eq!(chunk.arrays().len(), 2);
+/// ```
+#[macro_export]
+macro_rules! try_into_chunk {
+    ($($column:expr),+ $(,)?) => {
+        $crate::serialize::chunk_from_columns(vec![
+            $($crate::serialize::TryIntoArrow::try_into_arrow($column),)+
+        ])
+    };
+}
+
+/// For internal use by the [`try_into_chunk`] macro.
+///
+/// Builds a `Chunk` from serialized columns, returning the first serialization error or an error if the
+/// columns have different lengths.
+#[doc(hidden)]
+pub fn chunk_from_columns(columns: Vec<Result<Box<dyn Array>>>) -> Result<Chunk<Box<dyn Array>>> {
+    let columns = columns.into_iter().collect::<Result<Vec<_>>>()?;
+    if let Some(first) = columns.first() {
+        if let Some((index, column)) = columns
+            .iter()
+            .enumerate()
+            .find(|(_, c)| c.len() != first.len())
+        {
+            return Err(Error::InvalidArgument(format!(
+                "Column {index} has {} rows, expected {} rows like column 0",
+                column.len(),
+                first.len()
+            )));
+        }
+    }
+    Ok(Chunk::try_new(columns)?)
+}
+
+/// Concatenates arrays of the same data type, for example arrays serialized from separate batches,
+/// into a single array.
+///
+/// Returns an error if `arrays` is empty or if the data types of the arrays don't match.
+pub fn concat_arrays(arrays: &[Box<dyn Array>]) -> Result<Box<dyn Array>> {
+    let first = arrays
+        .first()
+        .ok_or_else(|| Error::InvalidArgument("Cannot concatenate zero arrays".to_string()))?;
+    for array in &arrays[1..] {
+        if let Some((path, expected, found)) =
+            crate::deserialize::find_data_type_mismatch(first.data_type(), array.data_type())
+        {
+            return Err(Error::SchemaMismatch {
+                path,
+                expected: Box::new(expected),
+                found: Box::new(found),
+            });
+        }
+    }
+    let arrays = arrays.iter().map(|a| a.as_ref()).collect::<Vec<_>>();
+    Ok(arrow2::compute::concatenate::concatenate(&arrays)?)
+}
+
+/// Serializes `slice` in chunks of `chunk_size` elements on the rayon thread pool, and concatenates the
+/// serialized chunks in order with [`concat_arrays`]. The result is the same array as
+/// [`TryIntoArrow::try_into_arrow`] returns for `slice`.
+///
+/// Returns an error if `chunk_size` is zero. Requires the `rayon` feature.
+#[cfg(feature = "rayon")]
+pub fn try_into_arrow_parallel<T>(slice: &[T], chunk_size: usize) -> Result<Box<dyn Array>>
+where
+    T: ArrowSerialize + ArrowField<Type = T> + Sync + 'static,
+{
+    use rayon::prelude::*;
+
+    if chunk_size == 0 {
+        return Err(Error::InvalidArgument(
+            "The chunk size must be greater than zero".to_string(),
+        ));
+    }
+    if slice.is_empty() {
+        return slice.try_into_arrow();
+    }
+    let chunks = slice
+        .par_chunks(chunk_size)
+        .map(|chunk| chunk.try_into_arrow())
+        .collect::<Result<Vec<Box<dyn Array>>>>()?;
+    concat_arrays(&chunks)
+}
+
 /// API to flatten a Chunk consisting of an `arrow2::array::StructArray` into a `Chunk` consisting of `arrow2::array::Array`s contained by the `StructArray`
 pub trait FlattenChunk {
     /// Convert an `arrow2::chunk::Chunk` containing a `arrow2::array::StructArray` to an `arrow2::chunk::Chunk` consisting of the
     /// `arrow::array::Array`s contained by the `StructArray` by consuming the
     /// original `Chunk`. Returns an error if the `Chunk` cannot be flattened.
-    fn flatten(self) -> Result<Chunk<Box<dyn Array>>, arrow2::error::Error>;
+    fn flatten(self) -> Result<Chunk<Box<dyn Array>>>;
 }
 
 impl<A> FlattenChunk for Chunk<A>
 where
     A: AsRef<dyn Array>,
 {
-    fn flatten(self) -> Result<Chunk<Box<dyn Array>>, arrow2::error::Error> {
+    fn flatten(self) -> Result<Chunk<Box<dyn Array>>> {
         let arrays = self.into_arrays();
 
         // we only support flattening of a Chunk containing a single StructArray
         if arrays.len() != 1 {
-            return Err(arrow2::error::Error::InvalidArgumentError(
+            return Err(Error::InvalidArgument(
                 "Chunk must contain a single Array".to_string(),
             ));
         }
@@ -414,7 +1870,7 @@ where
 
         let physical_type = array.as_ref().data_type().to_physical_type();
         if physical_type != arrow2::datatypes::PhysicalType::Struct {
-            return Err(arrow2::error::Error::InvalidArgumentError(
+            return Err(Error::InvalidArgument(
                 "Array in Chunk must be of type arrow2::datatypes::PhysicalType::Struct"
                     .to_string(),
             ));
@@ -429,6 +1885,38 @@ where
     }
 }
 
+/// Top-level API to serialize a collection of structs to a `Chunk` of columns and a matching `Schema`
+pub trait TryIntoRecordBatch<'a, Element>
+where
+    Self: IntoIterator<Item = &'a Element>,
+    Element: 'static,
+{
+    /// Convert from any iterable collection of structs into an `arrow2::chunk::Chunk` containing one column
+    /// per struct field, and the `arrow2::datatypes::Schema` describing those columns. The result can be passed
+    /// directly to the `arrow2` IPC and parquet writers. Returns an error if `Element` is not a struct.
+    fn try_into_record_batch(self) -> Result<(Chunk<Box<dyn Array>>, Schema)>;
+}
+
+impl<'a, Element, Collection> TryIntoRecordBatch<'a, Element> for Collection
+where
+    Element: ArrowSerialize + ArrowField<Type = Element> + 'static,
+    Collection: IntoIterator<Item = &'a Element>,
+{
+    fn try_into_record_batch(self) -> Result<(Chunk<Box<dyn Array>>, Schema)> {
+        let fields = match <Element as ArrowField>::data_type() {
+            DataType::Struct(fields) => fields,
+            data_type => {
+                return Err(Error::InvalidArgument(format!(
+                    "Expected a struct data type to build a record batch, found {data_type:?}"
+                )))
+            }
+        };
+        let array: Box<dyn Array> = self.try_into_arrow()?;
+        let chunk = Chunk::new(vec![array]).flatten()?;
+        Ok((chunk, Schema::from(fields)))
+    }
+}
+
 /// Top-level API to serialize to Arrow
 pub trait TryIntoArrow<'a, ArrowArray, Element>
 where
@@ -436,13 +1924,17 @@ where
     Element: 'static,
 {
     /// Convert from any iterable collection into an `arrow2::Array`
-    fn try_into_arrow(self) -> arrow2::error::Result<ArrowArray>;
+    fn try_into_arrow(self) -> Result<ArrowArray>;
 
     /// Convert from any iterable collection into an `arrow2::Array` by coercing the conversion to a specific Arrow type.
     /// This is useful when the same rust type maps to one or more Arrow types for example `LargeString`.
-    fn try_into_arrow_as_type<ArrowType>(self) -> arrow2::error::Result<ArrowArray>
+    fn try_into_arrow_as_type<ArrowType>(self) -> Result<ArrowArray>
     where
         ArrowType: ArrowSerialize + ArrowField<Type = Element> + 'static;
+
+    /// Same as `try_into_arrow` except the result is paired with an `arrow2::datatypes::Field` with the given name.
+    /// This is useful when writing a single column to IPC or parquet, which requires a named field.
+    fn try_into_arrow_named(self, name: &str) -> Result<(ArrowArray, Field)>;
 }
 
 impl<'a, Element, Collection> TryIntoArrow<'a, Arc<dyn Array>, Element> for Collection
@@ -450,16 +1942,20 @@ where
     Element: ArrowSerialize + ArrowField<Type = Element> + 'static,
     Collection: IntoIterator<Item = &'a Element>,
 {
-    fn try_into_arrow(self) -> arrow2::error::Result<Arc<dyn Array>> {
+    fn try_into_arrow(self) -> Result<Arc<dyn Array>> {
         Ok(arrow_serialize_to_mutable_array::<Element, Element, Collection>(self)?.as_arc())
     }
 
-    fn try_into_arrow_as_type<Field>(self) -> arrow2::error::Result<Arc<dyn Array>>
+    fn try_into_arrow_as_type<Field>(self) -> Result<Arc<dyn Array>>
     where
         Field: ArrowSerialize + ArrowField<Type = Element> + 'static,
     {
         Ok(arrow_serialize_to_mutable_array::<Element, Field, Collection>(self)?.as_arc())
     }
+
+    fn try_into_arrow_named(self, name: &str) -> Result<(Arc<dyn Array>, Field)> {
+        Ok((self.try_into_arrow()?, <Element as ArrowField>::field(name)))
+    }
 }
 
 impl<'a, Element, Collection> TryIntoArrow<'a, Box<dyn Array>, Element> for Collection
@@ -467,16 +1963,20 @@ where
     Element: ArrowSerialize + ArrowField<Type = Element> + 'static,
     Collection: IntoIterator<Item = &'a Element>,
 {
-    fn try_into_arrow(self) -> arrow2::error::Result<Box<dyn Array>> {
+    fn try_into_arrow(self) -> Result<Box<dyn Array>> {
         Ok(arrow_serialize_to_mutable_array::<Element, Element, Collection>(self)?.as_box())
     }
 
-    fn try_into_arrow_as_type<E>(self) -> arrow2::error::Result<Box<dyn Array>>
+    fn try_into_arrow_as_type<E>(self) -> Result<Box<dyn Array>>
     where
         E: ArrowSerialize + ArrowField<Type = Element> + 'static,
     {
         Ok(arrow_serialize_to_mutable_array::<Element, E, Collection>(self)?.as_box())
     }
+
+    fn try_into_arrow_named(self, name: &str) -> Result<(Box<dyn Array>, Field)> {
+        Ok((self.try_into_arrow()?, <Element as ArrowField>::field(name)))
+    }
 }
 
 impl<'a, Element, Collection> TryIntoArrow<'a, Chunk<Arc<dyn Array>>, Element> for Collection
@@ -484,7 +1984,7 @@ where
     Element: ArrowSerialize + ArrowField<Type = Element> + 'static,
     Collection: IntoIterator<Item = &'a Element>,
 {
-    fn try_into_arrow(self) -> arrow2::error::Result<Chunk<Arc<dyn Array>>> {
+    fn try_into_arrow(self) -> Result<Chunk<Arc<dyn Array>>> {
         Ok(Chunk::new(vec![arrow_serialize_to_mutable_array::<
             Element,
             Element,
@@ -493,7 +1993,7 @@ where
         .as_arc()]))
     }
 
-    fn try_into_arrow_as_type<Field>(self) -> arrow2::error::Result<Chunk<Arc<dyn Array>>>
+    fn try_into_arrow_as_type<Field>(self) -> Result<Chunk<Arc<dyn Array>>>
     where
         Field: ArrowSerialize + ArrowField<Type = Element> + 'static,
     {
@@ -504,6 +2004,10 @@ where
         >(self)?
         .as_arc()]))
     }
+
+    fn try_into_arrow_named(self, name: &str) -> Result<(Chunk<Arc<dyn Array>>, Field)> {
+        Ok((self.try_into_arrow()?, <Element as ArrowField>::field(name)))
+    }
 }
 
 impl<'a, Element, Collection> TryIntoArrow<'a, Chunk<Box<dyn Array>>, Element> for Collection
@@ -511,7 +2015,7 @@ where
     Element: ArrowSerialize + ArrowField<Type = Element> + 'static,
     Collection: IntoIterator<Item = &'a Element>,
 {
-    fn try_into_arrow(self) -> arrow2::error::Result<Chunk<Box<dyn Array>>> {
+    fn try_into_arrow(self) -> Result<Chunk<Box<dyn Array>>> {
         Ok(Chunk::new(vec![arrow_serialize_to_mutable_array::<
             Element,
             Element,
@@ -520,7 +2024,7 @@ where
         .as_box()]))
     }
 
-    fn try_into_arrow_as_type<E>(self) -> arrow2::error::Result<Chunk<Box<dyn Array>>>
+    fn try_into_arrow_as_type<E>(self) -> Result<Chunk<Box<dyn Array>>>
     where
         E: ArrowSerialize + ArrowField<Type = Element> + 'static,
     {
@@ -531,4 +2035,73 @@ where
         >(self)?
         .as_box()]))
     }
+
+    fn try_into_arrow_named(self, name: &str) -> Result<(Chunk<Box<dyn Array>>, Field)> {
+        Ok((self.try_into_arrow()?, <Element as ArrowField>::field(name)))
+    }
+}
+
+/// Serializes an iterator of owned values into an `arrow2::MutableArray`
+pub fn arrow_serialize_owned_to_mutable_array<
+    A: 'static,
+    T: ArrowSerialize + ArrowField<Type = A> + 'static,
+    I: IntoIterator<Item = A>,
+>(
+    into_iter: I,
+) -> Result<<T as ArrowSerialize>::MutableArrayType> {
+    check_field_names(&<T as ArrowField>::data_type())?;
+    let mut arr = <T as ArrowSerialize>::new_array();
+    let iter = into_iter.into_iter();
+    arr.reserve(iter.size_hint().0);
+    for i in iter {
+        <T as ArrowSerialize>::arrow_serialize(&i, &mut arr).map_err(Error::Serialize)?;
+    }
+    Ok(arr)
+}
+
+/// Top-level API to serialize iterators of owned values to Arrow, for example a `Vec` that is consumed
+/// or a `map` adapter, without first collecting them to take references.
+pub trait TryIntoArrowOwned<ArrowArray, Element>
+where
+    Self: IntoIterator<Item = Element>,
+    Element: 'static,
+{
+    /// Convert from any iterable of owned values into an `arrow2::Array`
+    fn try_into_arrow_owned(self) -> Result<ArrowArray>;
+}
+
+impl<Element, Collection> TryIntoArrowOwned<Arc<dyn Array>, Element> for Collection
+where
+    Element: ArrowSerialize + ArrowField<Type = Element> + 'static,
+    Collection: IntoIterator<Item = Element>,
+{
+    fn try_into_arrow_owned(self) -> Result<Arc<dyn Array>> {
+        Ok(arrow_serialize_owned_to_mutable_array::<Element, Element, Collection>(self)?.as_arc())
+    }
+}
+
+impl<Element, Collection> TryIntoArrowOwned<Box<dyn Array>, Element> for Collection
+where
+    Element: ArrowSerialize + ArrowField<Type = Element> + 'static,
+    Collection: IntoIterator<Item = Element>,
+{
+    fn try_into_arrow_owned(self) -> Result<Box<dyn Array>> {
+        Ok(arrow_serialize_owned_to_mutable_array::<Element, Element, Collection>(self)?.as_box())
+    }
+}
+
+/// Serializes the values produced by applying `f` to each element of an iterator, without collecting
+/// them first. This is useful when the elements don't implement [`ArrowSerialize`] themselves, for
+/// example trait objects that can each be converted into a common type.
+pub fn try_into_arrow_via<'a, Element, Mapped, F, I>(into_iter: I, f: F) -> Result<Box<dyn Array>>
+where
+    Element: 'a + ?Sized,
+    Mapped: ArrowSerialize + ArrowField<Type = Mapped> + 'static,
+    F: Fn(&Element) -> Mapped,
+    I: IntoIterator<Item = &'a Element>,
+{
+    Ok(
+        arrow_serialize_owned_to_mutable_array::<Mapped, Mapped, _>(into_iter.into_iter().map(f))?
+            .as_box(),
+    )
 }
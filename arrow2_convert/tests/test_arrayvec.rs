@@ -0,0 +1,74 @@
+#![cfg(feature = "arrayvec")]
+
+use arrayvec::ArrayVec;
+use arrow2::array::*;
+use arrow2::datatypes::*;
+use arrow2_convert::deserialize::TryIntoCollection;
+use arrow2_convert::serialize::TryIntoArrow;
+use arrow2_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+
+#[test]
+fn test_arrayvec_round_trip() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Row {
+        ids: ArrayVec<i32, 4>,
+        names: Option<ArrayVec<String, 2>>,
+    }
+
+    let rows = vec![
+        Row {
+            ids: [1, 2, 3, 4].into(),
+            names: Some(ArrayVec::from_iter(["a".to_string()])),
+        },
+        Row {
+            ids: ArrayVec::new(),
+            names: None,
+        },
+    ];
+    let b: Box<dyn Array> = rows.try_into_arrow().unwrap();
+    // the lists have a variable length up to the capacity
+    assert_eq!(
+        b.data_type(),
+        &DataType::Struct(vec![
+            Field::new(
+                "ids",
+                DataType::List(Box::new(Field::new("item", DataType::Int32, false))),
+                false
+            ),
+            Field::new(
+                "names",
+                DataType::List(Box::new(Field::new("item", DataType::Utf8, false))),
+                true
+            ),
+        ])
+    );
+    let round_trip: Vec<Row> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, rows);
+}
+
+#[test]
+fn test_arrayvec_overflow() {
+    let lists: Box<dyn Array> = vec![vec![1, 2], vec![1, 2, 3]].try_into_arrow().unwrap();
+    let result: arrow2_convert::error::Result<Vec<ArrayVec<i32, 2>>> = lists.try_into_collection();
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Deserialize error: The list of 3 items at row 1 doesn't fit in an `ArrayVec` with a capacity of 2"
+    );
+
+    // lists nested in structs are checked before any row is deserialized
+    #[derive(Debug, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Row {
+        ids: Vec<i32>,
+    }
+    #[derive(Debug, ArrowField, ArrowDeserialize)]
+    #[allow(dead_code)]
+    struct SmallRow {
+        ids: ArrayVec<i32, 2>,
+    }
+    let rows: Box<dyn Array> = vec![Row { ids: vec![1, 2, 3] }].try_into_arrow().unwrap();
+    let result: arrow2_convert::error::Result<Vec<SmallRow>> = rows.try_into_collection();
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Deserialize error: The list of 3 items at row 0 doesn't fit in an `ArrayVec` with a capacity of 2"
+    );
+}
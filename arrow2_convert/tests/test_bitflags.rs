@@ -0,0 +1,64 @@
+use arrow2::array::*;
+use arrow2::datatypes::*;
+use arrow2_convert::deserialize::TryIntoCollection;
+use arrow2_convert::serialize::TryIntoArrow;
+use arrow2_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+
+bitflags::bitflags! {
+    struct Permissions: u32 {
+        const READ = 0b001;
+        const WRITE = 0b010;
+        const EXECUTE = 0b100;
+    }
+}
+
+arrow2_convert::arrow_bitflags!(Permissions, u32);
+
+#[test]
+fn test_bitflags_round_trip() {
+    let original = vec![
+        Permissions::READ,
+        Permissions::READ | Permissions::WRITE,
+        Permissions::empty(),
+        Permissions::all(),
+    ];
+
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    assert_eq!(b.data_type(), &DataType::UInt32);
+    assert_eq!(
+        b.as_any().downcast_ref::<UInt32Array>().unwrap(),
+        &UInt32Array::from_slice([0b001, 0b011, 0, 0b111])
+    );
+
+    let round_trip: Vec<Permissions> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_bitflags_in_struct() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct File {
+        name: String,
+        permissions: Permissions,
+        group_permissions: Option<Permissions>,
+        history: Vec<Permissions>,
+    }
+
+    let original = vec![File {
+        name: "a".to_string(),
+        permissions: Permissions::READ,
+        group_permissions: None,
+        history: vec![Permissions::WRITE, Permissions::EXECUTE],
+    }];
+
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    let round_trip: Vec<File> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_bitflags_unknown_bits_are_truncated() {
+    let b: Box<dyn Array> = UInt32Array::from_slice([0b1001]).boxed();
+    let flags: Vec<Permissions> = b.try_into_collection().unwrap();
+    assert_eq!(flags, vec![Permissions::READ]);
+}
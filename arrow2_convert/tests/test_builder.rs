@@ -0,0 +1,69 @@
+use arrow2::array::*;
+use arrow2::datatypes::{DataType, Field};
+use arrow2_convert::builder::StructBuilder;
+use arrow2_convert::{deserialize::*, serialize::*, ArrowDeserialize, ArrowField};
+
+#[test]
+fn test_struct_builder() {
+    let ids: Vec<i64> = vec![1, 2, 3];
+    let names: Vec<Option<String>> = vec![Some("a".to_string()), None, Some("c".to_string())];
+
+    let array = StructBuilder::new()
+        .column("id", ids.try_into_arrow().unwrap())
+        .column("name", names.try_into_arrow().unwrap())
+        .build()
+        .unwrap();
+    assert_eq!(
+        array.data_type(),
+        &DataType::Struct(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, true),
+        ])
+    );
+
+    // the built array has the same layout as a derived struct
+    #[derive(Debug, PartialEq, ArrowField, ArrowDeserialize)]
+    struct Row {
+        id: i64,
+        name: Option<String>,
+    }
+    let rows: Vec<Row> = array.boxed().try_into_collection().unwrap();
+    assert_eq!(
+        rows,
+        vec![
+            Row {
+                id: 1,
+                name: Some("a".to_string())
+            },
+            Row { id: 2, name: None },
+            Row {
+                id: 3,
+                name: Some("c".to_string())
+            },
+        ]
+    );
+
+    let (chunk, schema) = StructBuilder::new()
+        .field(
+            Field::new("id", DataType::Int64, true),
+            ids.try_into_arrow().unwrap(),
+        )
+        .build_chunk()
+        .unwrap();
+    assert_eq!(chunk.len(), 3);
+    assert_eq!(schema.fields, vec![Field::new("id", DataType::Int64, true)]);
+}
+
+#[test]
+fn test_struct_builder_errors() {
+    let short: Vec<i32> = vec![1];
+    let long: Vec<i32> = vec![1, 2];
+    let err = StructBuilder::new()
+        .column("short", short.try_into_arrow().unwrap())
+        .column("long", long.try_into_arrow().unwrap())
+        .build()
+        .unwrap_err();
+    assert!(err.to_string().contains("Column `long` has 2 rows"));
+
+    assert!(StructBuilder::new().build_chunk().is_err());
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "bytes")]
+
+use arrow2::array::*;
+use arrow2_convert::deserialize::TryIntoCollection;
+use arrow2_convert::serialize::TryIntoArrow;
+use arrow2_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+use bytes::Bytes;
+
+#[test]
+fn test_bytes_round_trip() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Message {
+        payload: Bytes,
+        optional_payload: Option<Bytes>,
+        payloads: Vec<Bytes>,
+    }
+
+    let original = vec![
+        Message {
+            payload: Bytes::from_static(b"abc"),
+            optional_payload: None,
+            payloads: vec![Bytes::from_static(b"d"), Bytes::new()],
+        },
+        Message {
+            payload: Bytes::new(),
+            optional_payload: Some(Bytes::from_static(b"ef")),
+            payloads: vec![],
+        },
+    ];
+
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Message> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_bytes_zero_copy() {
+    let original = vec![Bytes::from_static(b"hello"), Bytes::from_static(b"world")];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    let values = b
+        .as_any()
+        .downcast_ref::<BinaryArray<i32>>()
+        .unwrap()
+        .values();
+
+    let round_trip: Vec<Bytes> = b.as_ref().try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+    // the deserialized bytes point into the arrow buffer
+    assert_eq!(round_trip[0].as_ptr(), values.as_ptr());
+    assert_eq!(round_trip[1].as_ptr(), values[5..].as_ptr());
+}
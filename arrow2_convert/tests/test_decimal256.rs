@@ -0,0 +1,69 @@
+#![cfg(feature = "bigdecimal")]
+
+use arrow2::array::MutableArray;
+use arrow2::array::*;
+use arrow2::datatypes::DataType;
+use arrow2_convert::deserialize::{arrow_array_deserialize_iterator_as_type, TryIntoCollection};
+use arrow2_convert::field::Decimal256;
+use arrow2_convert::serialize::{arrow_serialize_to_mutable_array, TryIntoArrow};
+use arrow2_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+use bigdecimal::BigDecimal;
+use std::str::FromStr;
+
+#[test]
+fn test_decimal256_round_trip() {
+    // the first value doesn't fit in an i128
+    let original = vec![
+        BigDecimal::from_str("-123456789012345678901234567890123456789012345.678901").unwrap(),
+        BigDecimal::from_str("0.5").unwrap(),
+        BigDecimal::from_str("42").unwrap(),
+    ];
+    // like i128, precision and scale are required so TryIntoArrow isn't implemented for Vec<BigDecimal>
+    let b: Box<dyn Array> = arrow_serialize_to_mutable_array::<_, Decimal256<60, 6>, _>(&original)
+        .unwrap()
+        .as_box();
+    assert_eq!(b.data_type(), &DataType::Decimal256(60, 6));
+    let round_trip: Vec<BigDecimal> =
+        arrow_array_deserialize_iterator_as_type::<_, Decimal256<60, 6>>(b.as_ref())
+            .unwrap()
+            .collect();
+    assert_eq!(original, round_trip);
+    // values are normalized to the scale of the column
+    assert_eq!(round_trip[1].as_bigint_and_exponent().1, 6);
+}
+
+#[test]
+fn test_decimal256_truncates_and_checks_precision() {
+    let original = vec![BigDecimal::from_str("1.23456").unwrap()];
+    let b: Box<dyn Array> = arrow_serialize_to_mutable_array::<_, Decimal256<10, 2>, _>(&original)
+        .unwrap()
+        .as_box();
+    let round_trip: Vec<BigDecimal> =
+        arrow_array_deserialize_iterator_as_type::<_, Decimal256<10, 2>>(b.as_ref())
+            .unwrap()
+            .collect();
+    assert_eq!(round_trip, vec![BigDecimal::from_str("1.23").unwrap()]);
+
+    let too_large = vec![BigDecimal::from_str("123456789").unwrap()];
+    assert!(arrow_serialize_to_mutable_array::<_, Decimal256<10, 2>, _>(&too_large).is_err());
+}
+
+#[test]
+fn test_decimal256_struct() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Balance {
+        #[arrow_field(type = "Decimal256<76, 18>")]
+        amount: BigDecimal,
+        #[arrow_field(type = "Option<Decimal256<76, 18>>")]
+        limit: Option<BigDecimal>,
+    }
+
+    let original = vec![Balance {
+        amount: BigDecimal::from_str("99999999999999999999999999999999.000000000000000001")
+            .unwrap(),
+        limit: None,
+    }];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Balance> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
@@ -1,5 +1,5 @@
-use arrow2::error::Result;
 use arrow2::{array::*, buffer::Buffer};
+use arrow2_convert::error::Result;
 use arrow2_convert::{deserialize::*, serialize::*, ArrowDeserialize, ArrowField, ArrowSerialize};
 
 #[test]
@@ -52,6 +52,34 @@ fn test_deserialize_schema_mismatch_error() {
     assert!(result.is_err());
 }
 
+#[test]
+fn test_deserialize_schema_mismatch_error_variant() {
+    let arr1 = vec![1_i64, 2];
+    let arr1: Box<dyn Array> = arr1.try_into_arrow().unwrap();
+    let result: Result<Vec<String>> = arr1.as_ref().try_into_collection();
+    match result {
+        Err(arrow2_convert::error::Error::SchemaMismatch {
+            path,
+            expected,
+            found,
+        }) => {
+            assert_eq!(path, "");
+            assert_eq!(*expected, arrow2::datatypes::DataType::Utf8);
+            assert_eq!(*found, arrow2::datatypes::DataType::Int64);
+        }
+        _ => panic!("expected a schema mismatch error"),
+    }
+
+    // the error can be converted back to an arrow2 error
+    let result: arrow2::error::Result<Vec<String>> = arr1
+        .try_into_collection()
+        .map_err(|e: arrow2_convert::error::Error| e.into());
+    assert!(matches!(
+        result,
+        Err(arrow2::error::Error::InvalidArgumentError(_))
+    ));
+}
+
 #[test]
 fn test_deserialize_large_types_schema_mismatch_error() {
     #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
@@ -108,3 +136,601 @@ fn test_deserialize_buffer_u8() {
         assert_eq!(&i, k);
     }
 }
+
+#[test]
+fn test_deserialize_nested_schema_mismatch_path() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Root1 {
+        name: String,
+        child: Child1,
+    }
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Child1 {
+        child_array: Vec<ChildChild1>,
+    }
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct ChildChild1 {
+        a1: i64,
+        a2: bool,
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Root2 {
+        name: String,
+        child: Child2,
+    }
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Child2 {
+        child_array: Vec<ChildChild2>,
+    }
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct ChildChild2 {
+        a1: i32,
+        a2: bool,
+    }
+
+    let arr1 = vec![Root1 {
+        name: "a".to_string(),
+        child: Child1 {
+            child_array: vec![ChildChild1 { a1: 1, a2: true }],
+        },
+    }];
+    let arr1: Box<dyn Array> = arr1.try_into_arrow().unwrap();
+    let result: Result<Vec<Root2>> = arr1.try_into_collection();
+    match result {
+        Err(e @ arrow2_convert::error::Error::SchemaMismatch { .. }) => {
+            assert!(e.to_string().contains("child.child_array.item.a1"));
+            if let arrow2_convert::error::Error::SchemaMismatch {
+                path,
+                expected,
+                found,
+            } = e
+            {
+                assert_eq!(path, "child.child_array.item.a1");
+                assert_eq!(*expected, arrow2::datatypes::DataType::Int32);
+                assert_eq!(*found, arrow2::datatypes::DataType::Int64);
+            }
+        }
+        _ => panic!("expected a schema mismatch error"),
+    }
+}
+
+#[test]
+fn test_deserialize_match_by_name() {
+    use arrow2::datatypes::{DataType, Field};
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(match_by_name)]
+    struct S {
+        a: i64,
+        b: Option<String>,
+    }
+
+    // the fields are reordered and there is an extra field that is ignored
+    let array: Box<dyn Array> = StructArray::new(
+        DataType::Struct(vec![
+            Field::new("extra", DataType::Boolean, false),
+            Field::new("b", DataType::Utf8, true),
+            Field::new("a", DataType::Int64, false),
+        ]),
+        vec![
+            BooleanArray::from_slice([true, false]).boxed(),
+            Utf8Array::<i32>::from([Some("one"), None]).boxed(),
+            Int64Array::from_slice([1, 2]).boxed(),
+        ],
+        None,
+    )
+    .boxed();
+
+    let round_trip: Vec<S> = array.try_into_collection().unwrap();
+    assert_eq!(
+        round_trip,
+        vec![
+            S {
+                a: 1,
+                b: Some("one".to_string())
+            },
+            S { a: 2, b: None }
+        ]
+    );
+
+    // a missing or mistyped field is still an error
+    let array: Box<dyn Array> = StructArray::new(
+        DataType::Struct(vec![Field::new("a", DataType::Int64, false)]),
+        vec![Int64Array::from_slice([1]).boxed()],
+        None,
+    )
+    .boxed();
+    let result: Result<Vec<S>> = array.try_into_collection();
+    assert!(result.is_err());
+
+    let array: Box<dyn Array> = StructArray::new(
+        DataType::Struct(vec![
+            Field::new("b", DataType::Utf8, true),
+            Field::new("a", DataType::Int32, false),
+        ]),
+        vec![
+            Utf8Array::<i32>::from([Some("one")]).boxed(),
+            Int32Array::from_slice([1]).boxed(),
+        ],
+        None,
+    )
+    .boxed();
+    let result: Result<Vec<S>> = array.try_into_collection();
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Data type mismatch at `a`: expected Int64, found Int32"
+    );
+}
+
+#[test]
+fn test_deserialize_nested_match_by_name() {
+    use std::collections::BTreeMap;
+
+    #[derive(Debug, Clone, ArrowField, ArrowSerialize)]
+    struct WrittenInner {
+        extra: bool,
+        b: Option<String>,
+        a: i64,
+    }
+
+    #[derive(Debug, ArrowField, ArrowSerialize)]
+    struct Written {
+        id: i32,
+        inner: WrittenInner,
+        items: Vec<WrittenInner>,
+        by_key: BTreeMap<String, WrittenInner>,
+    }
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowDeserialize)]
+    #[arrow_field(match_by_name)]
+    struct Inner {
+        a: i64,
+        b: Option<String>,
+    }
+
+    // positional structs, lists and maps let their children match fields by name
+    #[derive(Debug, PartialEq, ArrowField, ArrowDeserialize)]
+    struct Outer {
+        id: i32,
+        inner: Inner,
+        items: Vec<Inner>,
+        by_key: BTreeMap<String, Inner>,
+    }
+
+    let inner = WrittenInner {
+        extra: true,
+        b: Some("one".to_string()),
+        a: 1,
+    };
+    let array: Box<dyn Array> = vec![Written {
+        id: 7,
+        inner: inner.clone(),
+        items: vec![inner.clone()],
+        by_key: BTreeMap::from([("k".to_string(), inner)]),
+    }]
+    .try_into_arrow()
+    .unwrap();
+    let round_trip: Vec<Outer> = array.try_into_collection().unwrap();
+    let expected = || Inner {
+        a: 1,
+        b: Some("one".to_string()),
+    };
+    assert_eq!(
+        round_trip,
+        vec![Outer {
+            id: 7,
+            inner: expected(),
+            items: vec![expected()],
+            by_key: BTreeMap::from([("k".to_string(), expected())]),
+        }]
+    );
+
+    // mismatches are still reported with the path of the nested field
+    #[derive(Debug, ArrowField, ArrowSerialize)]
+    struct WrittenMistyped {
+        id: i32,
+        inner: WrittenInner,
+        items: Vec<Written32>,
+        by_key: BTreeMap<String, WrittenInner>,
+    }
+
+    #[derive(Debug, ArrowField, ArrowSerialize)]
+    struct Written32 {
+        a: i32,
+        b: Option<String>,
+    }
+
+    let array: Box<dyn Array> = vec![WrittenMistyped {
+        id: 7,
+        inner: WrittenInner {
+            extra: false,
+            b: None,
+            a: 1,
+        },
+        items: vec![],
+        by_key: BTreeMap::new(),
+    }]
+    .try_into_arrow()
+    .unwrap();
+    let result: Result<Vec<Outer>> = array.try_into_collection();
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Data type mismatch at `items.item.a`: expected Int64, found Int32"
+    );
+}
+
+#[test]
+fn test_deserialize_missing_field_default() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Old {
+        a: i64,
+    }
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(match_by_name)]
+    struct New {
+        a: i64,
+        #[arrow_field(default)]
+        b: Option<String>,
+        #[arrow_field(default)]
+        c: Vec<u32>,
+    }
+
+    let old = vec![Old { a: 1 }, Old { a: 2 }];
+    let array: Box<dyn Array> = old.try_into_arrow().unwrap();
+    let new: Vec<New> = array.try_into_collection().unwrap();
+    assert_eq!(
+        new,
+        vec![
+            New {
+                a: 1,
+                b: None,
+                c: vec![]
+            },
+            New {
+                a: 2,
+                b: None,
+                c: vec![]
+            }
+        ]
+    );
+
+    // present fields are still deserialized
+    let original = vec![New {
+        a: 3,
+        b: Some("b".to_string()),
+        c: vec![1, 2],
+    }];
+    let array: Box<dyn Array> = original.try_into_arrow().unwrap();
+    let round_trip: Vec<New> = array.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+
+    // fields without a default must still be present
+    let array: Box<dyn Array> = vec![Some(New {
+        a: 4,
+        b: None,
+        c: vec![],
+    })]
+    .try_into_arrow()
+    .unwrap();
+    let result: Result<Vec<Old>> = array.try_into_collection();
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_deserialize_all_fields_default() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Other {
+        z: i32,
+    }
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(match_by_name)]
+    struct AllDefault {
+        #[arrow_field(default)]
+        a: i64,
+        #[arrow_field(default)]
+        b: Option<String>,
+    }
+
+    // none of the columns match, so every row comes from the defaults, one per row of the array
+    let array: Box<dyn Array> = vec![Other { z: 1 }, Other { z: 2 }, Other { z: 3 }]
+        .try_into_arrow()
+        .unwrap();
+    let iter = arrow_array_deserialize_iterator::<AllDefault>(array.as_ref()).unwrap();
+    assert_eq!(iter.count(), 3);
+    let rows: Vec<AllDefault> = array.try_into_collection().unwrap();
+    assert_eq!(
+        rows,
+        (0..3)
+            .map(|_| AllDefault { a: 0, b: None })
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_is_compatible() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct S {
+        a: i64,
+        b: Vec<String>,
+    }
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct T {
+        a: i64,
+        b: Vec<i32>,
+    }
+
+    let b: Box<dyn Array> = vec![S {
+        a: 1,
+        b: vec!["x".to_string()],
+    }]
+    .try_into_arrow()
+    .unwrap();
+    assert!(is_compatible::<S>(b.as_ref()));
+    assert!(assert_compatible::<S>(b.as_ref()).is_ok());
+
+    assert!(!is_compatible::<T>(b.as_ref()));
+    assert_eq!(
+        assert_compatible::<T>(b.as_ref()).unwrap_err().to_string(),
+        "Data type mismatch at `b.item`: expected Int32, found Utf8"
+    );
+    assert!(!is_compatible::<i64>(b.as_ref()));
+    assert!(is_compatible::<Option<S>>(b.as_ref()));
+}
+
+#[test]
+fn test_deserialize_column() {
+    use arrow2::chunk::Chunk;
+    use arrow2_convert::error::Error;
+
+    let ids: Box<dyn Array> = [1_i64, 2, 3].try_into_arrow().unwrap();
+    let names: Box<dyn Array> = ["a", "b", "c"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect::<Vec<_>>()
+        .try_into_arrow()
+        .unwrap();
+    let chunk = Chunk::new(vec![ids, names]);
+
+    let names: Vec<String> = deserialize_column(&chunk, 1).unwrap();
+    assert_eq!(names, vec!["a", "b", "c"]);
+    let ids: Vec<i64> = deserialize_column(&chunk, 0).unwrap();
+    assert_eq!(ids, vec![1, 2, 3]);
+
+    assert!(matches!(
+        deserialize_column::<i64, _>(&chunk, 2),
+        Err(Error::InvalidArgument(_))
+    ));
+    assert!(matches!(
+        deserialize_column::<i64, _>(&chunk, 1),
+        Err(Error::SchemaMismatch { .. })
+    ));
+}
+
+#[test]
+fn test_deserialize_indexed_iterator() {
+    let original = [Some(10_i32), None, Some(30), Some(40)];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    let indexed: Vec<(usize, Option<i32>)> =
+        arrow_array_deserialize_indexed_iterator::<Option<i32>>(b.as_ref())
+            .unwrap()
+            .collect();
+    assert_eq!(
+        indexed,
+        vec![(0, Some(10)), (1, None), (2, Some(30)), (3, Some(40))]
+    );
+
+    // the indices refer to the rows of a sliced array
+    let sliced = b.sliced(1, 2);
+    let indices: Vec<usize> =
+        arrow_array_deserialize_indexed_iterator::<Option<i32>>(sliced.as_ref())
+            .unwrap()
+            .map(|(i, _)| i)
+            .collect();
+    assert_eq!(indices, vec![0, 1]);
+}
+
+#[test]
+fn test_iterator_exact_size() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Point {
+        x: i32,
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(type = "dense")]
+    enum Shape {
+        Point(Point),
+        Empty,
+    }
+
+    let points = vec![Some(Point { x: 1 }), None, Some(Point { x: 2 })];
+    let b: Box<dyn Array> = points.try_into_arrow().unwrap();
+    let mut iter = <PointArray as ArrowArray>::iter_from_array_ref(b.as_ref());
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+    iter.next();
+    assert_eq!(iter.size_hint(), (2, Some(2)));
+    iter.next();
+    iter.next();
+    assert_eq!(iter.len(), 0);
+    assert!(iter.next().is_none());
+
+    let sliced = b.sliced(1, 2);
+    let iter = arrow_array_deserialize_iterator::<Option<Point>>(sliced.as_ref()).unwrap();
+    assert_eq!(iter.size_hint(), (2, Some(2)));
+
+    let shapes = vec![Shape::Empty, Shape::Point(Point { x: 1 })];
+    let b: Box<dyn Array> = shapes.try_into_arrow().unwrap();
+    let mut iter = <ShapeArray as ArrowArray>::iter_from_array_ref(b.as_ref());
+    assert_eq!(iter.len(), 2);
+    iter.next();
+    assert_eq!(iter.size_hint(), (1, Some(1)));
+}
+
+#[test]
+fn test_try_into_collection_lenient() {
+    use arrow2::datatypes::{DataType, Field};
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Reading {
+        id: i64,
+        value: Option<f64>,
+        samples: Vec<u32>,
+    }
+
+    // an external array written with narrower types
+    let array = StructArray::new(
+        DataType::Struct(vec![
+            Field::new("id", DataType::Int32, false),
+            Field::new("value", DataType::Float32, true),
+            Field::new(
+                "samples",
+                DataType::List(Box::new(Field::new("item", DataType::UInt8, false))),
+                false,
+            ),
+        ]),
+        vec![
+            Int32Array::from_slice([1, -2]).boxed(),
+            Float32Array::from([Some(0.5), None]).boxed(),
+            ListArray::<i32>::new(
+                DataType::List(Box::new(Field::new("item", DataType::UInt8, false))),
+                vec![0, 2, 3].try_into().unwrap(),
+                UInt8Array::from_slice([1, 2, 3]).boxed(),
+                None,
+            )
+            .boxed(),
+        ],
+        None,
+    )
+    .boxed();
+
+    let strict: Result<Vec<Reading>> = array.clone().try_into_collection();
+    assert!(strict.is_err());
+
+    let lenient: Vec<Reading> = array.try_into_collection_lenient().unwrap();
+    assert_eq!(
+        lenient,
+        vec![
+            Reading {
+                id: 1,
+                value: Some(0.5),
+                samples: vec![1, 2],
+            },
+            Reading {
+                id: -2,
+                value: None,
+                samples: vec![3],
+            },
+        ]
+    );
+
+    // narrowing is still a mismatch
+    let wide: Box<dyn Array> = Int64Array::from_slice([1]).boxed();
+    let narrow: Result<Vec<i32>> = wide.try_into_collection_lenient();
+    assert!(narrow.is_err());
+}
+
+#[test]
+fn test_for_each_deserialized() {
+    let values: Vec<i64> = vec![1, 2, 3, 4];
+    let b: Box<dyn Array> = values.try_into_arrow().unwrap();
+
+    let mut sum = 0;
+    for_each_deserialized::<i64, _>(b.as_ref(), |v| sum += v).unwrap();
+    assert_eq!(sum, 10);
+
+    // the schema is checked before any row is visited
+    let mut calls = 0;
+    assert!(for_each_deserialized::<String, _>(b.as_ref(), |_| calls += 1).is_err());
+    assert_eq!(calls, 0);
+}
+
+#[test]
+fn test_lenient_dictionary() {
+    use arrow2::datatypes::{DataType, IntegerType};
+
+    let values = Utf8Array::<i32>::from([Some("a"), Some("b"), None]);
+    let keys = PrimitiveArray::<i32>::from([Some(1), Some(0), None, Some(1), Some(2)]);
+    let dictionary = DictionaryArray::try_new(
+        DataType::Dictionary(IntegerType::Int32, Box::new(DataType::Utf8), false),
+        keys,
+        values.boxed(),
+    )
+    .unwrap();
+    let b: Box<dyn Array> = dictionary.boxed();
+
+    // the dictionary isn't a `Utf8` array
+    let strict: Result<Vec<Option<String>>> = b.clone().try_into_collection();
+    assert!(strict.is_err());
+
+    let round_trip: Vec<Option<String>> = b.try_into_collection_lenient().unwrap();
+    assert_eq!(
+        round_trip,
+        vec![
+            Some("b".to_string()),
+            Some("a".to_string()),
+            None,
+            Some("b".to_string()),
+            None
+        ]
+    );
+}
+
+#[test]
+fn test_deserialize_deny_unknown_fields() {
+    use arrow2::datatypes::{DataType, Field};
+    use arrow2_convert::error::Error;
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(match_by_name, deny_unknown_fields)]
+    struct S {
+        a: i64,
+        b: Option<String>,
+    }
+
+    // reordered fields are still accepted
+    let array: Box<dyn Array> = StructArray::new(
+        DataType::Struct(vec![
+            Field::new("b", DataType::Utf8, true),
+            Field::new("a", DataType::Int64, false),
+        ]),
+        vec![
+            Utf8Array::<i32>::from([Some("one")]).boxed(),
+            Int64Array::from_slice([1]).boxed(),
+        ],
+        None,
+    )
+    .boxed();
+    let round_trip: Vec<S> = array.try_into_collection().unwrap();
+    assert_eq!(
+        round_trip,
+        vec![S {
+            a: 1,
+            b: Some("one".to_string())
+        }]
+    );
+
+    // but an extra column is reported
+    let array: Box<dyn Array> = StructArray::new(
+        DataType::Struct(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Utf8, true),
+            Field::new("extra", DataType::Boolean, false),
+        ]),
+        vec![
+            Int64Array::from_slice([1]).boxed(),
+            Utf8Array::<i32>::from([Some("one")]).boxed(),
+            BooleanArray::from_slice([true]).boxed(),
+        ],
+        None,
+    )
+    .boxed();
+    let result: Result<Vec<S>> = array.try_into_collection();
+    match result {
+        Err(Error::SchemaMismatch { path, .. }) => assert_eq!(path, "extra"),
+        other => panic!("unexpected result {other:?}"),
+    }
+}
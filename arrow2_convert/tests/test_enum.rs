@@ -167,3 +167,487 @@ fn test_slice() {
         assert_eq!(round_trip, original_slice);
     }
 }
+
+#[test]
+fn test_try_union_into_collection() {
+    use arrow2_convert::deserialize::try_union_into_collection;
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(type = "dense")]
+    enum TestEnum {
+        VAL1(i32),
+        VAL2,
+    }
+
+    let enums = vec![TestEnum::VAL1(1), TestEnum::VAL2, TestEnum::VAL1(3)];
+    let b: Box<dyn Array> = enums.try_into_arrow().unwrap();
+    let round_trip: Vec<TestEnum> = try_union_into_collection(b.as_ref()).unwrap();
+    assert_eq!(round_trip, enums);
+
+    // replace the payload of the last row with a null, which can't be deserialized into an i32
+    let union = b.as_any().downcast_ref::<UnionArray>().unwrap();
+    let mut fields = union.fields().clone();
+    fields[0] = PrimitiveArray::<i32>::from([Some(1), None]).boxed();
+    let b = UnionArray::new(
+        union.data_type().clone(),
+        union.types().clone(),
+        fields,
+        union.offsets().cloned(),
+    );
+    let err = try_union_into_collection::<Vec<TestEnum>, _>(&b).unwrap_err();
+    assert!(matches!(err, arrow2_convert::error::Error::Deserialize(_)));
+    assert_eq!(
+        err.to_string(),
+        "Deserialize error: Failed to deserialize variant `TestEnum::VAL1` at row 2"
+    );
+}
+
+#[test]
+#[should_panic(expected = "A null row of `TestEnum` can only be deserialized into an `Option`")]
+fn test_union_into_collection_invalid_row() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(type = "dense")]
+    enum TestEnum {
+        VAL1(i32),
+    }
+
+    // a null payload is read as a null row, which a `TestEnum` can't hold
+    let b = UnionArray::new(
+        <TestEnum as arrow2_convert::field::ArrowField>::data_type(),
+        vec![0, 0].into(),
+        vec![PrimitiveArray::<i32>::from([Some(1), None]).boxed()],
+        Some(vec![0, 1].into()),
+    );
+    let _: Vec<TestEnum> = b.boxed().try_into_collection().unwrap();
+}
+
+#[test]
+fn test_optional_enum_round_trip() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(type = "dense")]
+    enum DenseEnum {
+        A(i32),
+        B,
+    }
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(type = "sparse")]
+    enum SparseEnum {
+        A(i32),
+        B,
+    }
+
+    // `None` rows are stored as a null payload of the first variant
+    let dense = vec![Some(DenseEnum::A(1)), None, Some(DenseEnum::B)];
+    let b: Box<dyn Array> = dense.try_into_arrow().unwrap();
+    let round_trip: Vec<Option<DenseEnum>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, dense);
+
+    let sparse = vec![Some(SparseEnum::A(1)), None, Some(SparseEnum::B)];
+    let b: Box<dyn Array> = sparse.try_into_arrow().unwrap();
+    let round_trip: Vec<Option<SparseEnum>> = b.as_ref().try_into_collection().unwrap();
+    assert_eq!(round_trip, sparse);
+
+    // without an `Option`, the null row is reported
+    let err =
+        arrow2_convert::deserialize::try_union_into_collection::<Vec<SparseEnum>, _>(b.as_ref())
+            .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Deserialize error: Failed to deserialize variant `SparseEnum::A` at row 1"
+    );
+}
+
+#[test]
+fn test_enum_custom_type_ids() {
+    use arrow2::datatypes::{DataType, Field, UnionMode};
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(type = "dense")]
+    enum TestDenseEnum {
+        #[arrow_field(type_id = 7)]
+        VAL1(i32),
+        #[arrow_field(type_id = 4)]
+        VAL2(String),
+        // defaults to the index of the variant
+        VAL3,
+    }
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(type = "sparse")]
+    enum TestSparseEnum {
+        #[arrow_field(type_id = 5)]
+        VAL1(i32),
+        #[arrow_field(type_id = 9)]
+        VAL2(String),
+    }
+
+    assert_eq!(
+        <TestDenseEnum as arrow2_convert::field::ArrowField>::data_type(),
+        DataType::Union(
+            vec![
+                Field::new("VAL1", DataType::Int32, false),
+                Field::new("VAL2", DataType::Utf8, false),
+                Field::new("VAL3", DataType::Boolean, false),
+            ],
+            Some(vec![7, 4, 2]),
+            UnionMode::Dense
+        )
+    );
+
+    let enums = vec![
+        TestDenseEnum::VAL2("a".to_string()),
+        TestDenseEnum::VAL1(1),
+        TestDenseEnum::VAL3,
+        TestDenseEnum::VAL1(2),
+    ];
+    let b: Box<dyn Array> = enums.try_into_arrow().unwrap();
+    let union = b.as_any().downcast_ref::<UnionArray>().unwrap();
+    assert_eq!(union.types().as_slice(), &[4, 7, 2, 7]);
+    let round_trip: Vec<TestDenseEnum> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, enums);
+
+    let enums = vec![
+        TestSparseEnum::VAL1(1),
+        TestSparseEnum::VAL2("b".to_string()),
+    ];
+    let b: Box<dyn Array> = enums.try_into_arrow().unwrap();
+    let union = b.as_any().downcast_ref::<UnionArray>().unwrap();
+    assert_eq!(union.types().as_slice(), &[5, 9]);
+    let round_trip: Vec<TestSparseEnum> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, enums);
+}
+
+#[test]
+fn test_enum_discriminant_type_ids() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(type = "sparse")]
+    #[repr(i32)]
+    enum Code {
+        A = 1,
+        B = 5,
+        // follows the previous discriminant
+        C,
+        #[arrow_field(type_id = 0)]
+        D = 20,
+    }
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(type = "dense")]
+    #[repr(u8)]
+    enum Payload {
+        Int(i32) = 3,
+        Text(String) = 10,
+    }
+
+    match <Code as arrow2_convert::field::ArrowField>::data_type() {
+        arrow2::datatypes::DataType::Union(_, ids, _) => assert_eq!(ids, Some(vec![1, 5, 6, 0])),
+        data_type => panic!("unexpected data type {data_type:?}"),
+    }
+
+    let codes = vec![Code::C, Code::A, Code::D, Code::B];
+    let b: Box<dyn Array> = codes.try_into_arrow().unwrap();
+    let union = b.as_any().downcast_ref::<UnionArray>().unwrap();
+    assert_eq!(union.types().as_slice(), &[6, 1, 0, 5]);
+    let round_trip: Vec<Code> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, codes);
+
+    let payloads = vec![Payload::Text("a".to_string()), Payload::Int(1)];
+    let b: Box<dyn Array> = payloads.try_into_arrow().unwrap();
+    let union = b.as_any().downcast_ref::<UnionArray>().unwrap();
+    assert_eq!(union.types().as_slice(), &[10, 3]);
+    let round_trip: Vec<Payload> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, payloads);
+}
+
+#[test]
+fn test_sparse_to_dense() {
+    use arrow2_convert::convert::{dense_to_sparse, sparse_to_dense};
+    use arrow2_convert::deserialize::ArrowDeserializeUnion;
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(type = "sparse")]
+    enum TestEnum {
+        VAL1,
+        VAL2(i32),
+        VAL3(String),
+    }
+
+    let enums = vec![
+        TestEnum::VAL2(1),
+        TestEnum::VAL1,
+        TestEnum::VAL3("a".to_string()),
+        TestEnum::VAL3("b".to_string()),
+        TestEnum::VAL2(2),
+    ];
+    let b: Box<dyn Array> = enums.try_into_arrow().unwrap();
+    let sparse = b.as_any().downcast_ref::<UnionArray>().unwrap();
+
+    let dense = sparse_to_dense(sparse).unwrap();
+    assert!(dense.offsets().is_some());
+    // each field only holds the rows that select it
+    assert_eq!(
+        dense.fields().iter().map(|f| f.len()).collect::<Vec<_>>(),
+        vec![1, 2, 2]
+    );
+    // the data type of `TestEnum` is sparse, so the dense rows are deserialized one at a time
+    let round_trip = (0..dense.len())
+        .map(|index| TestEnum::try_deserialize_union_row(&dense, index))
+        .collect::<arrow2_convert::error::Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(round_trip, enums);
+
+    let sparse = dense_to_sparse(&dense).unwrap();
+    assert!(sparse.offsets().is_none());
+    assert_eq!(sparse.data_type(), b.data_type());
+    let round_trip: Vec<TestEnum> = sparse.clone().boxed().try_into_collection().unwrap();
+    assert_eq!(round_trip, enums);
+
+    assert!(dense_to_sparse(&sparse).is_err());
+    assert!(sparse_to_dense(&dense).is_err());
+}
+
+#[test]
+fn test_index_enum() {
+    use arrow2::datatypes::DataType;
+
+    #[derive(Debug, Clone, Copy, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(type = "i8")]
+    enum IndexEnum {
+        VAL1,
+        VAL2,
+        VAL3,
+        #[arrow_field(type_id = 10)]
+        VAL4,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(type = "sparse")]
+    enum UnionEnum {
+        VAL1,
+        VAL2,
+        VAL3,
+        VAL4,
+    }
+
+    let enums = vec![
+        IndexEnum::VAL1,
+        IndexEnum::VAL2,
+        IndexEnum::VAL3,
+        IndexEnum::VAL4,
+    ];
+    let b: Box<dyn Array> = enums.try_into_arrow().unwrap();
+    assert_eq!(b.data_type(), &DataType::Int8);
+    // a single byte per row instead of a union with a child array per variant
+    let values = b.as_any().downcast_ref::<Int8Array>().unwrap();
+    assert_eq!(values.values().as_slice(), &[0, 1, 2, 10]);
+    let round_trip: Vec<IndexEnum> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, enums);
+
+    let unions: Box<dyn Array> = [
+        UnionEnum::VAL1,
+        UnionEnum::VAL2,
+        UnionEnum::VAL3,
+        UnionEnum::VAL4,
+    ]
+    .try_into_arrow()
+    .unwrap();
+    let unions = unions.as_any().downcast_ref::<UnionArray>().unwrap();
+    assert_eq!(unions.fields().len(), 4);
+    assert!(unions.fields().iter().all(|field| field.len() == 4));
+
+    let nullable = vec![Some(IndexEnum::VAL2), None];
+    let b: Box<dyn Array> = nullable.try_into_arrow().unwrap();
+    let round_trip: Vec<Option<IndexEnum>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, nullable);
+
+    // ids that aren't a variant are reported rather than read as null
+    let b = Int8Array::from_slice([0, 5]).boxed();
+    let result: arrow2_convert::error::Result<Vec<IndexEnum>> = b.try_into_collection();
+    let err = result.unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Deserialize error: Invalid tag 5 for `IndexEnum` at row 1"
+    );
+}
+
+#[test]
+fn test_union_row_payload_mismatch() {
+    use arrow2_convert::deserialize::{ArrowDeserialize, ArrowDeserializeUnion};
+    use arrow2_convert::error::Error;
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(type = "sparse")]
+    enum Expected {
+        A,
+        B(i32),
+    }
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(type = "sparse")]
+    enum Found {
+        A,
+        B(String),
+    }
+
+    let b: Box<dyn Array> = vec![Found::A, Found::B("x".to_string())]
+        .try_into_arrow()
+        .unwrap();
+    let union = b.as_any().downcast_ref::<UnionArray>().unwrap();
+
+    // the payload has the wrong type, which is reported instead of panicking on the downcast
+    match Expected::validate_union(union) {
+        Err(Error::SchemaMismatch { path, .. }) => assert_eq!(path, "B"),
+        other => panic!("unexpected result {other:?}"),
+    }
+    assert!(Found::validate_union(union).is_ok());
+    assert_eq!(
+        Found::try_deserialize_union_row(union, 0).unwrap(),
+        Found::A
+    );
+
+    // arrays of another physical type are rejected before downcasting them
+    let not_a_union = Int32Array::from_slice([1, 2]);
+    match <Expected as ArrowDeserialize>::validate_array(&not_a_union) {
+        Err(Error::Deserialize(msg)) => assert_eq!(msg, "Expected a union array"),
+        other => panic!("unexpected result {other:?}"),
+    }
+}
+
+#[test]
+fn test_enum_default_mode() {
+    use arrow2::datatypes::{DataType, UnionMode};
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    enum Shape {
+        Empty,
+        Circle(f64),
+        Label(String),
+    }
+
+    assert!(matches!(
+        <Shape as arrow2_convert::field::ArrowField>::data_type(),
+        DataType::Union(_, _, UnionMode::Dense)
+    ));
+
+    let shapes = vec![
+        Shape::Circle(1.5),
+        Shape::Empty,
+        Shape::Label("a".to_string()),
+    ];
+    let b: Box<dyn Array> = shapes.try_into_arrow().unwrap();
+    let union = b.as_any().downcast_ref::<UnionArray>().unwrap();
+    assert!(union.offsets().is_some());
+    let round_trip: Vec<Shape> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, shapes);
+}
+
+#[test]
+fn test_struct_enum() {
+    use arrow2::datatypes::{DataType, Field};
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(type = "struct")]
+    enum Tagged {
+        Empty,
+        Number(i32),
+        #[arrow_field(type_id = 5)]
+        Text(String),
+    }
+
+    let enums = vec![
+        Tagged::Number(1),
+        Tagged::Empty,
+        Tagged::Text("a".to_string()),
+        Tagged::Number(2),
+    ];
+    let b: Box<dyn Array> = enums.try_into_arrow().unwrap();
+    assert_eq!(
+        b.data_type(),
+        &DataType::Struct(vec![
+            Field::new("tag", DataType::Int8, false),
+            Field::new("Number", DataType::Int32, true),
+            Field::new("Text", DataType::Utf8, true),
+        ])
+    );
+    let arr = b.as_any().downcast_ref::<StructArray>().unwrap();
+    let tags = arr.values()[0]
+        .as_any()
+        .downcast_ref::<Int8Array>()
+        .unwrap();
+    assert_eq!(tags.values().as_slice(), &[1, 0, 5, 1]);
+    // only the payload of the selected variant is set in each row
+    let numbers = arr.values()[1]
+        .as_any()
+        .downcast_ref::<Int32Array>()
+        .unwrap();
+    assert_eq!(numbers, &Int32Array::from([Some(1), None, None, Some(2)]));
+    // a tag that isn't the type id of any variant is an error
+    let mut values = arr.values().to_vec();
+    values[0] = Int8Array::from_slice([1, 9, 5, 1]).boxed();
+    let unknown = StructArray::new(arr.data_type().clone(), values, None).boxed();
+    let result: arrow2_convert::error::Result<Vec<Tagged>> = unknown.try_into_collection();
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Deserialize error: Invalid tag 9 for `Tagged` at row 1"
+    );
+
+    let round_trip: Vec<Tagged> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, enums);
+
+    let nullable = vec![
+        Some(Tagged::Text("b".to_string())),
+        None,
+        Some(Tagged::Empty),
+    ];
+    let b: Box<dyn Array> = nullable.try_into_arrow().unwrap();
+    let round_trip: Vec<Option<Tagged>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, nullable);
+}
+
+#[test]
+fn test_enum_variant_rename() {
+    use arrow2::datatypes::DataType;
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(type = "sparse")]
+    enum Event {
+        #[arrow_field(rename = "click")]
+        Click(i32),
+        #[arrow_field(rename = "key_press")]
+        KeyPress(String),
+        Close,
+    }
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(type = "struct")]
+    enum Tagged {
+        #[arrow_field(rename = "number")]
+        Number(i32),
+        Empty,
+    }
+
+    let events = vec![
+        Event::Click(1),
+        Event::KeyPress("a".to_string()),
+        Event::Close,
+    ];
+    let b: Box<dyn Array> = events.try_into_arrow().unwrap();
+    let names = match b.data_type() {
+        DataType::Union(fields, _, _) => fields.iter().map(|f| f.name.as_str()).collect::<Vec<_>>(),
+        data_type => panic!("Expected a union, found {data_type:?}"),
+    };
+    assert_eq!(names, vec!["click", "key_press", "Close"]);
+    let round_trip: Vec<Event> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, events);
+
+    let tagged = vec![Tagged::Number(1), Tagged::Empty];
+    let b: Box<dyn Array> = tagged.try_into_arrow().unwrap();
+    let names = match b.data_type() {
+        DataType::Struct(fields) => fields.iter().map(|f| f.name.as_str()).collect::<Vec<_>>(),
+        data_type => panic!("Expected a struct, found {data_type:?}"),
+    };
+    assert_eq!(names, vec!["tag", "number"]);
+    let round_trip: Vec<Tagged> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, tagged);
+}
@@ -66,3 +66,84 @@ fn test_flatten_chunk_type_not_struct_error() {
 
     assert!(chunk.flatten().is_err());
 }
+
+#[test]
+fn test_try_into_record_batch() {
+    #[derive(Debug, Clone, ArrowField, ArrowSerialize)]
+    struct Struct {
+        a: i64,
+        b: String,
+        c: Option<bool>,
+    }
+
+    let array = vec![
+        Struct {
+            a: 1,
+            b: "one".to_string(),
+            c: Some(true),
+        },
+        Struct {
+            a: 2,
+            b: "two".to_string(),
+            c: None,
+        },
+    ];
+
+    let (chunk, schema) = array.try_into_record_batch().unwrap();
+    assert_eq!(schema.fields.len(), 3);
+    assert_eq!(chunk.arrays().len(), schema.fields.len());
+    assert_eq!(chunk.len(), 2);
+    assert_eq!(schema.fields[1].name, "b");
+    assert!(schema.fields[2].is_nullable);
+    for (array, field) in chunk.arrays().iter().zip(schema.fields.iter()) {
+        assert_eq!(array.data_type(), &field.data_type);
+    }
+}
+
+#[test]
+fn test_try_into_record_batch_not_struct_error() {
+    let array = vec![1_i64, 2];
+    assert!(array.try_into_record_batch().is_err());
+}
+
+#[test]
+fn test_unflatten_chunk() {
+    use arrow2_convert::deserialize::{TryIntoCollection, UnflattenChunk};
+    use arrow2_convert::ArrowDeserialize;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Struct {
+        a: i64,
+        b: Option<String>,
+    }
+
+    let original = vec![
+        Struct {
+            a: 1,
+            b: Some("one".to_string()),
+        },
+        Struct { a: 2, b: None },
+    ];
+
+    let array: Box<dyn Array> = original.try_into_arrow().unwrap();
+    let flattened = Chunk::new(vec![array]).flatten().unwrap();
+
+    let unflattened = flattened.clone().unflatten::<Struct>().unwrap();
+    let round_trip: Vec<Struct> = unflattened.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    let round_trip: Vec<Struct> = flattened.try_unflatten_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    // columns with the wrong data type or count are rejected
+    let chunk = Chunk::new(vec![
+        Int32Array::from_slice([1, 2]).boxed(),
+        Utf8Array::<i32>::from([Some("one"), None]).boxed(),
+    ]);
+    assert!(matches!(
+        chunk.unflatten::<Struct>(),
+        Err(arrow2_convert::error::Error::SchemaMismatch { path, .. }) if path == "a"
+    ));
+    let chunk = Chunk::new(vec![Int64Array::from_slice([1, 2]).boxed()]);
+    assert!(chunk.unflatten::<Struct>().is_err());
+}
@@ -0,0 +1,69 @@
+#![cfg(feature = "glam")]
+
+use arrow2::array::*;
+use arrow2::datatypes::*;
+use arrow2_convert::deserialize::TryIntoCollection;
+use arrow2_convert::serialize::TryIntoArrow;
+use arrow2_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+use glam::{Mat4, Vec3, Vec4};
+
+#[test]
+fn test_glam_round_trip() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Mesh {
+        position: Vec3,
+        color: Option<Vec4>,
+        transform: Mat4,
+        normals: Vec<Vec3>,
+    }
+
+    let item = || Box::new(Field::new("item", DataType::Float32, false));
+    assert_eq!(
+        <Mesh as arrow2_convert::field::ArrowField>::data_type(),
+        DataType::Struct(vec![
+            Field::new("position", DataType::FixedSizeList(item(), 3), false),
+            Field::new("color", DataType::FixedSizeList(item(), 4), true),
+            Field::new("transform", DataType::FixedSizeList(item(), 16), false),
+            Field::new(
+                "normals",
+                DataType::List(Box::new(Field::new(
+                    "item",
+                    DataType::FixedSizeList(item(), 3),
+                    false
+                ))),
+                false
+            ),
+        ])
+    );
+
+    let original = vec![
+        Mesh {
+            position: Vec3::new(1.0, 2.0, 3.0),
+            color: Some(Vec4::new(0.1, 0.2, 0.3, 1.0)),
+            transform: Mat4::from_translation(Vec3::new(4.0, 5.0, 6.0)),
+            normals: vec![Vec3::X, Vec3::Y],
+        },
+        Mesh {
+            position: Vec3::ZERO,
+            color: None,
+            transform: Mat4::IDENTITY,
+            normals: vec![],
+        },
+    ];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Mesh> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
+
+#[test]
+fn test_glam_mat4_column_major() {
+    let m = Mat4::from_cols_array(&std::array::from_fn(|i| i as f32));
+    let b: Box<dyn Array> = [m].try_into_arrow().unwrap();
+    let list = b.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    let values = list
+        .values()
+        .as_any()
+        .downcast_ref::<PrimitiveArray<f32>>()
+        .unwrap();
+    assert_eq!(values.values().as_slice(), &m.to_cols_array());
+}
@@ -0,0 +1,119 @@
+use arrow2::array::*;
+use arrow2::datatypes::*;
+use arrow2_convert::deserialize::TryIntoCollection;
+use arrow2_convert::serialize::TryIntoArrow;
+use arrow2_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+#[test]
+fn test_ipv4_round_trip() {
+    let original = vec![Ipv4Addr::new(127, 0, 0, 1), Ipv4Addr::new(10, 1, 2, 3)];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    assert_eq!(b.data_type(), &DataType::FixedSizeBinary(4));
+    let values = b.as_any().downcast_ref::<FixedSizeBinaryArray>().unwrap();
+    assert_eq!(values.value(1), &[10, 1, 2, 3]);
+    let round_trip: Vec<Ipv4Addr> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
+
+#[test]
+fn test_ipv6_round_trip() {
+    let original = vec![
+        Some(Ipv6Addr::LOCALHOST),
+        None,
+        Some(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)),
+    ];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    assert_eq!(b.data_type(), &DataType::FixedSizeBinary(16));
+    let round_trip: Vec<Option<Ipv6Addr>> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
+
+#[test]
+fn test_ip_addr_round_trip() {
+    let original = vec![
+        IpAddr::V4(Ipv4Addr::new(192, 168, 0, 1)),
+        IpAddr::V6(Ipv6Addr::LOCALHOST),
+        IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+    ];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    assert_eq!(
+        b.data_type(),
+        &DataType::Union(
+            vec![
+                Field::new("V4", DataType::FixedSizeBinary(4), true),
+                Field::new("V6", DataType::FixedSizeBinary(16), false),
+            ],
+            None,
+            UnionMode::Dense
+        )
+    );
+    let union = b.as_any().downcast_ref::<UnionArray>().unwrap();
+    assert_eq!(union.types().as_slice(), &[0, 1, 0]);
+    let round_trip: Vec<IpAddr> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+
+    // the offsets of another writer don't have to follow the order of the rows
+    let union = UnionArray::new(
+        <IpAddr as arrow2_convert::field::ArrowField>::data_type(),
+        vec![0, 1, 0].into(),
+        vec![
+            FixedSizeBinaryArray::from([Some([1, 1, 1, 1]), Some([2, 2, 2, 2])]).boxed(),
+            FixedSizeBinaryArray::from([Some(Ipv6Addr::LOCALHOST.octets())]).boxed(),
+        ],
+        Some(vec![1, 0, 0].into()),
+    );
+    let round_trip: Vec<IpAddr> = union.boxed().try_into_collection().unwrap();
+    assert_eq!(
+        round_trip,
+        vec![
+            IpAddr::V4(Ipv4Addr::new(2, 2, 2, 2)),
+            IpAddr::V6(Ipv6Addr::LOCALHOST),
+            IpAddr::V4(Ipv4Addr::new(1, 1, 1, 1)),
+        ]
+    );
+}
+
+#[test]
+fn test_ip_addr_null() {
+    // unions don't have a validity, so a null is stored in the nullable `V4` child
+    let original = vec![
+        Some(IpAddr::V6(Ipv6Addr::LOCALHOST)),
+        None,
+        Some(IpAddr::V4(Ipv4Addr::UNSPECIFIED)),
+    ];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    let union = b.as_any().downcast_ref::<UnionArray>().unwrap();
+    assert_eq!(union.fields()[0].null_count(), 1);
+    let round_trip: Vec<Option<IpAddr>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_ip_addr_in_struct() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Connection {
+        source: IpAddr,
+        destination: Option<Ipv4Addr>,
+        hops: Vec<IpAddr>,
+    }
+
+    let original = vec![
+        Connection {
+            source: IpAddr::V6(Ipv6Addr::LOCALHOST),
+            destination: Some(Ipv4Addr::LOCALHOST),
+            hops: vec![
+                IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+                IpAddr::V6(Ipv6Addr::UNSPECIFIED),
+            ],
+        },
+        Connection {
+            source: IpAddr::V4(Ipv4Addr::BROADCAST),
+            destination: None,
+            hops: vec![],
+        },
+    ];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Connection> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
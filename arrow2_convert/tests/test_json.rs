@@ -0,0 +1,94 @@
+#![cfg(feature = "serde_json")]
+
+use arrow2::array::*;
+use arrow2::datatypes::*;
+use arrow2_convert::deserialize::{arrow_array_deserialize_iterator_as_type, TryIntoCollection};
+use arrow2_convert::field::Json;
+use arrow2_convert::serialize::{arrow_serialize_to_mutable_array, TryIntoArrow};
+use arrow2_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+use serde_json::json;
+
+#[test]
+fn test_json_round_trip() {
+    let original = vec![
+        json!({"a": 1, "b": [true, null]}),
+        json!("string"),
+        serde_json::Value::Null,
+    ];
+
+    // `serde_json::Value` doesn't implement the arrow traits itself, so use the lower level API
+    let b: Box<dyn Array> =
+        arrow_serialize_to_mutable_array::<_, Json<serde_json::Value>, _>(&original)
+            .unwrap()
+            .as_box();
+    assert_eq!(
+        b.data_type(),
+        &DataType::Extension("json".to_string(), Box::new(DataType::Utf8), None)
+    );
+    let values = b.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+    assert_eq!(values.value(0), r#"{"a":1,"b":[true,null]}"#);
+
+    let round_trip: Vec<serde_json::Value> =
+        arrow_array_deserialize_iterator_as_type::<_, Json<serde_json::Value>>(b.as_ref())
+            .unwrap()
+            .collect();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_json_in_struct() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Event {
+        id: i64,
+        #[arrow_field(type = "Json<serde_json::Value>")]
+        payload: serde_json::Value,
+        #[arrow_field(type = "Option<Json<serde_json::Value>>")]
+        extra: Option<serde_json::Value>,
+    }
+
+    let original = vec![
+        Event {
+            id: 1,
+            payload: json!({"nested": {"x": [1, 2, 3]}}),
+            extra: None,
+        },
+        Event {
+            id: 2,
+            payload: json!([]),
+            extra: Some(json!(1.5)),
+        },
+    ];
+
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Event> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_json_malformed() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowDeserialize)]
+    struct Event {
+        #[arrow_field(type = "Option<Json<Vec<i32>>>")]
+        values: Option<Vec<i32>>,
+    }
+
+    // the second row isn't valid JSON, and the third doesn't match the type, so the second one is reported
+    let values = Utf8Array::<i32>::from([Some("[1]"), Some("[1,"), Some("{}")]);
+    let values = Utf8Array::<i32>::new(
+        DataType::Extension("json".to_string(), Box::new(DataType::Utf8), None),
+        values.offsets().clone(),
+        values.values().clone(),
+        None,
+    );
+    let b = StructArray::new(
+        <Event as arrow2_convert::field::ArrowField>::data_type(),
+        vec![values.boxed()],
+        None,
+    )
+    .boxed();
+    let result: arrow2_convert::error::Result<Vec<Event>> = b.try_into_collection();
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Deserialize error: Invalid JSON at row 1: EOF while parsing a value at line 1 column 3"
+    );
+}
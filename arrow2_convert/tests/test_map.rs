@@ -0,0 +1,228 @@
+use arrow2::array::*;
+use arrow2::datatypes::*;
+use arrow2_convert::{
+    deserialize::TryIntoCollection, serialize::TryIntoArrow, ArrowDeserialize, ArrowField,
+    ArrowSerialize,
+};
+use std::collections::HashMap;
+
+#[test]
+fn test_map_schema() {
+    assert_eq!(
+        <HashMap<i32, Option<String>> as arrow2_convert::field::ArrowField>::data_type(),
+        DataType::Map(
+            Box::new(Field::new(
+                "entries",
+                DataType::Struct(vec![
+                    Field::new("key", DataType::Int32, false),
+                    Field::new("value", DataType::Utf8, true),
+                ]),
+                false
+            )),
+            false
+        )
+    );
+    assert_eq!(
+        <char as arrow2_convert::field::ArrowField>::data_type(),
+        DataType::Utf8
+    );
+}
+
+#[test]
+fn test_int_keyed_map() {
+    let original_array = vec![
+        HashMap::from([(1, "one".to_string()), (2, "two".to_string())]),
+        HashMap::new(),
+        HashMap::from([(3, "three".to_string())]),
+    ];
+    let b: Box<dyn Array> = original_array.try_into_arrow().unwrap();
+    let map_array = b.as_any().downcast_ref::<MapArray>().unwrap();
+    let entries = map_array
+        .field()
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap();
+    assert_eq!(entries.values()[0].data_type(), &DataType::Int32);
+    assert_eq!(entries.values()[1].data_type(), &DataType::Utf8);
+
+    let round_trip: Vec<HashMap<i32, String>> = b.try_into_collection().unwrap();
+    assert_eq!(original_array, round_trip);
+
+    let original_array = vec![Some(HashMap::from([(1, "one".to_string())])), None];
+    let b: Box<dyn Array> = original_array.try_into_arrow().unwrap();
+    let round_trip: Vec<Option<HashMap<i32, String>>> = b.try_into_collection().unwrap();
+    assert_eq!(original_array, round_trip);
+}
+
+#[test]
+fn test_char_keyed_map() {
+    let original_array = vec![HashMap::from([('a', 1), ('ß', 2), ('😀', 3)])];
+    let b: Box<dyn Array> = original_array.try_into_arrow().unwrap();
+    let round_trip: Vec<HashMap<char, i32>> = b.try_into_collection().unwrap();
+    assert_eq!(original_array, round_trip);
+}
+
+#[test]
+fn test_char_rejects_strings() {
+    // empty strings and strings with more than one character aren't a `char`
+    for value in ["", "ab"] {
+        let b: Box<dyn Array> = vec!["a".to_string(), value.to_string()]
+            .try_into_arrow()
+            .unwrap();
+        let result: arrow2_convert::error::Result<Vec<char>> = b.try_into_collection();
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            format!("Deserialize error: Expected a single character at row 1, found {value:?}")
+        );
+    }
+}
+
+#[test]
+fn test_enum_keyed_map() {
+    #[derive(
+        Debug, Clone, Copy, PartialEq, Eq, Hash, ArrowField, ArrowSerialize, ArrowDeserialize,
+    )]
+    #[arrow_field(type = "i8")]
+    enum Color {
+        Red,
+        Green,
+        Blue,
+    }
+
+    let original_array = vec![
+        HashMap::from([(Color::Red, 1), (Color::Blue, 3)]),
+        HashMap::from([(Color::Green, 2)]),
+    ];
+    let b: Box<dyn Array> = original_array.try_into_arrow().unwrap();
+    // the keys are stored as the `Int8` type id of each variant rather than as a union
+    let map_array = b.as_any().downcast_ref::<MapArray>().unwrap();
+    let entries = map_array
+        .field()
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap();
+    assert_eq!(entries.values()[0].data_type(), &DataType::Int8);
+    let round_trip: Vec<HashMap<Color, i32>> = b.try_into_collection().unwrap();
+    assert_eq!(original_array, round_trip);
+}
+
+#[test]
+fn test_nested_map() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct S {
+        a1: HashMap<i64, Vec<String>>,
+        a2: Vec<HashMap<String, Option<i32>>>,
+    }
+
+    let original_array = vec![
+        S {
+            a1: HashMap::from([(1, vec!["a".to_string()]), (2, vec![])]),
+            a2: vec![HashMap::from([
+                ("x".to_string(), Some(1)),
+                ("y".to_string(), None),
+            ])],
+        },
+        S {
+            a1: HashMap::new(),
+            a2: vec![],
+        },
+    ];
+    let b: Box<dyn Array> = original_array.try_into_arrow().unwrap();
+    let round_trip: Vec<S> = b.try_into_collection().unwrap();
+    assert_eq!(original_array, round_trip);
+}
+
+#[test]
+fn test_btree_map() {
+    use std::collections::BTreeMap;
+
+    let original_array = vec![
+        BTreeMap::from([("b".to_string(), 2), ("a".to_string(), 1)]),
+        BTreeMap::new(),
+    ];
+    let b: Box<dyn Array> = original_array.try_into_arrow().unwrap();
+    assert_eq!(
+        b.data_type(),
+        &<HashMap<String, i32> as arrow2_convert::field::ArrowField>::data_type()
+    );
+    let round_trip: Vec<BTreeMap<String, i32>> = b.try_into_collection().unwrap();
+    assert_eq!(original_array, round_trip);
+
+    // a map serialized from a HashMap can be read back as a BTreeMap
+    let hash_maps = vec![HashMap::from([("a".to_string(), 1), ("b".to_string(), 2)])];
+    let b: Box<dyn Array> = hash_maps.try_into_arrow().unwrap();
+    let round_trip: Vec<BTreeMap<String, i32>> = b.try_into_collection().unwrap();
+    assert_eq!(
+        round_trip,
+        vec![BTreeMap::from([("a".to_string(), 1), ("b".to_string(), 2)])]
+    );
+}
+
+#[test]
+fn test_map_entries_preserve_order() {
+    use arrow2_convert::field::MapEntries;
+
+    // keys are intentionally unsorted and include a duplicate
+    let original_array = vec![
+        MapEntries(vec![
+            (3, "c".to_string()),
+            (1, "a".to_string()),
+            (2, "b".to_string()),
+        ]),
+        MapEntries(vec![]),
+        MapEntries(vec![(9, "x".to_string()), (9, "y".to_string())]),
+    ];
+    let b: Box<dyn Array> = original_array.try_into_arrow().unwrap();
+
+    let map_array = b.as_any().downcast_ref::<MapArray>().unwrap();
+    let entries = map_array
+        .field()
+        .as_any()
+        .downcast_ref::<StructArray>()
+        .unwrap();
+    assert_eq!(
+        entries.values()[0].as_ref(),
+        &Int32Array::from_slice([3, 1, 2, 9, 9]) as &dyn Array
+    );
+
+    let round_trip: Vec<MapEntries<i32, String>> = b.try_into_collection().unwrap();
+    assert_eq!(original_array, round_trip);
+}
+
+#[test]
+fn test_map_type_override() {
+    use arrow2_convert::field::Map;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Labels {
+        #[arrow_field(type = "Map<String, i32>")]
+        labels: Vec<(String, i32)>,
+    }
+
+    let original_array = vec![
+        vec![
+            ("b".to_string(), 2),
+            ("a".to_string(), 1),
+            ("b".to_string(), 3),
+        ],
+        vec![],
+    ];
+    let original_array = original_array
+        .into_iter()
+        .map(|labels| Labels { labels })
+        .collect::<Vec<_>>();
+    let b: Box<dyn Array> = original_array.try_into_arrow().unwrap();
+    let struct_array = b.as_any().downcast_ref::<StructArray>().unwrap();
+    assert_eq!(
+        struct_array.values()[0].data_type(),
+        &<Map<String, i32> as arrow2_convert::field::ArrowField>::data_type()
+    );
+    let map_array = struct_array.values()[0]
+        .as_any()
+        .downcast_ref::<MapArray>()
+        .unwrap();
+    assert_eq!(map_array.offsets().as_slice(), &[0, 3, 3]);
+
+    let round_trip: Vec<Labels> = b.try_into_collection().unwrap();
+    assert_eq!(original_array, round_trip);
+}
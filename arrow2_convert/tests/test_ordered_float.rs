@@ -0,0 +1,49 @@
+#![cfg(feature = "ordered-float")]
+
+use arrow2::array::*;
+use arrow2::datatypes::DataType;
+use arrow2_convert::deserialize::TryIntoCollection;
+use arrow2_convert::serialize::TryIntoArrow;
+use arrow2_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+use ordered_float::OrderedFloat;
+use std::collections::BTreeMap;
+
+#[test]
+fn test_ordered_float_round_trip() {
+    // NaN is stored as is, and compares equal to itself as an `OrderedFloat`
+    let original = vec![
+        OrderedFloat(1.5_f64),
+        OrderedFloat(f64::NAN),
+        OrderedFloat(f64::NEG_INFINITY),
+        OrderedFloat(-0.0),
+    ];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    assert_eq!(b.data_type(), &DataType::Float64);
+    let values = b.as_any().downcast_ref::<Float64Array>().unwrap();
+    assert!(values.value(1).is_nan());
+    let round_trip: Vec<OrderedFloat<f64>> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+
+    let original = vec![Some(OrderedFloat(2.0_f32)), None];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    assert_eq!(b.data_type(), &DataType::Float32);
+    let round_trip: Vec<Option<OrderedFloat<f32>>> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
+
+#[test]
+fn test_ordered_float_map_key() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Histogram {
+        buckets: BTreeMap<OrderedFloat<f64>, u64>,
+        bounds: Vec<OrderedFloat<f64>>,
+    }
+
+    let original = vec![Histogram {
+        buckets: BTreeMap::from([(OrderedFloat(0.5), 3), (OrderedFloat(10.0), 1)]),
+        bounds: vec![OrderedFloat(0.0), OrderedFloat(100.0)],
+    }];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Histogram> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
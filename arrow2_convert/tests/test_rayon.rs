@@ -0,0 +1,40 @@
+#![cfg(feature = "rayon")]
+
+use arrow2::array::*;
+use arrow2_convert::deserialize::TryIntoCollection;
+use arrow2_convert::serialize::{try_into_arrow_parallel, TryIntoArrow};
+use arrow2_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+
+#[test]
+fn test_try_into_arrow_parallel() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Row {
+        id: i64,
+        name: Option<String>,
+        values: Vec<f64>,
+    }
+
+    let rows = (0..1000)
+        .map(|i| Row {
+            id: i,
+            name: (i % 3 != 0).then(|| format!("row {i}")),
+            values: vec![i as f64; (i % 4) as usize],
+        })
+        .collect::<Vec<_>>();
+
+    let sequential: Box<dyn Array> = rows.try_into_arrow().unwrap();
+    // the last chunk is shorter than the others
+    for chunk_size in [1, 7, 256, 1000, 5000] {
+        let parallel = try_into_arrow_parallel(&rows, chunk_size).unwrap();
+        assert_eq!(parallel, sequential);
+    }
+    let round_trip: Vec<Row> = try_into_arrow_parallel(&rows, 100)
+        .unwrap()
+        .try_into_collection()
+        .unwrap();
+    assert_eq!(round_trip, rows);
+
+    let empty = try_into_arrow_parallel::<Row>(&[], 10).unwrap();
+    assert_eq!(empty.len(), 0);
+    assert!(try_into_arrow_parallel(&rows, 0).is_err());
+}
@@ -0,0 +1,89 @@
+use arrow2::array::*;
+use arrow2::datatypes::*;
+use arrow2_convert::deserialize::TryIntoCollection;
+use arrow2_convert::serialize::TryIntoArrow;
+use arrow2_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+
+#[test]
+fn test_result_round_trip() {
+    let original: Vec<Result<i64, String>> = vec![
+        Ok(1),
+        Err("failed".to_string()),
+        Ok(3),
+        Err("again".to_string()),
+    ];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    assert_eq!(
+        b.data_type(),
+        &DataType::Union(
+            vec![
+                Field::new("Ok", DataType::Int64, true),
+                Field::new("Err", DataType::Utf8, false),
+            ],
+            None,
+            UnionMode::Dense
+        )
+    );
+    let union = b.as_any().downcast_ref::<UnionArray>().unwrap();
+    assert_eq!(union.types().as_slice(), &[0, 1, 0, 1]);
+    assert_eq!(union.offsets().unwrap().as_slice(), &[0, 0, 1, 1]);
+
+    let round_trip: Vec<Result<i64, String>> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
+
+#[test]
+fn test_result_nested() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Payload {
+        value: f64,
+    }
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Row {
+        id: u32,
+        outcome: Result<Payload, String>,
+        retry: Option<Result<u16, String>>,
+        attempts: Vec<Result<u16, String>>,
+    }
+
+    let original = vec![
+        Row {
+            id: 1,
+            outcome: Ok(Payload { value: 1.5 }),
+            retry: None,
+            attempts: vec![Err("timeout".to_string()), Ok(2)],
+        },
+        Row {
+            id: 2,
+            outcome: Err("invalid input".to_string()),
+            retry: Some(Err("gave up".to_string())),
+            attempts: vec![],
+        },
+        Row {
+            id: 3,
+            outcome: Ok(Payload { value: -2.0 }),
+            retry: Some(Ok(4)),
+            attempts: vec![Ok(1)],
+        },
+    ];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Row> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
+
+#[test]
+fn test_result_null() {
+    // unions don't have a validity, so a null is stored in the nullable `Ok` child
+    let original: Vec<Option<Result<i64, String>>> =
+        vec![Some(Err("failed".to_string())), None, Some(Ok(2))];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    let union = b.as_any().downcast_ref::<UnionArray>().unwrap();
+    match b.data_type() {
+        DataType::Union(fields, _, _) => assert!(fields[0].is_nullable),
+        dt => panic!("unexpected data type {dt:?}"),
+    }
+    assert_eq!(union.fields()[0].null_count(), 1);
+    let round_trip: Vec<Option<Result<i64, String>>> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
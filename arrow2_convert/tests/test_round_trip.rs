@@ -2,7 +2,7 @@ use arrow2::array::*;
 use arrow2::datatypes::*;
 use arrow2_convert::deserialize::arrow_array_deserialize_iterator_as_type;
 use arrow2_convert::deserialize::*;
-use arrow2_convert::field::{LargeBinary, I128};
+use arrow2_convert::field::{DoubleOption, LargeBinary, U8List, I128};
 use arrow2_convert::serialize::*;
 use arrow2_convert::{
     field::{FixedSizeBinary, FixedSizeVec, LargeString, LargeVec},
@@ -88,6 +88,40 @@ fn test_large_binary_nested() {
     assert_eq!(round_trip, strs);
 }
 
+#[test]
+fn test_u8_list() {
+    let bytes = [b"abc".to_vec(), vec![]];
+    let b: Box<dyn Array> = bytes.try_into_arrow_as_type::<U8List>().unwrap();
+    assert_eq!(
+        b.data_type(),
+        &DataType::List(Box::new(Field::new("item", DataType::UInt8, false)))
+    );
+    let round_trip: Vec<Vec<u8>> = b.try_into_collection_as_type::<U8List>().unwrap();
+    assert_eq!(round_trip, bytes);
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Packet {
+        #[arrow_field(type = "U8List")]
+        payload: Vec<u8>,
+        #[arrow_field(type = "Option<U8List>")]
+        checksum: Option<Vec<u8>>,
+    }
+
+    let original = vec![
+        Packet {
+            payload: vec![1, 2, 3],
+            checksum: Some(vec![6]),
+        },
+        Packet {
+            payload: vec![],
+            checksum: None,
+        },
+    ];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Packet> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
 #[test]
 fn test_fixed_size_binary() {
     let strs = [b"abc".to_vec()];
@@ -143,6 +177,45 @@ fn test_fixed_size_vec() {
     assert_eq!(round_trip, ints);
 }
 
+#[test]
+fn test_fixed_size_vec_nullable_items() {
+    let ints = vec![vec![Some(1), None, Some(3)], vec![None, None, None]];
+    let b: Box<dyn Array> = ints
+        .try_into_arrow_as_type::<FixedSizeVec<Option<i32>, 3>>()
+        .unwrap();
+    assert_eq!(
+        b.data_type(),
+        &DataType::FixedSizeList(Box::new(Field::new("item", DataType::Int32, true)), 3)
+    );
+    let values = b
+        .as_any()
+        .downcast_ref::<FixedSizeListArray>()
+        .unwrap()
+        .values();
+    assert_eq!(values.null_count(), 4);
+    let round_trip: Vec<Vec<Option<i32>>> = b
+        .try_into_collection_as_type::<FixedSizeVec<Option<i32>, 3>>()
+        .unwrap();
+    assert_eq!(round_trip, ints);
+
+    // nullable items inside a nullable fixed size list in a struct
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct S {
+        #[arrow_field(type = "Option<FixedSizeVec<Option<i32>, 3>>")]
+        values: Option<Vec<Option<i32>>>,
+    }
+
+    let original = vec![
+        S {
+            values: Some(vec![Some(1), None, Some(3)]),
+        },
+        S { values: None },
+    ];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    let round_trip: Vec<S> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
+
 #[test]
 fn test_primitive_type_vec() {
     macro_rules! test_int_type {
@@ -264,6 +337,101 @@ fn test_primitive_type_vec() {
     assert_eq!(original_array, round_trip);
 }
 
+#[test]
+fn test_f16_struct() {
+    use arrow2::types::f16;
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Half {
+        value: f16,
+        optional: Option<f16>,
+        values: Vec<f16>,
+        nested: Vec<Vec<f16>>,
+    }
+
+    let original_array = vec![
+        Half {
+            value: f16::from_f32(1.5),
+            optional: None,
+            values: vec![f16::from_f32(-2.0), f16::from_f32(0.25)],
+            nested: vec![vec![f16::from_f32(3.0)], vec![]],
+        },
+        Half {
+            value: f16::from_f32(0.0),
+            optional: Some(f16::from_f32(65504.0)),
+            values: vec![],
+            nested: vec![],
+        },
+    ];
+    assert_eq!(
+        <Half as arrow2_convert::field::ArrowField>::data_type(),
+        DataType::Struct(vec![
+            Field::new("value", DataType::Float16, false),
+            Field::new("optional", DataType::Float16, true),
+            Field::new(
+                "values",
+                DataType::List(Box::new(Field::new("item", DataType::Float16, false))),
+                false
+            ),
+            Field::new(
+                "nested",
+                DataType::List(Box::new(Field::new(
+                    "item",
+                    DataType::List(Box::new(Field::new("item", DataType::Float16, false))),
+                    false
+                ))),
+                false
+            ),
+        ])
+    );
+    let b: Box<dyn Array> = original_array.try_into_arrow().unwrap();
+    let round_trip: Vec<Half> = b.try_into_collection().unwrap();
+    assert_eq!(original_array, round_trip);
+}
+
+#[test]
+fn test_vec_deque() {
+    use std::collections::VecDeque;
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct RingBuffer {
+        values: VecDeque<i32>,
+        nested: Vec<VecDeque<Option<String>>>,
+    }
+
+    // wrap around so that the elements aren't contiguous in the ring buffer
+    let mut values = VecDeque::with_capacity(4);
+    values.extend([1, 2, 3, 4]);
+    values.pop_front();
+    values.pop_front();
+    values.push_back(5);
+    values.push_front(0);
+
+    let original_array = vec![
+        RingBuffer {
+            values,
+            nested: vec![VecDeque::from([Some("a".to_string()), None])],
+        },
+        RingBuffer {
+            values: VecDeque::new(),
+            nested: vec![],
+        },
+    ];
+    assert_eq!(
+        <VecDeque<i32> as arrow2_convert::field::ArrowField>::data_type(),
+        <Vec<i32> as arrow2_convert::field::ArrowField>::data_type()
+    );
+    let b: Box<dyn Array> = original_array.try_into_arrow().unwrap();
+    let round_trip: Vec<RingBuffer> = b.as_ref().try_into_collection().unwrap();
+    assert_eq!(original_array, round_trip);
+    assert_eq!(round_trip[0].values, [0, 3, 4, 5]);
+
+    // VecDeque and Vec are interchangeable
+    let values = b.as_any().downcast_ref::<StructArray>().unwrap().values()[0].as_ref();
+    let vecs: Vec<Vec<i32>> = values.try_into_collection().unwrap();
+    assert_eq!(vecs, vec![vec![0, 3, 4, 5], vec![]]);
+}
+
 #[test]
 fn test_escaped_name() {
     #[derive(ArrowField, ArrowSerialize, ArrowDeserialize, Debug, Eq, PartialEq)]
@@ -282,3 +450,448 @@ fn test_escaped_name() {
     let round_trip: Vec<EscapedName> = b.try_into_collection().unwrap();
     assert_eq!(array.as_slice(), round_trip.as_slice());
 }
+
+#[test]
+#[allow(clippy::box_collection)]
+fn test_box() {
+    #[derive(Debug, Clone, ArrowField, ArrowSerialize, ArrowDeserialize, PartialEq)]
+    struct BoxedString {
+        a1: Box<String>,
+    }
+
+    #[derive(Debug, Clone, ArrowField, ArrowSerialize, ArrowDeserialize, PartialEq)]
+    struct OptionalBoxedInt {
+        a1: Option<Box<i64>>,
+    }
+
+    assert_eq!(
+        <BoxedString as arrow2_convert::field::ArrowField>::data_type(),
+        DataType::Struct(vec![Field::new("a1", DataType::Utf8, false)])
+    );
+    assert_eq!(
+        <OptionalBoxedInt as arrow2_convert::field::ArrowField>::data_type(),
+        DataType::Struct(vec![Field::new("a1", DataType::Int64, true)])
+    );
+
+    let original_array = vec![
+        BoxedString {
+            a1: Box::new("hello".to_string()),
+        },
+        BoxedString {
+            a1: Box::new("world".to_string()),
+        },
+    ];
+    let b: Box<dyn Array> = original_array.try_into_arrow().unwrap();
+    let round_trip: Vec<BoxedString> = b.try_into_collection().unwrap();
+    assert_eq!(original_array, round_trip);
+
+    let original_array = vec![
+        OptionalBoxedInt {
+            a1: Some(Box::new(1)),
+        },
+        OptionalBoxedInt { a1: None },
+        OptionalBoxedInt {
+            a1: Some(Box::new(3)),
+        },
+    ];
+    let b: Box<dyn Array> = original_array.try_into_arrow().unwrap();
+    let round_trip: Vec<OptionalBoxedInt> = b.try_into_collection().unwrap();
+    assert_eq!(original_array, round_trip);
+}
+
+#[test]
+fn test_naive_date_as_days() {
+    use chrono::NaiveDate;
+
+    #[derive(Debug, Clone, ArrowField, ArrowSerialize, ArrowDeserialize, PartialEq)]
+    struct S {
+        #[arrow_field(as_days_int)]
+        a1: NaiveDate,
+        #[arrow_field(as_days_int)]
+        a2: Option<NaiveDate>,
+    }
+
+    assert_eq!(
+        <S as arrow2_convert::field::ArrowField>::data_type(),
+        DataType::Struct(vec![
+            Field::new("a1", DataType::Int32, false),
+            Field::new("a2", DataType::Int32, true),
+        ])
+    );
+
+    let original_array = vec![
+        S {
+            a1: NaiveDate::from_ymd_opt(1970, 1, 2).unwrap(),
+            a2: None,
+        },
+        S {
+            a1: NaiveDate::from_ymd_opt(1969, 12, 31).unwrap(),
+            a2: NaiveDate::from_ymd_opt(2022, 3, 4),
+        },
+    ];
+    let b: Box<dyn Array> = original_array.try_into_arrow().unwrap();
+    let struct_array = b.as_any().downcast_ref::<StructArray>().unwrap();
+    let days = struct_array.values()[0]
+        .as_any()
+        .downcast_ref::<Int32Array>()
+        .unwrap();
+    assert_eq!(days.values().as_slice(), &[1, -1]);
+
+    let round_trip: Vec<S> = b.try_into_collection().unwrap();
+    assert_eq!(original_array, round_trip);
+}
+
+#[test]
+fn test_interval_month_day_nano() {
+    use arrow2_convert::field::IntervalMonthDayNano;
+
+    let original_array = vec![
+        Some(IntervalMonthDayNano {
+            months: 1,
+            days: 2,
+            nanos: 3,
+        }),
+        None,
+        Some(IntervalMonthDayNano {
+            months: -12,
+            days: 0,
+            nanos: i64::MAX,
+        }),
+        Some(IntervalMonthDayNano::default()),
+    ];
+    let b: Box<dyn Array> = original_array.try_into_arrow().unwrap();
+    assert_eq!(
+        b.data_type(),
+        &DataType::Interval(IntervalUnit::MonthDayNano)
+    );
+    let values = b
+        .as_any()
+        .downcast_ref::<PrimitiveArray<arrow2::types::months_days_ns>>()
+        .unwrap();
+    assert_eq!(values.value(0), arrow2::types::months_days_ns::new(1, 2, 3));
+    let round_trip: Vec<Option<IntervalMonthDayNano>> = b.try_into_collection().unwrap();
+    assert_eq!(original_array, round_trip);
+}
+
+#[test]
+fn test_null_column() {
+    use arrow2_convert::field::NullColumn;
+
+    let original_array = vec![NullColumn; 3];
+    let b: Box<dyn Array> = original_array.try_into_arrow().unwrap();
+    assert_eq!(b.data_type(), &DataType::Null);
+    let nulls = b.as_any().downcast_ref::<NullArray>().unwrap();
+    assert_eq!(nulls.len(), 3);
+    let round_trip: Vec<NullColumn> = b.try_into_collection().unwrap();
+    assert_eq!(original_array, round_trip);
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct WithNull {
+        a: i32,
+        unused: NullColumn,
+    }
+
+    assert_eq!(
+        <WithNull as arrow2_convert::field::ArrowField>::data_type(),
+        DataType::Struct(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("unused", DataType::Null, true),
+        ])
+    );
+    let original_array = vec![
+        WithNull {
+            a: 1,
+            unused: NullColumn,
+        },
+        WithNull {
+            a: 2,
+            unused: NullColumn,
+        },
+    ];
+    let b: Box<dyn Array> = original_array.try_into_arrow().unwrap();
+    let round_trip: Vec<WithNull> = b.try_into_collection().unwrap();
+    assert_eq!(original_array, round_trip);
+}
+
+#[test]
+fn test_arc_str_and_bytes() {
+    let original_array: Vec<Arc<str>> = vec![Arc::from("hello"), Arc::from(""), Arc::from("ß")];
+    let b: Box<dyn Array> = original_array.try_into_arrow().unwrap();
+    assert_eq!(b.data_type(), &DataType::Utf8);
+    let round_trip: Vec<Arc<str>> = b.try_into_collection().unwrap();
+    assert_eq!(original_array, round_trip);
+
+    let original_array: Vec<Option<Arc<str>>> = vec![Some(Arc::from("a")), None];
+    let b: Box<dyn Array> = original_array.try_into_arrow().unwrap();
+    let round_trip: Vec<Option<Arc<str>>> = b.try_into_collection().unwrap();
+    assert_eq!(original_array, round_trip);
+
+    #[derive(Debug, Clone, ArrowField, ArrowSerialize, ArrowDeserialize, PartialEq)]
+    struct Shared {
+        names: Vec<Arc<str>>,
+        data: Arc<[u8]>,
+    }
+
+    assert_eq!(
+        <Shared as arrow2_convert::field::ArrowField>::data_type(),
+        DataType::Struct(vec![
+            Field::new(
+                "names",
+                DataType::List(Box::new(Field::new("item", DataType::Utf8, false))),
+                false
+            ),
+            Field::new("data", DataType::Binary, false),
+        ])
+    );
+
+    let original_array = vec![
+        Shared {
+            names: vec![Arc::from("a"), Arc::from("b")],
+            data: Arc::from(&b"abc"[..]),
+        },
+        Shared {
+            names: vec![],
+            data: Arc::from(&b""[..]),
+        },
+    ];
+    let b: Box<dyn Array> = original_array.try_into_arrow().unwrap();
+    let round_trip: Vec<Shared> = b.try_into_collection().unwrap();
+    assert_eq!(original_array, round_trip);
+}
+
+#[test]
+fn test_double_option() {
+    let original = vec![Some(Some(1_i32)), Some(None), None, Some(Some(4))];
+    let b: Box<dyn Array> = original
+        .try_into_arrow_as_type::<DoubleOption<i32>>()
+        .unwrap();
+    assert_eq!(
+        b.data_type(),
+        &DataType::Struct(vec![Field::new("value", DataType::Int32, true)])
+    );
+    let round_trip: Vec<Option<Option<i32>>> = b
+        .try_into_collection_as_type::<DoubleOption<i32>>()
+        .unwrap();
+    assert_eq!(original, round_trip);
+
+    // without the override, both levels share the same validity
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Option<Option<i32>>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, vec![Some(Some(1)), None, None, Some(Some(4))]);
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Patch {
+        #[arrow_field(type = "DoubleOption<String>")]
+        name: Option<Option<String>>,
+        #[arrow_field(type = "Vec<DoubleOption<i64>>")]
+        values: Vec<Option<Option<i64>>>,
+    }
+
+    let original = vec![
+        Patch {
+            name: Some(Some("a".to_string())),
+            values: vec![None, Some(None), Some(Some(3))],
+        },
+        Patch {
+            name: Some(None),
+            values: vec![],
+        },
+        Patch {
+            name: None,
+            values: vec![Some(None)],
+        },
+    ];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Patch> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
+
+#[test]
+fn test_empty_and_null_lists() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Lists {
+        required: Vec<i32>,
+        optional: Option<Vec<i32>>,
+    }
+
+    let original = vec![
+        Lists {
+            required: vec![],
+            optional: Some(vec![]),
+        },
+        Lists {
+            required: vec![1],
+            optional: None,
+        },
+        Lists {
+            required: vec![],
+            optional: Some(vec![2, 3]),
+        },
+    ];
+
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    let s = b.as_any().downcast_ref::<StructArray>().unwrap();
+    // an empty list is a valid zero-length list, while `None` is null
+    let required = s.values()[0]
+        .as_any()
+        .downcast_ref::<ListArray<i32>>()
+        .unwrap();
+    assert_eq!(required.null_count(), 0);
+    assert_eq!(required.value(0).len(), 0);
+    let optional = s.values()[1]
+        .as_any()
+        .downcast_ref::<ListArray<i32>>()
+        .unwrap();
+    assert!(optional.is_valid(0));
+    assert_eq!(optional.value(0).len(), 0);
+    assert!(optional.is_null(1));
+
+    let round_trip: Vec<Lists> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    // the same holds for a top-level column of optional lists
+    let original: Vec<Option<Vec<i32>>> = vec![Some(vec![]), None, Some(vec![1])];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    assert_eq!(b.null_count(), 1);
+    let round_trip: Vec<Option<Vec<i32>>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_i256() {
+    use arrow2::types::i256;
+
+    let values = [
+        i256::from_words(0, 1),
+        i256::from_words(-1, -5),
+        i256::from_words(i128::MAX, 0),
+    ];
+    let b: Box<dyn Array> = values.try_into_arrow().unwrap();
+    assert_eq!(b.data_type(), &DataType::Decimal256(76, 0));
+    let round_trip: Vec<i256> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, values);
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Hashes {
+        value: i256,
+        history: Vec<Option<i256>>,
+    }
+
+    let original = vec![Hashes {
+        value: i256::from_words(1, 2),
+        history: vec![Some(i256::from_words(3, 4)), None],
+    }];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Hashes> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_decimal_binary() {
+    use arrow2_convert::field::DecimalBinary;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Price {
+        #[arrow_field(type = "DecimalBinary<38, 2>")]
+        amount: i128,
+        #[arrow_field(type = "Option<DecimalBinary<38, 2>>")]
+        discount: Option<i128>,
+    }
+
+    let original_array = vec![
+        Price {
+            amount: 12_345,
+            discount: Some(-1),
+        },
+        Price {
+            amount: -(10_i128.pow(38) - 1),
+            discount: None,
+        },
+    ];
+    let b: Box<dyn Array> = original_array.try_into_arrow().unwrap();
+    let struct_array = b.as_any().downcast_ref::<StructArray>().unwrap();
+    assert_eq!(
+        struct_array.fields()[0].data_type,
+        DataType::FixedSizeBinary(16)
+    );
+    let amounts = struct_array.values()[0]
+        .as_any()
+        .downcast_ref::<FixedSizeBinaryArray>()
+        .unwrap();
+    assert_eq!(amounts.value(0), 12_345_i128.to_be_bytes());
+
+    let round_trip: Vec<Price> = b.try_into_collection().unwrap();
+    assert_eq!(original_array, round_trip);
+
+    // values are checked against the precision
+    let too_large = vec![1000_i128];
+    assert!(arrow_serialize_to_mutable_array::<_, DecimalBinary<3, 0>, _>(&too_large).is_err());
+}
+
+#[test]
+fn test_dictionary() {
+    use arrow2_convert::field::Dictionary;
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Event {
+        #[arrow_field(type = "Dictionary<i16, String, true>")]
+        level: String,
+        #[arrow_field(type = "Option<Dictionary<u8, String>>")]
+        source: Option<String>,
+    }
+
+    let original_array = ["warn", "info", "warn", "warn"]
+        .iter()
+        .zip([Some("app"), None, Some("db"), Some("app")])
+        .map(|(level, source)| Event {
+            level: level.to_string(),
+            source: source.map(str::to_string),
+        })
+        .collect::<Vec<_>>();
+    let b: Box<dyn Array> = original_array.try_into_arrow().unwrap();
+    let struct_array = b.as_any().downcast_ref::<StructArray>().unwrap();
+    // the key type and the ordered flag are kept in the data type
+    assert_eq!(
+        struct_array.fields()[0].data_type,
+        DataType::Dictionary(IntegerType::Int16, Box::new(DataType::Utf8), true)
+    );
+    assert_eq!(
+        struct_array.fields()[1].data_type,
+        DataType::Dictionary(IntegerType::UInt8, Box::new(DataType::Utf8), false)
+    );
+    let levels = struct_array.values()[0]
+        .as_any()
+        .downcast_ref::<DictionaryArray<i16>>()
+        .unwrap();
+    // the values of an ordered dictionary are sorted, even though "warn" comes first
+    assert_eq!(levels.keys().values().as_slice(), &[1, 0, 1, 1]);
+    assert_eq!(
+        levels.values().as_ref(),
+        &Utf8Array::<i32>::from_slice(["info", "warn"]) as &dyn Array
+    );
+    let sources = struct_array.values()[1]
+        .as_any()
+        .downcast_ref::<DictionaryArray<u8>>()
+        .unwrap();
+    assert_eq!(
+        sources.keys(),
+        &UInt8Array::from([Some(0), None, Some(1), Some(0)])
+    );
+
+    let round_trip: Vec<Event> = b.try_into_collection().unwrap();
+    assert_eq!(original_array, round_trip);
+
+    // dictionaries with another key type or ordering aren't deserialized
+    let levels = vec!["info".to_string()];
+    let unordered: Box<dyn Array> = levels
+        .try_into_arrow_as_type::<Dictionary<i16, String>>()
+        .unwrap();
+    let wider: Box<dyn Array> = levels
+        .try_into_arrow_as_type::<Dictionary<i32, String, true>>()
+        .unwrap();
+    for array in [unordered, wider] {
+        let result: arrow2_convert::error::Result<Vec<String>> =
+            array.try_into_collection_as_type::<Dictionary<i16, String, true>>();
+        assert!(result.is_err());
+    }
+}
@@ -241,3 +241,400 @@ fn test_large_string_schema() {
         DataType::List(Box::new(Field::new("item", DataType::LargeUtf8, false)))
     );
 }
+
+#[test]
+fn test_schema_rename() {
+    #[derive(Debug, ArrowField)]
+    #[allow(dead_code)]
+    struct Root {
+        #[arrow_field(rename = "id")]
+        a: i64,
+        b: Option<String>,
+    }
+
+    assert_eq!(
+        <Root as arrow2_convert::field::ArrowField>::data_type(),
+        DataType::Struct(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("b", DataType::Utf8, true),
+        ])
+    );
+}
+
+#[test]
+fn test_schema_nullable_override() {
+    #[derive(Debug, ArrowField)]
+    #[allow(dead_code)]
+    struct Root {
+        #[arrow_field(nullable = true)]
+        a: String,
+        #[arrow_field(nullable = false)]
+        b: i64,
+        c: Option<String>,
+    }
+
+    assert_eq!(
+        <Root as arrow2_convert::field::ArrowField>::data_type(),
+        DataType::Struct(vec![
+            Field::new("a", DataType::Utf8, true),
+            Field::new("b", DataType::Int64, false),
+            Field::new("c", DataType::Utf8, true),
+        ])
+    );
+}
+
+#[test]
+fn test_list_item_name() {
+    use arrow2::array::Array;
+    use arrow2_convert::field::{FixedSizeVec, LargeVec, LIST_ITEM_NAME};
+    use arrow2_convert::serialize::TryIntoArrow;
+    use arrow2_convert::ArrowSerialize;
+
+    #[derive(Debug, ArrowField, ArrowSerialize)]
+    struct Root {
+        #[arrow_field(type = "LargeVec<i32>")]
+        large: Vec<i32>,
+        #[arrow_field(type = "FixedSizeVec<i32, 2>")]
+        fixed: Vec<i32>,
+    }
+
+    let fields = match <Root as arrow2_convert::field::ArrowField>::data_type() {
+        DataType::Struct(fields) => fields,
+        _ => panic!("expected a struct"),
+    };
+    match fields[0].data_type() {
+        DataType::LargeList(item) => assert_eq!(item.name, LIST_ITEM_NAME),
+        dt => panic!("unexpected data type {dt:?}"),
+    }
+    match fields[1].data_type() {
+        DataType::FixedSizeList(item, 2) => assert_eq!(item.name, LIST_ITEM_NAME),
+        dt => panic!("unexpected data type {dt:?}"),
+    }
+
+    // the serialized arrays use the same child name as the declared data type
+    let b: Box<dyn Array> = [Root {
+        large: vec![1, 2],
+        fixed: vec![3, 4],
+    }]
+    .try_into_arrow()
+    .unwrap();
+    assert_eq!(
+        b.data_type(),
+        &<Root as arrow2_convert::field::ArrowField>::data_type()
+    );
+}
+
+#[test]
+fn test_extension_name() {
+    use arrow2::array::Array;
+    use arrow2_convert::deserialize::TryIntoCollection;
+    use arrow2_convert::serialize::TryIntoArrow;
+    use arrow2_convert::{ArrowDeserialize, ArrowSerialize};
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(extension_name = "my.point", extension_metadata = "{\"unit\":\"m\"}")]
+    struct Point {
+        x: f64,
+        y: f64,
+    }
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(type = "dense", extension_name = "my.shape")]
+    enum Shape {
+        Point(Point),
+        Empty,
+    }
+
+    assert_eq!(
+        <Point as arrow2_convert::field::ArrowField>::data_type(),
+        DataType::Extension(
+            "my.point".to_string(),
+            Box::new(DataType::Struct(vec![
+                Field::new("x", DataType::Float64, false),
+                Field::new("y", DataType::Float64, false),
+            ])),
+            Some("{\"unit\":\"m\"}".to_string()),
+        )
+    );
+    match <Shape as arrow2_convert::field::ArrowField>::data_type() {
+        DataType::Extension(name, inner, None) => {
+            assert_eq!(name, "my.shape");
+            assert!(matches!(*inner, DataType::Union(..)));
+        }
+        dt => panic!("unexpected data type {dt:?}"),
+    }
+
+    // the extension name is carried by the schema field
+    let schema = Schema::from(vec![<Point as arrow2_convert::field::ArrowField>::field(
+        "point",
+    )]);
+    match schema.fields[0].data_type() {
+        DataType::Extension(name, _, metadata) => {
+            assert_eq!(name, "my.point");
+            assert_eq!(metadata.as_deref(), Some("{\"unit\":\"m\"}"));
+        }
+        dt => panic!("unexpected data type {dt:?}"),
+    }
+
+    let points = vec![Point { x: 1.0, y: 2.0 }, Point { x: 3.0, y: 4.0 }];
+    let b: Box<dyn Array> = points.try_into_arrow().unwrap();
+    assert_eq!(
+        b.data_type(),
+        &<Point as arrow2_convert::field::ArrowField>::data_type()
+    );
+    let round_trip: Vec<Point> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, points);
+
+    let shapes = vec![Shape::Point(Point { x: 1.0, y: 2.0 }), Shape::Empty];
+    let b: Box<dyn Array> = shapes.try_into_arrow().unwrap();
+    let round_trip: Vec<Shape> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, shapes);
+}
+
+#[test]
+fn test_item_nullable() {
+    use arrow2::array::Array;
+    use arrow2_convert::deserialize::TryIntoCollection;
+    use arrow2_convert::field::LIST_ITEM_NAME;
+    use arrow2_convert::serialize::TryIntoArrow;
+    use arrow2_convert::{ArrowDeserialize, ArrowSerialize};
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Root {
+        #[arrow_field(item_nullable = true)]
+        a: Vec<String>,
+        #[arrow_field(item_nullable = false)]
+        b: Option<Vec<Option<i32>>>,
+        c: Vec<String>,
+    }
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowDeserialize)]
+    #[arrow_field(match_by_name)]
+    struct ByName {
+        #[arrow_field(item_nullable = true)]
+        a: Vec<String>,
+    }
+
+    let list = |data_type, nullable| {
+        DataType::List(Box::new(Field::new(LIST_ITEM_NAME, data_type, nullable)))
+    };
+    assert_eq!(
+        <Root as arrow2_convert::field::ArrowField>::data_type(),
+        DataType::Struct(vec![
+            Field::new("a", list(DataType::Utf8, true), false),
+            Field::new("b", list(DataType::Int32, false), true),
+            Field::new("c", list(DataType::Utf8, false), false),
+        ])
+    );
+
+    let original = vec![Root {
+        a: vec!["a".to_string()],
+        b: Some(vec![Some(1), Some(2)]),
+        c: vec!["c".to_string()],
+    }];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    assert_eq!(
+        b.data_type(),
+        &<Root as arrow2_convert::field::ArrowField>::data_type()
+    );
+    let round_trip: Vec<Root> = b.clone().try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    let by_name: Vec<ByName> = b.try_into_collection().unwrap();
+    assert_eq!(
+        by_name,
+        vec![ByName {
+            a: vec!["a".to_string()]
+        }]
+    );
+}
+
+#[test]
+fn test_large_list_attribute() {
+    use arrow2::array::Array;
+    use arrow2_convert::deserialize::TryIntoCollection;
+    use arrow2_convert::field::LIST_ITEM_NAME;
+    use arrow2_convert::serialize::TryIntoArrow;
+    use arrow2_convert::{ArrowDeserialize, ArrowSerialize};
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Root {
+        #[arrow_field(large_list)]
+        a: Vec<String>,
+        #[arrow_field(large_list)]
+        b: Option<Vec<i32>>,
+        c: Vec<i32>,
+    }
+
+    let fields = match <Root as arrow2_convert::field::ArrowField>::data_type() {
+        DataType::Struct(fields) => fields,
+        _ => panic!("expected a struct"),
+    };
+    assert_eq!(
+        fields[0],
+        Field::new(
+            "a",
+            DataType::LargeList(Box::new(Field::new(LIST_ITEM_NAME, DataType::Utf8, false))),
+            false
+        )
+    );
+    assert_eq!(
+        fields[1],
+        Field::new(
+            "b",
+            DataType::LargeList(Box::new(Field::new(LIST_ITEM_NAME, DataType::Int32, false))),
+            true
+        )
+    );
+    assert!(matches!(fields[2].data_type(), DataType::List(_)));
+
+    let original = vec![Root {
+        a: vec!["a".to_string()],
+        b: None,
+        c: vec![1],
+    }];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Root> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_large_binary_attribute() {
+    use arrow2::array::Array;
+    use arrow2_convert::deserialize::TryIntoCollection;
+    use arrow2_convert::field::LIST_ITEM_NAME;
+    use arrow2_convert::serialize::TryIntoArrow;
+    use arrow2_convert::{ArrowDeserialize, ArrowSerialize};
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Blobs {
+        list_binary: Vec<Vec<u8>>,
+        #[arrow_field(large_list)]
+        large_list_binary: Vec<Vec<u8>>,
+        #[arrow_field(large_binary)]
+        list_large_binary: Vec<Vec<u8>>,
+        #[arrow_field(large_list, large_binary)]
+        large_list_large_binary: Vec<Vec<u8>>,
+        #[arrow_field(large_binary)]
+        blob: Option<Vec<u8>>,
+        #[arrow_field(large_list, large_binary)]
+        nullable_items: Option<Vec<Option<Vec<u8>>>>,
+    }
+
+    let list = |binary| DataType::List(Box::new(Field::new(LIST_ITEM_NAME, binary, false)));
+    let large_list =
+        |binary| DataType::LargeList(Box::new(Field::new(LIST_ITEM_NAME, binary, false)));
+    assert_eq!(
+        <Blobs as arrow2_convert::field::ArrowField>::data_type(),
+        DataType::Struct(vec![
+            Field::new("list_binary", list(DataType::Binary), false),
+            Field::new("large_list_binary", large_list(DataType::Binary), false),
+            Field::new("list_large_binary", list(DataType::LargeBinary), false),
+            Field::new(
+                "large_list_large_binary",
+                large_list(DataType::LargeBinary),
+                false
+            ),
+            Field::new("blob", DataType::LargeBinary, true),
+            Field::new(
+                "nullable_items",
+                DataType::LargeList(Box::new(Field::new(
+                    LIST_ITEM_NAME,
+                    DataType::LargeBinary,
+                    true
+                ))),
+                true
+            ),
+        ])
+    );
+
+    let blobs = vec![vec![1, 2], vec![], vec![3]];
+    let original = vec![Blobs {
+        list_binary: blobs.clone(),
+        large_list_binary: blobs.clone(),
+        list_large_binary: blobs.clone(),
+        large_list_large_binary: blobs,
+        blob: Some(vec![4]),
+        nullable_items: Some(vec![None, Some(vec![5])]),
+    }];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Blobs> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_to_field() {
+    use arrow2_convert::field::to_field;
+
+    let field = to_field::<Option<String>>("name");
+    assert_eq!(field.name, "name");
+    assert!(field.is_nullable);
+    assert_eq!(field, Field::new("name", DataType::Utf8, true));
+
+    assert_eq!(
+        to_field::<Vec<i32>>("values"),
+        Field::new(
+            "values",
+            DataType::List(Box::new(Field::new("item", DataType::Int32, false))),
+            false
+        )
+    );
+}
+
+#[test]
+fn test_matches_field() {
+    use arrow2_convert::field::{matches_field, to_field};
+
+    #[derive(Debug, ArrowField)]
+    #[allow(dead_code)]
+    struct Row {
+        a: i32,
+        b: Option<String>,
+    }
+
+    let row_type = <Row as arrow2_convert::field::ArrowField>::data_type();
+
+    // the name and field level metadata don't have to match
+    let field = to_field::<Row>("rows").with_metadata(
+        [("key".to_string(), "value".to_string())]
+            .into_iter()
+            .collect(),
+    );
+    assert!(matches_field::<Row>(&field));
+    assert!(matches_field::<Row>(&Field::new(
+        "other",
+        row_type.clone(),
+        false
+    )));
+    assert!(matches_field::<Option<Row>>(&Field::new(
+        "rows",
+        row_type.clone(),
+        true
+    )));
+
+    // nullability
+    assert!(!matches_field::<Row>(&Field::new(
+        "rows",
+        row_type.clone(),
+        true
+    )));
+    assert!(!matches_field::<Option<i32>>(&Field::new(
+        "a",
+        DataType::Int32,
+        false
+    )));
+    // data type
+    assert!(!matches_field::<i32>(&Field::new(
+        "a",
+        DataType::Int64,
+        false
+    )));
+    assert!(!matches_field::<Row>(&Field::new(
+        "rows",
+        DataType::Struct(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, false),
+        ]),
+        false
+    )));
+}
@@ -1,6 +1,7 @@
 use arrow2::array::Array;
 use arrow2::buffer::Buffer;
 use arrow2::chunk::Chunk;
+use arrow2::datatypes::{DataType, Field};
 use arrow2_convert::field::{ArrowField, FixedSizeBinary};
 use arrow2_convert::serialize::*;
 use std::sync::Arc;
@@ -8,9 +9,42 @@ use std::sync::Arc;
 #[test]
 fn test_error_exceed_fixed_size_binary() {
     let strs = [b"abc".to_vec()];
-    let r: arrow2::error::Result<Box<dyn Array>> =
+    let r: arrow2_convert::error::Result<Box<dyn Array>> =
         strs.try_into_arrow_as_type::<FixedSizeBinary<2>>();
-    assert!(r.is_err())
+    assert!(r.is_err());
+    assert!(r
+        .unwrap_err()
+        .to_string()
+        .contains("FixedSizeBinary<2>: expected 2 bytes, got 3"));
+
+    let strs = [b"a".to_vec()];
+    let r: arrow2_convert::error::Result<Box<dyn Array>> =
+        strs.try_into_arrow_as_type::<FixedSizeBinary<2>>();
+    assert!(r
+        .unwrap_err()
+        .to_string()
+        .contains("FixedSizeBinary<2>: expected 2 bytes, got 1"));
+}
+
+#[test]
+fn test_error_exceed_i128_precision() {
+    use arrow2_convert::field::I128;
+
+    // the largest values with 5 digits fit
+    let values = [99_999_i128, -99_999];
+    assert!(arrow_serialize_to_mutable_array::<_, I128<5, 2>, _>(&values).is_ok());
+
+    for v in [100_000_i128, -100_000, i128::MIN] {
+        let r = arrow_serialize_to_mutable_array::<_, I128<5, 2>, _>(&[v]);
+        assert!(r
+            .unwrap_err()
+            .to_string()
+            .contains(&format!("I128<5, 2>: {v} exceeds the precision")));
+    }
+
+    // any i128 fits in 39 digits
+    let values = [i128::MAX, i128::MIN];
+    assert!(arrow_serialize_to_mutable_array::<_, I128<39, 0>, _>(&values).is_ok());
 }
 
 #[test]
@@ -70,6 +104,48 @@ fn test_array() {
     assert_eq!(r.data_type(), &<Vec<u8> as ArrowField>::data_type());
 }
 
+#[test]
+fn test_named() {
+    let ints = vec![1_i32, 2, 3];
+    let (r, field): (Box<dyn Array>, Field) = ints.try_into_arrow_named("ints").unwrap();
+    assert_eq!(r.len(), 3);
+    assert_eq!(field, Field::new("ints", DataType::Int32, false));
+    assert_eq!(r.data_type(), field.data_type());
+
+    let ints = vec![Some(1_i32), None];
+    let (r, field): (Arc<dyn Array>, Field) = ints.try_into_arrow_named("ints").unwrap();
+    assert_eq!(r.len(), 2);
+    assert_eq!(field, Field::new("ints", DataType::Int32, true));
+
+    let (r, field): (Chunk<Box<dyn Array>>, Field) = ints.try_into_arrow_named("ints").unwrap();
+    assert_eq!(r.len(), 2);
+    assert_eq!(r.arrays()[0].data_type(), field.data_type());
+}
+
+#[test]
+fn test_named_struct() {
+    use arrow2_convert::ArrowSerialize;
+
+    #[derive(Debug, arrow2_convert::ArrowField, ArrowSerialize)]
+    struct Point {
+        x: f64,
+        y: Option<f64>,
+    }
+
+    let points = vec![Point { x: 1.0, y: None }];
+    let (r, field): (Box<dyn Array>, Field) = points.try_into_arrow_named("points").unwrap();
+    assert_eq!(field.name, "points");
+    assert!(!field.is_nullable);
+    assert_eq!(
+        field.data_type(),
+        &DataType::Struct(vec![
+            Field::new("x", DataType::Float64, false),
+            Field::new("y", DataType::Float64, true),
+        ])
+    );
+    assert_eq!(r.data_type(), field.data_type());
+}
+
 #[test]
 fn test_buffer() {
     // Buffer<u8> and Vec<u8> should serialize into BinaryArray
@@ -128,6 +204,463 @@ fn test_field_serialize_error() {
     }
 
     let arr = vec![CustomType(0)];
-    let r: arrow2::error::Result<Box<dyn Array>> = arr.try_into_arrow();
+    let r: arrow2_convert::error::Result<Box<dyn Array>> = arr.try_into_arrow();
     assert!(r.is_err())
 }
+
+#[test]
+fn test_serialize_subset() {
+    use arrow2::array::{Int64Array, StructArray, Utf8Array};
+
+    #[derive(Debug, arrow2_convert::ArrowField, arrow2_convert::ArrowSerialize)]
+    struct Row {
+        a: i64,
+        b: String,
+        c: bool,
+    }
+
+    let rows = vec![
+        Row {
+            a: 1,
+            b: "one".to_string(),
+            c: true,
+        },
+        Row {
+            a: 2,
+            b: "two".to_string(),
+            c: false,
+        },
+    ];
+
+    let array = serialize_subset::<Row, Row, _>(&rows, &["b", "a"]).unwrap();
+    let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+    assert_eq!(
+        array.data_type(),
+        &DataType::Struct(vec![
+            Field::new("b", DataType::Utf8, false),
+            Field::new("a", DataType::Int64, false),
+        ])
+    );
+    assert_eq!(
+        array.values()[0].as_ref(),
+        &Utf8Array::<i32>::from_slice(["one", "two"]) as &dyn Array
+    );
+    assert_eq!(
+        array.values()[1].as_ref(),
+        &Int64Array::from_slice([1, 2]) as &dyn Array
+    );
+
+    let err = serialize_subset::<Row, Row, _>(&rows, &["a", "d"]).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        arrow2_convert::error::Error::InvalidArgument("Field `d` does not exist".to_string())
+            .to_string()
+    );
+}
+
+#[test]
+fn test_concat_arrays() {
+    use arrow2_convert::deserialize::TryIntoCollection;
+
+    #[derive(
+        Debug,
+        Clone,
+        PartialEq,
+        arrow2_convert::ArrowField,
+        arrow2_convert::ArrowSerialize,
+        arrow2_convert::ArrowDeserialize,
+    )]
+    struct Row {
+        a: i64,
+        b: Option<String>,
+    }
+
+    let first = vec![
+        Row {
+            a: 1,
+            b: Some("one".to_string()),
+        },
+        Row { a: 2, b: None },
+    ];
+    let second = vec![Row {
+        a: 3,
+        b: Some("three".to_string()),
+    }];
+
+    let arrays: Vec<Box<dyn Array>> = vec![
+        first.try_into_arrow().unwrap(),
+        second.try_into_arrow().unwrap(),
+    ];
+    let concatenated = concat_arrays(&arrays).unwrap();
+    assert_eq!(concatenated.len(), 3);
+
+    let round_trip: Vec<Row> = concatenated.try_into_collection().unwrap();
+    assert_eq!(round_trip, [first, second].concat());
+
+    // mismatched data types and empty input are rejected
+    let other: Box<dyn Array> = [1_i32, 2].try_into_arrow().unwrap();
+    assert!(concat_arrays(&[arrays[0].clone(), other]).is_err());
+    assert!(concat_arrays(&[]).is_err());
+}
+
+#[test]
+fn test_try_into_arrow_chunks() {
+    use arrow2::array::Int32Array;
+
+    let values = vec![1, 2, 3, 4, 5];
+    let chunks = try_into_arrow_chunks::<i32, i32, _>(&values, 2)
+        .collect::<arrow2_convert::error::Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(
+        chunks.iter().map(|c| c.len()).collect::<Vec<_>>(),
+        vec![2, 2, 1]
+    );
+    assert_eq!(
+        chunks[2].as_any().downcast_ref::<Int32Array>().unwrap(),
+        &Int32Array::from_slice([5])
+    );
+
+    let empty: Vec<i32> = vec![];
+    assert_eq!(try_into_arrow_chunks::<i32, i32, _>(&empty, 2).count(), 0);
+}
+
+#[test]
+fn test_generated_mutable_array() {
+    use arrow2::array::{MutableArray, TryPush};
+    use arrow2_convert::deserialize::TryIntoCollection;
+    use arrow2_convert::{ArrowDeserialize, ArrowSerialize};
+
+    #[derive(
+        Debug, Clone, PartialEq, arrow2_convert::ArrowField, ArrowSerialize, ArrowDeserialize,
+    )]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    // the generated type is named `Mutable{Name}Array`, and is also available as the `MutableArrayType`
+    let mut array: <Point as ArrowSerialize>::MutableArrayType = MutablePointArray::new();
+    array.reserve(3);
+    array.try_push(Some(Point { x: 1, y: 2 })).unwrap();
+    array.try_push(None::<Point>).unwrap();
+    array.try_push(Some(&Point { x: 3, y: 4 })).unwrap();
+    assert_eq!(array.len(), 3);
+
+    let b = array.as_box();
+    let round_trip: Vec<Option<Point>> = b.try_into_collection().unwrap();
+    assert_eq!(
+        round_trip,
+        vec![Some(Point { x: 1, y: 2 }), None, Some(Point { x: 3, y: 4 })]
+    );
+
+    // the array is emptied by `as_box` and can be reused for the next batch
+    assert_eq!(array.len(), 0);
+    array.try_push(Some(Point { x: 5, y: 6 })).unwrap();
+    assert_eq!(array.as_box().len(), 1);
+}
+
+#[test]
+fn test_mutable_array_reset() {
+    use arrow2::array::{MutableArray, TryPush};
+    use arrow2_convert::deserialize::TryIntoCollection;
+    use arrow2_convert::{ArrowDeserialize, ArrowSerialize};
+
+    #[derive(
+        Debug, Clone, PartialEq, arrow2_convert::ArrowField, ArrowSerialize, ArrowDeserialize,
+    )]
+    struct Point {
+        x: i32,
+        name: String,
+    }
+
+    #[derive(
+        Debug, Clone, PartialEq, arrow2_convert::ArrowField, ArrowSerialize, ArrowDeserialize,
+    )]
+    #[arrow_field(type = "dense")]
+    enum Shape {
+        Point(Point),
+        Radius(f64),
+    }
+
+    #[derive(
+        Debug, Clone, PartialEq, arrow2_convert::ArrowField, ArrowSerialize, ArrowDeserialize,
+    )]
+    #[arrow_field(type = "sparse")]
+    enum Tag {
+        Id(u32),
+        Label(String),
+    }
+
+    let point = |x: i32| Point {
+        x,
+        name: x.to_string(),
+    };
+
+    let mut array = MutablePointArray::new();
+    for i in 0..100 {
+        array.try_push((i % 2 == 0).then(|| point(i))).unwrap();
+    }
+    let capacity = array.validity().unwrap().capacity();
+    array.reset();
+    assert_eq!(array.len(), 0);
+    assert_eq!(array.validity().unwrap().len(), 0);
+    assert_eq!(array.validity().unwrap().capacity(), capacity);
+
+    array.try_push(Some(point(1))).unwrap();
+    array.try_push(None::<Point>).unwrap();
+    let round_trip: Vec<Option<Point>> = array.as_box().try_into_collection().unwrap();
+    assert_eq!(round_trip, vec![Some(point(1)), None]);
+
+    let mut array = MutableShapeArray::new();
+    array.try_push(Some(Shape::Radius(1.0))).unwrap();
+    array.try_push(Some(Shape::Point(point(2)))).unwrap();
+    array.reset();
+    assert_eq!(array.len(), 0);
+    array.try_push(Some(Shape::Point(point(3)))).unwrap();
+    let round_trip: Vec<Shape> = array.as_box().try_into_collection().unwrap();
+    assert_eq!(round_trip, vec![Shape::Point(point(3))]);
+
+    let mut array = MutableTagArray::new();
+    array.try_push(Some(Tag::Id(1))).unwrap();
+    array.reset();
+    array.try_push(Some(Tag::Label("a".to_string()))).unwrap();
+    array.try_push(Some(Tag::Id(2))).unwrap();
+    let round_trip: Vec<Tag> = array.as_box().try_into_collection().unwrap();
+    assert_eq!(round_trip, vec![Tag::Label("a".to_string()), Tag::Id(2)]);
+}
+
+#[test]
+fn test_into_columns() {
+    use arrow2_convert::deserialize::TryIntoCollection;
+
+    let pairs: Vec<(i64, String)> = vec![(1, "a".to_string()), (2, "b".to_string())];
+    let (keys, values) = try_into_columns(&pairs).unwrap();
+    assert_eq!(keys.data_type(), &DataType::Int64);
+    assert_eq!(values.data_type(), &DataType::Utf8);
+
+    let keys: Vec<i64> = keys.try_into_collection().unwrap();
+    let values: Vec<String> = values.try_into_collection().unwrap();
+    assert_eq!(keys, vec![1, 2]);
+    assert_eq!(values, vec!["a".to_string(), "b".to_string()]);
+}
+
+#[test]
+fn test_try_into_arrow_owned() {
+    use arrow2_convert::deserialize::TryIntoCollection;
+    use arrow2_convert::{ArrowDeserialize, ArrowSerialize};
+
+    #[derive(Debug, PartialEq, arrow2_convert::ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Foo {
+        a: i64,
+        b: String,
+    }
+
+    impl Foo {
+        fn new(i: i64) -> Self {
+            Self {
+                a: i,
+                b: i.to_string(),
+            }
+        }
+    }
+
+    let b: Box<dyn Array> = (0..100).map(Foo::new).try_into_arrow_owned().unwrap();
+    assert_eq!(b.len(), 100);
+    let round_trip: Vec<Foo> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, (0..100).map(Foo::new).collect::<Vec<_>>());
+
+    let a: Arc<dyn Array> = vec![Foo::new(1)].try_into_arrow_owned().unwrap();
+    assert_eq!(a.data_type(), &<Foo as ArrowField>::data_type());
+}
+
+#[test]
+fn test_mutable_array_into_box() {
+    use arrow2::array::TryPush;
+    use arrow2_convert::deserialize::TryIntoCollection;
+    use arrow2_convert::{ArrowDeserialize, ArrowSerialize};
+
+    #[derive(Debug, PartialEq, arrow2_convert::ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(Debug, PartialEq, arrow2_convert::ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(type = "dense")]
+    enum Shape {
+        Point(Point),
+        Empty,
+    }
+
+    let mut mutable = MutablePointArray::new();
+    mutable.try_push(Some(Point { x: 1, y: 2 })).unwrap();
+    let a: Box<dyn Array> = mutable.into();
+    let round_trip: Vec<Point> = a.try_into_collection().unwrap();
+    assert_eq!(round_trip, vec![Point { x: 1, y: 2 }]);
+
+    let mut mutable = MutableShapeArray::default();
+    mutable.try_push(Some(Shape::Empty)).unwrap();
+    let a: Box<dyn Array> = mutable.into();
+    let round_trip: Vec<Shape> = a.try_into_collection().unwrap();
+    assert_eq!(round_trip, vec![Shape::Empty]);
+}
+
+#[test]
+fn test_try_into_arrow_via() {
+    use arrow2_convert::deserialize::TryIntoCollection;
+    use arrow2_convert::{ArrowDeserialize, ArrowSerialize};
+
+    #[derive(Debug, PartialEq, arrow2_convert::ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Shape {
+        name: String,
+        area: f64,
+    }
+
+    trait AsShape {
+        fn to_shape(&self) -> Shape;
+    }
+
+    struct Square(f64);
+    struct Circle(f64);
+
+    impl AsShape for Square {
+        fn to_shape(&self) -> Shape {
+            Shape {
+                name: "square".to_string(),
+                area: self.0 * self.0,
+            }
+        }
+    }
+
+    impl AsShape for Circle {
+        fn to_shape(&self) -> Shape {
+            Shape {
+                name: "circle".to_string(),
+                area: 3.0 * self.0 * self.0,
+            }
+        }
+    }
+
+    let shapes: Vec<Box<dyn AsShape>> = vec![Box::new(Square(2.0)), Box::new(Circle(1.0))];
+    let b = try_into_arrow_via(shapes.iter().map(|s| s.as_ref()), |s| s.to_shape()).unwrap();
+    assert_eq!(b.data_type(), &<Shape as ArrowField>::data_type());
+
+    let round_trip: Vec<Shape> = b.try_into_collection().unwrap();
+    assert_eq!(
+        round_trip,
+        vec![
+            Shape {
+                name: "square".to_string(),
+                area: 4.0
+            },
+            Shape {
+                name: "circle".to_string(),
+                area: 3.0
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_top_level_optional_struct() {
+    use arrow2::array::StructArray;
+    use arrow2_convert::deserialize::TryIntoCollection;
+    use arrow2_convert::{ArrowDeserialize, ArrowSerialize};
+
+    #[derive(
+        Debug, Clone, PartialEq, arrow2_convert::ArrowField, ArrowSerialize, ArrowDeserialize,
+    )]
+    struct Row {
+        a: i32,
+    }
+
+    let rows = vec![Some(Row { a: 1 }), None, Some(Row { a: 3 })];
+    let b: Box<dyn Array> = rows.try_into_arrow().unwrap();
+    assert_eq!(b.data_type(), &<Row as ArrowField>::data_type());
+
+    let struct_array = b.as_any().downcast_ref::<StructArray>().unwrap();
+    assert_eq!(
+        struct_array.validity().unwrap().iter().collect::<Vec<_>>(),
+        vec![true, false, true]
+    );
+    assert_eq!(struct_array.null_count(), 1);
+
+    let round_trip: Vec<Option<Row>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, rows);
+}
+
+#[test]
+fn test_str_slices() {
+    use arrow2::array::{ListArray, Utf8Array};
+
+    let strs: Vec<&str> = vec!["a", "bc", ""];
+    let b: Box<dyn Array> = strs.try_into_arrow().unwrap();
+    assert_eq!(
+        b.as_ref(),
+        &Utf8Array::<i32>::from_slice(["a", "bc", ""]) as &dyn Array
+    );
+
+    let lists: Vec<Vec<&str>> = vec![vec!["a", "b"], vec![], vec!["c"]];
+    let b: Box<dyn Array> = lists.try_into_arrow().unwrap();
+    assert_eq!(b.data_type(), &<Vec<String> as ArrowField>::data_type());
+    let list = b.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+    assert_eq!(list.offsets().as_slice(), &[0, 2, 2, 3]);
+    assert_eq!(
+        list.values().as_ref(),
+        &Utf8Array::<i32>::from_slice(["a", "b", "c"]) as &dyn Array
+    );
+}
+
+#[test]
+fn test_try_into_chunk() {
+    use arrow2_convert::deserialize::TryIntoCollection;
+    use arrow2_convert::try_into_chunk;
+    use arrow2_convert::{ArrowDeserialize, ArrowSerialize};
+
+    #[derive(
+        Debug, Clone, PartialEq, arrow2_convert::ArrowField, ArrowSerialize, ArrowDeserialize,
+    )]
+    struct A {
+        a: i32,
+    }
+
+    #[derive(
+        Debug, Clone, PartialEq, arrow2_convert::ArrowField, ArrowSerialize, ArrowDeserialize,
+    )]
+    struct B {
+        b: String,
+    }
+
+    let a = vec![A { a: 1 }, A { a: 2 }];
+    let b = vec![B { b: "x".to_string() }, B { b: "y".to_string() }];
+    let chunk = try_into_chunk!(&a, &b).unwrap();
+    assert_eq!(chunk.len(), 2);
+    assert_eq!(chunk.arrays().len(), 2);
+    assert_eq!(
+        chunk.arrays()[0].data_type(),
+        &<A as ArrowField>::data_type()
+    );
+    assert_eq!(
+        chunk.arrays()[1].data_type(),
+        &<B as ArrowField>::data_type()
+    );
+
+    let round_trip: Vec<B> = chunk.arrays()[1].as_ref().try_into_collection().unwrap();
+    assert_eq!(round_trip, b);
+
+    // the columns must have the same length
+    assert!(try_into_chunk!(&a, &b[..1]).is_err());
+}
+
+#[test]
+fn test_top_level_optional_primitive() {
+    use arrow2::array::Int32Array;
+
+    // options are pushed directly into the primitive array of the inner type, with a validity bitmap
+    let values = vec![Some(1_i32), None, Some(3), None];
+    let b: Box<dyn Array> = values.try_into_arrow().unwrap();
+    assert_eq!(b.data_type(), &DataType::Int32);
+    let array = b.as_any().downcast_ref::<Int32Array>().unwrap();
+    assert_eq!(array.null_count(), 2);
+    assert_eq!(array, &Int32Array::from(values));
+}
@@ -0,0 +1,67 @@
+use arrow2::array::*;
+use arrow2::datatypes::*;
+use arrow2_convert::{
+    deserialize::TryIntoCollection, serialize::TryIntoArrow, ArrowDeserialize, ArrowField,
+    ArrowSerialize,
+};
+use std::collections::{BTreeSet, HashSet};
+
+#[test]
+fn test_set_schema() {
+    let list = DataType::List(Box::new(Field::new("item", DataType::Utf8, false)));
+    assert_eq!(
+        <BTreeSet<String> as arrow2_convert::field::ArrowField>::data_type(),
+        list
+    );
+    assert_eq!(
+        <HashSet<String> as arrow2_convert::field::ArrowField>::data_type(),
+        list
+    );
+}
+
+#[test]
+fn test_btree_set() {
+    let original = vec![BTreeSet::from([3, 1, 2]), BTreeSet::new()];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+
+    // the values are stored in sorted order
+    let lists = b.as_any().downcast_ref::<ListArray<i32>>().unwrap();
+    assert_eq!(
+        lists
+            .value(0)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap(),
+        &Int32Array::from_slice([1, 2, 3])
+    );
+
+    let round_trip: Vec<BTreeSet<i32>> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
+
+#[test]
+fn test_hash_set() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Tagged {
+        tags: HashSet<String>,
+        ids: Vec<BTreeSet<u32>>,
+    }
+
+    let original = vec![Tagged {
+        tags: HashSet::from(["a".to_string(), "b".to_string()]),
+        ids: vec![BTreeSet::from([2, 1])],
+    }];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Tagged> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
+
+#[test]
+fn test_set_deduplicates_on_read() {
+    let lists = vec![vec![1, 2, 1, 3, 2]];
+    let b: Box<dyn Array> = lists.try_into_arrow().unwrap();
+    let sets: Vec<BTreeSet<i32>> = b.as_ref().try_into_collection().unwrap();
+    assert_eq!(sets, vec![BTreeSet::from([1, 2, 3])]);
+    let sets: Vec<HashSet<i32>> = b.try_into_collection().unwrap();
+    assert_eq!(sets, vec![HashSet::from([1, 2, 3])]);
+}
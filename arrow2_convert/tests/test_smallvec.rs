@@ -0,0 +1,47 @@
+#![cfg(feature = "smallvec")]
+
+use arrow2::array::*;
+use arrow2::datatypes::*;
+use arrow2_convert::deserialize::TryIntoCollection;
+use arrow2_convert::serialize::TryIntoArrow;
+use arrow2_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+use smallvec::{smallvec, SmallVec};
+
+#[test]
+fn test_smallvec_round_trip() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Row {
+        ids: SmallVec<[i32; 2]>,
+        names: Option<SmallVec<[String; 1]>>,
+    }
+
+    let rows = vec![
+        Row {
+            // longer than the inline capacity
+            ids: smallvec![1, 2, 3],
+            names: Some(smallvec!["a".to_string()]),
+        },
+        Row {
+            ids: SmallVec::new(),
+            names: None,
+        },
+    ];
+    let b: Box<dyn Array> = rows.try_into_arrow().unwrap();
+    assert_eq!(
+        b.data_type(),
+        &DataType::Struct(vec![
+            Field::new(
+                "ids",
+                DataType::List(Box::new(Field::new("item", DataType::Int32, false))),
+                false
+            ),
+            Field::new(
+                "names",
+                DataType::List(Box::new(Field::new("item", DataType::Utf8, false))),
+                true
+            ),
+        ])
+    );
+    let round_trip: Vec<Row> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, rows);
+}
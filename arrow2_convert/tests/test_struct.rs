@@ -1,4 +1,5 @@
 use arrow2::array::*;
+use arrow2::datatypes::{DataType, Field};
 use arrow2_convert::{deserialize::*, serialize::*, ArrowDeserialize, ArrowField, ArrowSerialize};
 
 #[test]
@@ -90,3 +91,542 @@ fn test_nested_slice() {
         assert_eq!(round_trip, original_slice);
     }
 }
+
+#[test]
+fn test_nested_null_middle_struct() {
+    #[derive(Debug, Clone, ArrowField, ArrowSerialize, ArrowDeserialize, PartialEq)]
+    struct Top {
+        id: i32,
+        mid: Option<Mid>,
+    }
+    #[derive(Debug, Clone, ArrowField, ArrowSerialize, ArrowDeserialize, PartialEq)]
+    struct Mid {
+        a1: i64,
+        inner: Inner,
+        inner_list: Vec<Inner>,
+    }
+    #[derive(Debug, Clone, ArrowField, ArrowSerialize, ArrowDeserialize, PartialEq)]
+    struct Inner {
+        b1: String,
+        b2: Option<i32>,
+    }
+
+    let mid = |i: i64| Mid {
+        a1: i,
+        inner: Inner {
+            b1: format!("inner {i}"),
+            b2: Some(i as i32),
+        },
+        inner_list: vec![
+            Inner {
+                b1: format!("list {i}"),
+                b2: None,
+            };
+            i as usize
+        ],
+    };
+
+    let original = vec![
+        Top {
+            id: 1,
+            mid: Some(mid(1)),
+        },
+        Top { id: 2, mid: None },
+        Top { id: 3, mid: None },
+        Top {
+            id: 4,
+            mid: Some(mid(4)),
+        },
+        Top { id: 5, mid: None },
+        Top {
+            id: 6,
+            mid: Some(mid(6)),
+        },
+    ];
+
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Top> = b.as_ref().try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    for i in 0..original.len() {
+        let arrow_slice = b.sliced(i, original.len() - i);
+        let round_trip: Vec<Top> = arrow_slice.try_into_collection().unwrap();
+        assert_eq!(round_trip, &original[i..]);
+    }
+}
+
+#[test]
+fn test_struct_all_but_one_field_skipped() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Test {
+        #[arrow_field(skip)]
+        a: String,
+        b: i64,
+        #[arrow_field(skip)]
+        c: Option<bool>,
+    }
+
+    assert_eq!(
+        <Test as arrow2_convert::field::ArrowField>::data_type(),
+        DataType::Struct(vec![Field::new("b", DataType::Int64, false)])
+    );
+
+    let original = vec![
+        Test {
+            a: "a".to_string(),
+            b: 1,
+            c: Some(true),
+        },
+        Test {
+            a: "b".to_string(),
+            b: 2,
+            c: None,
+        },
+    ];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    let struct_array = b.as_any().downcast_ref::<StructArray>().unwrap();
+    assert_eq!(struct_array.values().len(), 1);
+    assert_eq!(
+        struct_array.values()[0].as_ref(),
+        &Int64Array::from_slice([1, 2]) as &dyn Array
+    );
+
+    let round_trip: Vec<Test> = b.try_into_collection().unwrap();
+    assert_eq!(
+        round_trip,
+        vec![
+            Test {
+                a: String::default(),
+                b: 1,
+                c: None,
+            },
+            Test {
+                a: String::default(),
+                b: 2,
+                c: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_struct_nullable_override_deserializes_null_to_default() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Test {
+        #[arrow_field(nullable = true)]
+        a: String,
+        b: i64,
+    }
+
+    // an external array with a null in the non-optional field
+    let array = StructArray::new(
+        <Test as arrow2_convert::field::ArrowField>::data_type(),
+        vec![
+            Utf8Array::<i32>::from([Some("x"), None]).boxed(),
+            Int64Array::from_slice([1, 2]).boxed(),
+        ],
+        None,
+    );
+    let round_trip: Vec<Test> = array.boxed().try_into_collection().unwrap();
+    assert_eq!(
+        round_trip,
+        vec![
+            Test {
+                a: "x".to_string(),
+                b: 1
+            },
+            Test {
+                a: String::default(),
+                b: 2
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_serialize_or_deserialize_only() {
+    // only the derived traits are generated, so a struct can be write-only or read-only
+    #[derive(Debug, ArrowField, ArrowSerialize)]
+    struct WriteOnly {
+        a: i64,
+        b: String,
+    }
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowDeserialize)]
+    struct ReadOnly {
+        a: i64,
+        b: String,
+    }
+
+    let b: Box<dyn Array> = vec![WriteOnly {
+        a: 1,
+        b: "one".to_string(),
+    }]
+    .try_into_arrow()
+    .unwrap();
+    let round_trip: Vec<ReadOnly> = b.try_into_collection().unwrap();
+    assert_eq!(
+        round_trip,
+        vec![ReadOnly {
+            a: 1,
+            b: "one".to_string()
+        }]
+    );
+}
+
+#[test]
+fn test_transparent_with_phantom_data() {
+    use std::marker::PhantomData;
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Inner {
+        a: i32,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Meter;
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(transparent)]
+    struct Wrapper {
+        inner: Inner,
+        _unit: PhantomData<Meter>,
+    }
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(transparent)]
+    struct Tagged(PhantomData<Meter>, f64, #[arrow_field(skip)] Option<String>);
+
+    // no struct layer is added for the wrapper
+    assert_eq!(
+        <Wrapper as arrow2_convert::field::ArrowField>::data_type(),
+        <Inner as arrow2_convert::field::ArrowField>::data_type()
+    );
+    assert_eq!(
+        <Tagged as arrow2_convert::field::ArrowField>::data_type(),
+        DataType::Float64
+    );
+
+    let original = vec![Wrapper {
+        inner: Inner { a: 1 },
+        _unit: PhantomData,
+    }];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Wrapper> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+
+    let original = vec![Tagged(PhantomData, 1.5, None)];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    assert_eq!(b.data_type(), &DataType::Float64);
+    let round_trip: Vec<Tagged> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
+
+#[test]
+fn test_transparent_generic() {
+    use std::marker::PhantomData;
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(transparent)]
+    struct Meters(f64);
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(transparent)]
+    struct Wrapper<T>(T);
+
+    // a typed id, where the type parameter only appears in a `PhantomData`
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(transparent)]
+    struct Id<T> {
+        value: u64,
+        _marker: PhantomData<T>,
+    }
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Row {
+        distance: Meters,
+        count: Wrapper<i32>,
+        names: Wrapper<Vec<String>>,
+        nested: Option<Wrapper<Wrapper<Meters>>>,
+        id: Id<Meters>,
+    }
+
+    assert_eq!(
+        <Wrapper<i32> as arrow2_convert::field::ArrowField>::data_type(),
+        DataType::Int32
+    );
+    assert_eq!(
+        <Id<Meters> as arrow2_convert::field::ArrowField>::data_type(),
+        DataType::UInt64
+    );
+
+    let original = vec![Wrapper(1_i32), Wrapper(-5)];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    assert_eq!(b.data_type(), &DataType::Int32);
+    let round_trip: Vec<Wrapper<i32>> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+
+    let original = vec![
+        Row {
+            distance: Meters(1.5),
+            count: Wrapper(3),
+            names: Wrapper(vec!["a".to_string()]),
+            nested: Some(Wrapper(Wrapper(Meters(2.0)))),
+            id: Id {
+                value: 7,
+                _marker: PhantomData,
+            },
+        },
+        Row {
+            distance: Meters(0.0),
+            count: Wrapper(0),
+            names: Wrapper(vec![]),
+            nested: None,
+            id: Id {
+                value: 8,
+                _marker: PhantomData,
+            },
+        },
+    ];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Row> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
+
+#[test]
+fn test_phantom_data_field() {
+    use std::marker::PhantomData;
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Marked {
+        a: i32,
+        _marker: PhantomData<u8>,
+        b: String,
+    }
+
+    // the marker isn't part of the schema
+    assert_eq!(
+        <Marked as arrow2_convert::field::ArrowField>::data_type(),
+        DataType::Struct(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, false),
+        ])
+    );
+
+    let original = vec![Marked {
+        a: 1,
+        _marker: PhantomData,
+        b: "one".to_string(),
+    }];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Marked> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
+
+#[test]
+fn test_null_struct_rows() {
+    use arrow2::bitmap::Bitmap;
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct MyStruct {
+        a: i64,
+        b: String,
+    }
+
+    // an external array where the second row is null at the struct level only
+    let array = StructArray::new(
+        <MyStruct as arrow2_convert::field::ArrowField>::data_type(),
+        vec![
+            Int64Array::from_slice([1, 2, 3]).boxed(),
+            Utf8Array::<i32>::from_slice(["x", "y", "z"]).boxed(),
+        ],
+        Some(Bitmap::from([true, false, true])),
+    )
+    .boxed();
+    let expected = vec![
+        Some(MyStruct {
+            a: 1,
+            b: "x".to_string(),
+        }),
+        None,
+        Some(MyStruct {
+            a: 3,
+            b: "z".to_string(),
+        }),
+    ];
+
+    let round_trip = arrow_array_deserialize_iterator::<Option<MyStruct>>(array.as_ref())
+        .unwrap()
+        .collect::<Vec<_>>();
+    assert_eq!(round_trip, expected);
+
+    let round_trip: Vec<Option<MyStruct>> = array.sliced(1, 2).try_into_collection().unwrap();
+    assert_eq!(round_trip, expected[1..]);
+}
+
+#[test]
+fn test_flatten() {
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Address {
+        street: String,
+        zip: Option<i32>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Outer {
+        id: i64,
+        #[arrow_field(flatten)]
+        address: Address,
+        name: String,
+    }
+
+    assert_eq!(
+        <Outer as arrow2_convert::field::ArrowField>::data_type(),
+        DataType::Struct(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("street", DataType::Utf8, false),
+            Field::new("zip", DataType::Int32, true),
+            Field::new("name", DataType::Utf8, false),
+        ])
+    );
+
+    let original = vec![
+        Outer {
+            id: 1,
+            address: Address {
+                street: "Main".to_string(),
+                zip: Some(123),
+            },
+            name: "a".to_string(),
+        },
+        Outer {
+            id: 2,
+            address: Address {
+                street: "High".to_string(),
+                zip: None,
+            },
+            name: "b".to_string(),
+        },
+    ];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    let struct_array = b.as_any().downcast_ref::<StructArray>().unwrap();
+    assert_eq!(struct_array.values().len(), 4);
+    assert_eq!(
+        struct_array.values()[2].as_ref(),
+        &Int32Array::from([Some(123), None]) as &dyn Array
+    );
+    let round_trip: Vec<Outer> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    // the columns of a null row are skipped, including the flattened ones
+    let original = vec![None, Some(original[0].clone())];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Option<Outer>> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    // a flattened struct can itself contain a flattened struct, whose columns are inlined at every level
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Top {
+        #[arrow_field(flatten)]
+        outer: Outer,
+        rank: u8,
+    }
+
+    let original = vec![Top {
+        outer: original[1].clone().unwrap(),
+        rank: 7,
+    }];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    let struct_array = b.as_any().downcast_ref::<StructArray>().unwrap();
+    assert_eq!(struct_array.values().len(), 5);
+    let round_trip: Vec<Top> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_flatten_name_collision() {
+    #[derive(Debug, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Inner {
+        id: i64,
+    }
+
+    #[derive(Debug, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Outer {
+        id: i64,
+        #[arrow_field(flatten)]
+        inner: Inner,
+    }
+
+    // building the data type doesn't panic, the collision is reported by the fallible entry points
+    let data_type = <Outer as arrow2_convert::field::ArrowField>::data_type();
+
+    let original = vec![Outer {
+        id: 1,
+        inner: Inner { id: 2 },
+    }];
+    let err =
+        TryIntoArrow::<Box<dyn Array>, Outer>::try_into_arrow(original.as_slice()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Invalid argument: Duplicate arrow field name `id` after flattening"
+    );
+
+    let id = Int64Array::from_slice([1]).boxed();
+    let array = StructArray::new(data_type, vec![id.clone(), id], None).boxed();
+    let result: arrow2_convert::error::Result<Vec<Outer>> = array.try_into_collection();
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Invalid argument: Duplicate arrow field name `id` after flattening"
+    );
+}
+
+#[test]
+fn test_single_field_struct_vs_transparent() {
+    // a single-field struct is a `Struct` with one field unless it is transparent
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Wrapper {
+        value: i64,
+    }
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Tuple(i64);
+
+    #[derive(Debug, Clone, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    #[arrow_field(transparent)]
+    struct Id(i64);
+
+    assert_eq!(
+        <Wrapper as arrow2_convert::field::ArrowField>::data_type(),
+        DataType::Struct(vec![Field::new("value", DataType::Int64, false)])
+    );
+    assert_eq!(
+        <Tuple as arrow2_convert::field::ArrowField>::data_type(),
+        DataType::Struct(vec![Field::new("field_0", DataType::Int64, false)])
+    );
+    assert_eq!(
+        <Id as arrow2_convert::field::ArrowField>::data_type(),
+        DataType::Int64
+    );
+
+    let original = vec![Wrapper { value: 1 }, Wrapper { value: 2 }];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    assert!(b.as_any().downcast_ref::<StructArray>().is_some());
+    let round_trip: Vec<Wrapper> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    let original = vec![Tuple(1), Tuple(2)];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    assert!(b.as_any().downcast_ref::<StructArray>().is_some());
+    let round_trip: Vec<Tuple> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    let original = vec![Id(1), Id(2)];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    assert_eq!(b.as_ref(), &Int64Array::from_slice([1, 2]) as &dyn Array);
+    let round_trip: Vec<Id> = b.clone().try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+
+    // the two layouts aren't interchangeable
+    let round_trip: arrow2_convert::error::Result<Vec<Wrapper>> = b.try_into_collection();
+    assert!(round_trip.is_err());
+}
@@ -0,0 +1,260 @@
+use arrow2::array::*;
+use arrow2::datatypes::*;
+use arrow2_convert::deserialize::{arrow_array_deserialize_iterator_as_type, TryIntoCollection};
+use arrow2_convert::field::{Microsecond, Millisecond, Nanosecond, Second, Timestamp};
+use arrow2_convert::serialize::TryIntoArrow;
+use arrow2_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+use chrono::{NaiveDate, NaiveDateTime};
+
+fn datetime(secs: i64, nanos: u32) -> NaiveDateTime {
+    NaiveDateTime::from_timestamp_opt(secs, nanos).unwrap()
+}
+
+#[test]
+fn test_timestamp_units() {
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Event {
+        #[arrow_field(type = "Timestamp<Second>")]
+        s: NaiveDateTime,
+        #[arrow_field(type = "Timestamp<Millisecond>")]
+        ms: NaiveDateTime,
+        #[arrow_field(type = "Timestamp<Microsecond>")]
+        us: NaiveDateTime,
+        #[arrow_field(type = "Option<Timestamp<Nanosecond>>")]
+        ns: Option<NaiveDateTime>,
+    }
+
+    assert_eq!(
+        <Event as arrow2_convert::field::ArrowField>::data_type(),
+        DataType::Struct(vec![
+            Field::new("s", DataType::Timestamp(TimeUnit::Second, None), false),
+            Field::new(
+                "ms",
+                DataType::Timestamp(TimeUnit::Millisecond, None),
+                false
+            ),
+            Field::new(
+                "us",
+                DataType::Timestamp(TimeUnit::Microsecond, None),
+                false
+            ),
+            Field::new("ns", DataType::Timestamp(TimeUnit::Nanosecond, None), true),
+        ])
+    );
+
+    let original = vec![
+        Event {
+            s: datetime(1_600_000_000, 0),
+            ms: datetime(1_600_000_000, 123_000_000),
+            us: datetime(-1, 123_456_000),
+            ns: Some(datetime(1_600_000_000, 123_456_789)),
+        },
+        Event {
+            s: datetime(0, 0),
+            ms: datetime(0, 0),
+            us: datetime(0, 0),
+            ns: None,
+        },
+    ];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Event> = b.try_into_collection().unwrap();
+    assert_eq!(original, round_trip);
+}
+
+#[test]
+fn test_timestamp_from_external_array() {
+    // arrays written by other tools, in each time unit
+    let value = datetime(1_600_000_000, 123_456_789);
+
+    let arr =
+        Int64Array::from_slice([1_600_000_000]).to(DataType::Timestamp(TimeUnit::Second, None));
+    let v: Vec<NaiveDateTime> =
+        arrow_array_deserialize_iterator_as_type::<_, Timestamp<Second>>(&arr)
+            .unwrap()
+            .collect();
+    assert_eq!(v, vec![datetime(1_600_000_000, 0)]);
+
+    let arr = Int64Array::from_slice([1_600_000_000_123])
+        .to(DataType::Timestamp(TimeUnit::Millisecond, None));
+    let v: Vec<NaiveDateTime> =
+        arrow_array_deserialize_iterator_as_type::<_, Timestamp<Millisecond>>(&arr)
+            .unwrap()
+            .collect();
+    assert_eq!(v, vec![datetime(1_600_000_000, 123_000_000)]);
+
+    let arr = Int64Array::from_slice([1_600_000_000_123_456])
+        .to(DataType::Timestamp(TimeUnit::Microsecond, None));
+    let v: Vec<NaiveDateTime> =
+        arrow_array_deserialize_iterator_as_type::<_, Timestamp<Microsecond>>(&arr)
+            .unwrap()
+            .collect();
+    assert_eq!(v, vec![datetime(1_600_000_000, 123_456_000)]);
+
+    let arr = Int64Array::from_slice([value.timestamp_nanos()])
+        .to(DataType::Timestamp(TimeUnit::Nanosecond, None));
+    let v: Vec<NaiveDateTime> =
+        arrow_array_deserialize_iterator_as_type::<_, Timestamp<Nanosecond>>(&arr)
+            .unwrap()
+            .collect();
+    assert_eq!(v, vec![value]);
+
+    // the unit must match the array
+    let arr = Int64Array::from_slice([1_600_000_000_123_456])
+        .to(DataType::Timestamp(TimeUnit::Microsecond, None));
+    assert!(arrow_array_deserialize_iterator_as_type::<_, Timestamp<Millisecond>>(&arr).is_err());
+    assert!(arrow_array_deserialize_iterator_as_type::<_, NaiveDateTime>(&arr).is_err());
+}
+
+#[test]
+fn test_timestamp_truncates_precision() {
+    let original = [datetime(10, 123_456_789)];
+    let b = arrow2_convert::serialize::arrow_serialize_to_mutable_array::<
+        _,
+        Timestamp<Millisecond>,
+        _,
+    >(&original)
+    .unwrap()
+    .as_box();
+    assert_eq!(
+        b.data_type(),
+        &DataType::Timestamp(TimeUnit::Millisecond, None)
+    );
+    let v: Vec<NaiveDateTime> =
+        arrow_array_deserialize_iterator_as_type::<_, Timestamp<Millisecond>>(b.as_ref())
+            .unwrap()
+            .collect();
+    assert_eq!(v, vec![datetime(10, 123_000_000)]);
+}
+
+#[test]
+fn test_date64() {
+    use arrow2_convert::field::Date64;
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Event {
+        #[arrow_field(type = "arrow2_convert::field::Date64")]
+        day: NaiveDate,
+        #[arrow_field(type = "Option<Date64>")]
+        end: Option<NaiveDate>,
+    }
+
+    let days = [
+        NaiveDate::from_ymd_opt(1970, 1, 2).unwrap(),
+        NaiveDate::from_ymd_opt(1969, 12, 31).unwrap(),
+    ];
+    let b: Box<dyn Array> = days.try_into_arrow_as_type::<Date64>().unwrap();
+    assert_eq!(b.data_type(), &DataType::Date64);
+    let values = b.as_any().downcast_ref::<Int64Array>().unwrap();
+    assert_eq!(values.values().as_slice(), &[86_400_000, -86_400_000]);
+    let round_trip: Vec<NaiveDate> = b.try_into_collection_as_type::<Date64>().unwrap();
+    assert_eq!(round_trip, days);
+
+    let original = vec![Event {
+        day: days[0],
+        end: None,
+    }];
+    let b: Box<dyn Array> = original.try_into_arrow().unwrap();
+    let round_trip: Vec<Event> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, original);
+}
+
+#[test]
+fn test_timestamp_with_time_zone_lenient() {
+    let value = datetime(1_600_000_000, 123_456_789);
+    let b = Int64Array::from([Some(value.timestamp_nanos()), None])
+        .to(DataType::Timestamp(
+            TimeUnit::Nanosecond,
+            Some("+02:00".to_string()),
+        ))
+        .boxed();
+
+    let strict: arrow2_convert::error::Result<Vec<Option<NaiveDateTime>>> =
+        b.clone().try_into_collection();
+    assert!(strict.is_err());
+
+    // the time zone is ignored and the instant is read as is
+    let v: Vec<Option<NaiveDateTime>> = b.try_into_collection_lenient().unwrap();
+    assert_eq!(v, vec![Some(value), None]);
+
+    // the unit must still match
+    let b = Int64Array::from_slice([1_600_000_000])
+        .to(DataType::Timestamp(
+            TimeUnit::Second,
+            Some("UTC".to_string()),
+        ))
+        .boxed();
+    let v: arrow2_convert::error::Result<Vec<NaiveDateTime>> = b.try_into_collection_lenient();
+    assert!(v.is_err());
+}
+
+#[test]
+fn test_date64_into_naive_date_lenient() {
+    let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    let day_ms = 86_400_000;
+    let b = Int64Array::from([Some(0), None, Some(19_000 * day_ms), Some(-day_ms)])
+        .to(DataType::Date64)
+        .boxed();
+
+    // only the `Date64` placeholder reads `Date64` arrays without the lenient conversion
+    let strict: arrow2_convert::error::Result<Vec<Option<NaiveDate>>> =
+        b.as_ref().try_into_collection();
+    assert!(strict.is_err());
+
+    let v: Vec<Option<NaiveDate>> = b.try_into_collection_lenient().unwrap();
+    assert_eq!(
+        v,
+        vec![
+            Some(date(1970, 1, 1)),
+            None,
+            Some(date(2022, 1, 8)),
+            Some(date(1969, 12, 31))
+        ]
+    );
+
+    // a time of day can't be represented in a `NaiveDate`
+    let b = Int64Array::from([Some(0), Some(day_ms + 1)])
+        .to(DataType::Date64)
+        .boxed();
+    let v: arrow2_convert::error::Result<Vec<NaiveDate>> = b.try_into_collection_lenient();
+    assert!(v.is_err());
+}
+
+#[test]
+fn test_chrono_duration() {
+    use chrono::Duration;
+
+    #[derive(Debug, PartialEq, ArrowField, ArrowSerialize, ArrowDeserialize)]
+    struct Lap {
+        delta: Duration,
+        penalty: Option<Duration>,
+    }
+
+    let laps = vec![
+        Lap {
+            delta: Duration::milliseconds(1500),
+            penalty: Some(Duration::seconds(-5)),
+        },
+        Lap {
+            delta: Duration::nanoseconds(-1),
+            penalty: None,
+        },
+    ];
+    let b: Box<dyn Array> = laps.try_into_arrow().unwrap();
+    let struct_array = b.as_any().downcast_ref::<StructArray>().unwrap();
+    assert_eq!(
+        struct_array.fields()[0].data_type,
+        DataType::Duration(TimeUnit::Nanosecond)
+    );
+    let deltas = struct_array.values()[0]
+        .as_any()
+        .downcast_ref::<Int64Array>()
+        .unwrap();
+    assert_eq!(deltas.values().as_slice(), &[1_500_000_000, -1]);
+    let round_trip: Vec<Lap> = b.try_into_collection().unwrap();
+    assert_eq!(round_trip, laps);
+
+    // durations that don't fit in an i64 of nanoseconds can't be serialized
+    let too_long = vec![Duration::days(365 * 300)];
+    let result: arrow2_convert::error::Result<Box<dyn Array>> = too_long.try_into_arrow();
+    assert!(result.is_err());
+}
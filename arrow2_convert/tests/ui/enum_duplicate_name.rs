@@ -0,0 +1,10 @@
+use arrow2_convert::ArrowField;
+
+#[derive(Debug, ArrowField)]
+enum Test {
+    A(i32),
+    #[arrow_field(rename = "A")]
+    B(i32),
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use arrow2_convert::ArrowField;
+
+#[derive(Debug, ArrowField)]
+#[arrow_field(type = "dense")]
+enum Test {
+    #[arrow_field(type_id = 1)]
+    A(i32),
+    B(i64),
+}
+
+fn main() {}
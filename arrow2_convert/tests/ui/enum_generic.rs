@@ -0,0 +1,9 @@
+use arrow2_convert::{ArrowField, ArrowSerialize, ArrowDeserialize};
+
+#[derive(Debug, ArrowField, ArrowSerialize, ArrowDeserialize)]
+enum Test<T> {
+    A(T),
+    B(i32),
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use arrow2_convert::{ArrowField, ArrowSerialize, ArrowDeserialize};
+
+#[derive(Debug, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[arrow_field(type = "i8")]
+enum Test {
+    A,
+    B(i32),
+}
+
+fn main() {}
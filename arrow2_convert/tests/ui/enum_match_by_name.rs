@@ -0,0 +1,10 @@
+use arrow2_convert::{ArrowField, ArrowSerialize, ArrowDeserialize};
+
+#[derive(Debug, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[arrow_field(type = "dense", match_by_name)]
+enum Test {
+    A(i32),
+    B(String),
+}
+
+fn main() {}
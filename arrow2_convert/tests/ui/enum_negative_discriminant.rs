@@ -0,0 +1,11 @@
+use arrow2_convert::{ArrowField, ArrowSerialize, ArrowDeserialize};
+
+#[derive(Debug, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[arrow_field(type = "sparse")]
+#[repr(i32)]
+enum Test {
+    A = -1,
+    B = 2,
+}
+
+fn main() {}
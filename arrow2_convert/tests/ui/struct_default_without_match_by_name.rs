@@ -0,0 +1,10 @@
+use arrow2_convert::{ArrowField, ArrowSerialize, ArrowDeserialize};
+
+#[derive(Debug, ArrowField, ArrowSerialize, ArrowDeserialize)]
+struct Test {
+    a: i32,
+    #[arrow_field(default)]
+    b: Option<String>,
+}
+
+fn main() {}
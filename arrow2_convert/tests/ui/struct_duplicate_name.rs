@@ -0,0 +1,10 @@
+use arrow2_convert::ArrowField;
+
+#[derive(Debug, ArrowField)]
+struct Test {
+    a: i32,
+    #[arrow_field(rename = "a")]
+    b: i32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use arrow2_convert::{ArrowField, ArrowSerialize, ArrowDeserialize};
+
+#[derive(Debug, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[arrow_field(extension_metadata = "{}")]
+struct Test {
+    a: i32,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use arrow2_convert::ArrowField;
+
+#[derive(Debug, ArrowField)]
+struct Address {
+    city: String,
+}
+
+#[derive(Debug, ArrowField)]
+struct Outer {
+    id: i64,
+    #[arrow_field(flatten)]
+    address: Option<Address>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use arrow2_convert::{ArrowField, ArrowSerialize, ArrowDeserialize};
+
+#[derive(Debug, ArrowField, ArrowSerialize, ArrowDeserialize)]
+struct Test<T> {
+    a: T,
+    b: i32,
+}
+
+fn main() {}
@@ -1,4 +1,3 @@
-use arrow2_convert::field::LargeBinary;
 use arrow2_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
 
 #[derive(Debug, ArrowField, ArrowSerialize, ArrowDeserialize)]
@@ -0,0 +1,9 @@
+use arrow2_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+
+#[derive(Debug, ArrowField, ArrowSerialize, ArrowDeserialize)]
+struct Test {
+    #[arrow_field(type = "Option<I128<32, 2>>")]
+    s: Option<i64>,
+}
+
+fn main() {}
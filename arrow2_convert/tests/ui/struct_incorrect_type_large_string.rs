@@ -0,0 +1,9 @@
+use arrow2_convert::{ArrowDeserialize, ArrowField, ArrowSerialize};
+
+#[derive(Debug, ArrowField, ArrowSerialize, ArrowDeserialize)]
+struct Test {
+    #[arrow_field(type = "LargeString")]
+    s: Vec<u8>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use arrow2_convert::ArrowField;
+
+#[derive(Debug, ArrowField)]
+struct Test {
+    #[arrow_field(large_binary)]
+    a: Vec<String>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use arrow2_convert::{ArrowField, ArrowSerialize, ArrowDeserialize};
+
+#[derive(Debug, ArrowField, ArrowSerialize, ArrowDeserialize)]
+struct Test {
+    #[arrow_field(large_list)]
+    a: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use arrow2_convert::ArrowField;
+
+#[derive(Debug, ArrowField)]
+struct Test {
+    #[arrow_field(nullable = false)]
+    a: Option<i64>,
+}
+
+fn main() {}
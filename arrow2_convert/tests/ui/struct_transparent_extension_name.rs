@@ -0,0 +1,7 @@
+use arrow2_convert::{ArrowField, ArrowSerialize, ArrowDeserialize};
+
+#[derive(Debug, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[arrow_field(transparent, extension_name = "my.ext")]
+struct Test(i32);
+
+fn main() {}
@@ -0,0 +1,7 @@
+use arrow2_convert::{ArrowField, ArrowSerialize, ArrowDeserialize};
+
+#[derive(Debug, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[arrow_field(transparent)]
+struct Test(#[arrow_field(item_nullable = true)] Vec<i32>);
+
+fn main() {}
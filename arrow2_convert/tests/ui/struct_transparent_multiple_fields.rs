@@ -0,0 +1,10 @@
+use arrow2_convert::{ArrowField, ArrowSerialize, ArrowDeserialize};
+
+#[derive(Debug, ArrowField, ArrowSerialize, ArrowDeserialize)]
+#[arrow_field(transparent)]
+struct Test {
+    a: i32,
+    b: i32,
+}
+
+fn main() {}
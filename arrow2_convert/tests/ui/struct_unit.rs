@@ -0,0 +1,6 @@
+use arrow2_convert::ArrowField;
+
+#[derive(ArrowField)]
+struct S;
+
+fn main() {}
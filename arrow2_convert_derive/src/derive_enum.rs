@@ -14,6 +14,10 @@ struct Common<'a> {
     variant_names: Vec<proc_macro2::Ident>,
     variant_names_str: Vec<syn::LitStr>,
     variant_indices: Vec<syn::LitInt>,
+    /// The union type id of each variant, defaulting to the index of the variant
+    variant_type_ids: Vec<syn::LitInt>,
+    /// The explicit type ids of the union data type, if any variant overrides its type id
+    union_type_ids: TokenStream,
     variant_types: Vec<&'a syn::TypePath>,
 }
 
@@ -43,10 +47,24 @@ impl<'a> From<&'a DeriveEnum> for Common<'a> {
             );
         }
 
-        let variant_names_str = variant_names
+        // The arrow field names, taking `rename` into account
+        let variant_names_str = variants
             .iter()
-            .map(|v| syn::LitStr::new(&format!("{v}"), proc_macro2::Span::call_site()))
+            .map(|v| match &v.rename {
+                Some(rename) => rename.clone(),
+                None => syn::LitStr::new(&format!("{}", v.syn.ident), v.syn.ident.span()),
+            })
             .collect::<Vec<_>>();
+        for name in &variant_names_str {
+            if variant_names_str
+                .iter()
+                .filter(|n| n.value() == name.value())
+                .count()
+                > 1
+            {
+                abort!(name.span(), "Duplicate arrow field name `{}`", name.value());
+            }
+        }
 
         let variant_indices = variant_names
             .iter()
@@ -56,6 +74,24 @@ impl<'a> From<&'a DeriveEnum> for Common<'a> {
             })
             .collect::<Vec<_>>();
 
+        let variant_type_ids = variants
+            .iter()
+            .zip(&variant_indices)
+            .map(|(v, idx)| {
+                let id = v.type_id.as_ref().unwrap_or(idx);
+                syn::LitInt::new(&format!("{}i8", id.base10_digits()), id.span())
+            })
+            .collect::<Vec<_>>();
+
+        let union_type_ids = if variants.iter().any(|v| v.type_id.is_some()) {
+            let ids = variant_type_ids
+                .iter()
+                .map(|id| syn::LitInt::new(id.base10_digits(), id.span()));
+            quote!(Some(vec![#(#ids),*]))
+        } else {
+            quote!(None)
+        };
+
         let variant_types: Vec<&syn::TypePath> = variants
             .iter()
             .map(|v| match &v.field_type {
@@ -73,34 +109,48 @@ impl<'a> From<&'a DeriveEnum> for Common<'a> {
             variant_names,
             variant_names_str,
             variant_indices,
+            variant_type_ids,
+            union_type_ids,
             variant_types,
         }
     }
 }
 
 pub fn expand_field(input: DeriveEnum) -> TokenStream {
+    if input.is_index {
+        return expand_index_field(input);
+    }
+    if input.is_struct {
+        return expand_struct_field(input);
+    }
+
     let Common {
         original_name,
         union_type,
+        union_type_ids,
         variant_names_str,
         variant_types,
         ..
     } = (&input).into();
 
+    let data_type_impl = input.common.extension_data_type(quote! {
+        arrow2::datatypes::DataType::Union(
+            vec![
+                #(
+                    <#variant_types as arrow2_convert::field::ArrowField>::field(#variant_names_str),
+                )*
+            ],
+            #union_type_ids,
+            #union_type,
+        )
+    });
+
     quote! {
         impl arrow2_convert::field::ArrowField for #original_name {
             type Type = Self;
 
             fn data_type() -> arrow2::datatypes::DataType {
-                arrow2::datatypes::DataType::Union(
-                    vec![
-                        #(
-                            <#variant_types as arrow2_convert::field::ArrowField>::field(#variant_names_str),
-                        )*
-                    ],
-                    None,
-                    #union_type,
-                )
+                #data_type_impl
             }
         }
 
@@ -109,12 +159,19 @@ pub fn expand_field(input: DeriveEnum) -> TokenStream {
 }
 
 pub fn expand_serialize(input: DeriveEnum) -> TokenStream {
+    if input.is_index {
+        return expand_index_serialize(input);
+    }
+    if input.is_struct {
+        return expand_struct_serialize(input);
+    }
+
     let Common {
         original_name,
         visibility,
         variants,
         variant_names,
-        variant_indices,
+        variant_type_ids,
         variant_types,
         ..
     } = (&input).into();
@@ -127,25 +184,39 @@ pub fn expand_serialize(input: DeriveEnum) -> TokenStream {
         .map(|field_type| quote_spanned!( field_type.span() => <#field_type as arrow2_convert::serialize::ArrowSerialize>::MutableArrayType))
         .collect::<Vec<TokenStream>>();
 
-    let (offsets_decl, offsets_init, offsets_reserve, offsets_take, offsets_shrink_to_fit) =
-        if is_dense {
-            (
-                quote! { offsets: Vec<i32>, },
-                quote! { offsets: vec![], },
-                quote! { self.offsets.reserve(additional); },
-                quote! { Some(std::mem::take(&mut self.offsets).into()), },
-                quote! { self.offsets.shrink_to_fit(); },
-            )
-        } else {
-            (quote! {}, quote! {}, quote! {}, quote! {None}, quote! {})
-        };
+    let (
+        offsets_decl,
+        offsets_init,
+        offsets_reserve,
+        offsets_take,
+        offsets_shrink_to_fit,
+        offsets_clear,
+    ) = if is_dense {
+        (
+            quote! { offsets: Vec<i32>, },
+            quote! { offsets: vec![], },
+            quote! { self.offsets.reserve(additional); },
+            quote! { Some(std::mem::take(&mut self.offsets).into()), },
+            quote! { self.offsets.shrink_to_fit(); },
+            quote! { self.offsets.clear(); },
+        )
+    } else {
+        (
+            quote! {},
+            quote! {},
+            quote! {},
+            quote! {None},
+            quote! {},
+            quote! {},
+        )
+    };
 
     let try_push_match_blocks = variants
             .iter()
             .enumerate()
-            .zip(&variant_indices)
+            .zip(&variant_type_ids)
             .zip(&variant_types)
-            .map(|(((idx, v), lit_idx), variant_type)| {
+            .map(|(((idx, v), type_id), variant_type)| {
                 let name = &v.syn.ident;
                 // - For dense unions, update the mutable array of the matched variant and also the offset.
                 // - For sparse unions, update the mutable array of the matched variant, and push null for all
@@ -153,7 +224,7 @@ pub fn expand_serialize(input: DeriveEnum) -> TokenStream {
                 //   There might be a better way of doing this.
                 if is_dense {
                     let update_offset = quote! {
-                        self.types.push(#lit_idx);
+                        self.types.push(#type_id);
                         self.offsets.push((self.#name.len() - 1) as i32);
                     };
                     if v.is_unit {
@@ -192,7 +263,7 @@ pub fn expand_serialize(input: DeriveEnum) -> TokenStream {
                         .collect::<Vec<TokenStream>>();
 
                     let update_offset = quote! {
-                        self.types.push(#lit_idx);
+                        self.types.push(#type_id);
                     };
 
                     if v.is_unit {
@@ -224,77 +295,173 @@ pub fn expand_serialize(input: DeriveEnum) -> TokenStream {
     let try_push_none = if is_dense {
         let first_array_type = &mutable_variant_array_types[0];
         let first_name = &variant_names[0];
+        let first_type_id = &variant_type_ids[0];
         quote! {
-            self.types.push(0);
+            self.types.push(#first_type_id);
             self.offsets.push((self.#first_name.len()) as i32);
             <#first_array_type as MutableArray>::push_null(&mut self.#first_name);
         }
     } else {
+        let first_type_id = &variant_type_ids[0];
         quote! {
-            self.types.push(0);
+            self.types.push(#first_type_id);
             #(
                 <#mutable_variant_array_types as MutableArray>::push_null(&mut self.#variant_names);
             )*
         }
     };
 
-    let array_decl = quote! {
+    let try_push = quote! {
+        match item {
+            Some(i) => {
+                match i.borrow() {
+                    #(
+                        #try_push_match_blocks
+                    )*
+                }
+            },
+            None => {
+                #try_push_none
+            }
+        }
+    };
+
+    expand_mutable_array(MutableArrayParts {
+        original_name,
+        visibility,
+        mutable_array_name,
+        child_names: &variant_names,
+        child_types: &variant_types,
+        own_fields: quote! {
+            types: Vec<i8>,
+            #offsets_decl
+        },
+        own_init: quote! {
+            types: vec![],
+            #offsets_init
+        },
+        own_reset: quote! {
+            self.types.clear();
+            #offsets_clear
+        },
+        own_reserve: quote! {
+            self.types.reserve(additional);
+            #offsets_reserve
+        },
+        own_shrink_to_fit: quote! {
+            self.types.shrink_to_fit();
+            #offsets_shrink_to_fit
+        },
+        reset_doc: "Removes all rows while keeping the allocated type ids and offsets, so that the array can be reused \
+            for the next batch. The variant arrays are replaced by new, empty arrays.",
+        len: quote!(self.types.len()),
+        validity: quote!(None),
+        try_push,
+        into_array: quote! {
+            Box::new(arrow2::array::UnionArray::new(
+                <#original_name as arrow2_convert::field::ArrowField>::data_type(),
+                std::mem::take(&mut self.types).into(),
+                values,
+                #offsets_take
+            ))
+        },
+        methods: quote! {},
+    })
+}
+
+/// The parts of a generated mutable enum array that differ between layouts. The arrays of the variants are
+/// the children, and the layout adds its own buffers, such as the type ids of a union or the tags of a struct.
+struct MutableArrayParts<'a> {
+    original_name: &'a proc_macro2::Ident,
+    visibility: &'a syn::Visibility,
+    mutable_array_name: &'a syn::Ident,
+    child_names: &'a [proc_macro2::Ident],
+    child_types: &'a [&'a syn::TypePath],
+    own_fields: TokenStream,
+    own_init: TokenStream,
+    own_reset: TokenStream,
+    own_reserve: TokenStream,
+    own_shrink_to_fit: TokenStream,
+    reset_doc: &'a str,
+    len: TokenStream,
+    validity: TokenStream,
+    /// The body of `try_push`, pushing the `Option` in `item`
+    try_push: TokenStream,
+    /// Builds the array from the boxed children in `values`
+    into_array: TokenStream,
+    methods: TokenStream,
+}
+
+/// Generates the mutable array of an enum and its `ArrowSerialize` implementation
+fn expand_mutable_array(parts: MutableArrayParts) -> TokenStream {
+    let MutableArrayParts {
+        original_name,
+        visibility,
+        mutable_array_name,
+        child_names,
+        child_types,
+        own_fields,
+        own_init,
+        own_reset,
+        own_reserve,
+        own_shrink_to_fit,
+        reset_doc,
+        len,
+        validity,
+        try_push,
+        into_array,
+        methods,
+    } = parts;
+
+    let mutable_child_array_types = child_types
+        .iter()
+        .map(|field_type| quote_spanned!( field_type.span() => <#field_type as arrow2_convert::serialize::ArrowSerialize>::MutableArrayType))
+        .collect::<Vec<TokenStream>>();
+
+    quote! {
         #[allow(non_snake_case)]
         #[derive(Debug)]
         #visibility struct #mutable_array_name {
             #(
-                #variant_names: #mutable_variant_array_types,
+                #child_names: #mutable_child_array_types,
             )*
             data_type: arrow2::datatypes::DataType,
-            types: Vec<i8>,
-            #offsets_decl
+            #own_fields
         }
-    };
 
-    let array_impl = quote! {
         impl #mutable_array_name {
             pub fn new() -> Self {
                 Self {
-                    #(#variant_names: <#variant_types as arrow2_convert::serialize::ArrowSerialize>::new_array(),)*
+                    #(#child_names: <#child_types as arrow2_convert::serialize::ArrowSerialize>::new_array(),)*
                     data_type: <#original_name as arrow2_convert::field::ArrowField>::data_type(),
-                    types: vec![],
-                    #offsets_init
+                    #own_init
                 }
             }
+
+            #[doc = #reset_doc]
+            pub fn reset(&mut self) {
+                #(self.#child_names = <#child_types as arrow2_convert::serialize::ArrowSerialize>::new_array();)*
+                #own_reset
+            }
+
+            #methods
         }
-    };
 
-    let array_try_push_impl = quote! {
         impl<__T: std::borrow::Borrow<#original_name>> arrow2::array::TryPush<Option<__T>> for #mutable_array_name {
             fn try_push(&mut self, item: Option<__T>) -> arrow2::error::Result<()> {
                 use arrow2::array::MutableArray;
 
-                match item {
-                    Some(i) => {
-                        match i.borrow() {
-                            #(
-                                #try_push_match_blocks
-                            )*
-                        }
-                    },
-                    None => {
-                        #try_push_none
-                    }
-                }
+                #try_push
                 Ok(())
             }
         }
-    };
 
-    let array_default_impl = quote! {
         impl Default for #mutable_array_name {
             fn default() -> Self {
                 Self::new()
             }
         }
-    };
 
-    let array_try_extend_impl = quote! {
         impl<__T: std::borrow::Borrow<#original_name>> arrow2::array::TryExtend<Option<__T>> for #mutable_array_name {
             fn try_extend<I: IntoIterator<Item = Option<__T>>>(&mut self, iter: I) -> arrow2::error::Result<()> {
                 use arrow2::array::TryPush;
@@ -304,46 +471,29 @@ pub fn expand_serialize(input: DeriveEnum) -> TokenStream {
                 Ok(())
             }
         }
-    };
 
-    let array_mutable_array_impl = quote! {
         impl arrow2::array::MutableArray for #mutable_array_name {
             fn data_type(&self) -> &arrow2::datatypes::DataType {
                 &self.data_type
             }
 
             fn len(&self) -> usize {
-                self.types.len()
+                #len
             }
 
             fn validity(&self) -> Option<&arrow2::bitmap::MutableBitmap> {
-                None
+                #validity
             }
 
             fn as_box(&mut self) -> Box<dyn arrow2::array::Array> {
                 let values = vec![#(
-                    <#mutable_variant_array_types as arrow2::array::MutableArray>::as_box(&mut self.#variant_names),
+                    <#mutable_child_array_types as arrow2::array::MutableArray>::as_box(&mut self.#child_names),
                 )*];
-
-                    Box::new(arrow2::array::UnionArray::new(
-                    <#original_name as arrow2_convert::field::ArrowField>::data_type().clone(),
-                    std::mem::take(&mut self.types).into(),
-                    values,
-                    #offsets_take
-                ))
+                #into_array
             }
 
             fn as_arc(&mut self) -> std::sync::Arc<dyn arrow2::array::Array> {
-                let values = vec![#(
-                    <#mutable_variant_array_types as arrow2::array::MutableArray>::as_box(&mut self.#variant_names),
-                )*];
-
-                    std::sync::Arc::new(arrow2::array::UnionArray::new(
-                    <#original_name as arrow2_convert::field::ArrowField>::data_type().clone(),
-                    std::mem::take(&mut self.types).into(),
-                    values,
-                    #offsets_take
-                ))
+                self.as_box().into()
             }
 
             fn as_any(&self) -> &dyn std::any::Any {
@@ -361,21 +511,25 @@ pub fn expand_serialize(input: DeriveEnum) -> TokenStream {
 
             fn shrink_to_fit(&mut self) {
                 #(
-                    <#mutable_variant_array_types as arrow2::array::MutableArray>::shrink_to_fit(&mut self.#variant_names);
+                    <#mutable_child_array_types as arrow2::array::MutableArray>::shrink_to_fit(&mut self.#child_names);
                 )*
-                self.types.shrink_to_fit();
-                #offsets_shrink_to_fit
+                #own_shrink_to_fit
             }
 
             fn reserve(&mut self, additional: usize) {
-                #(<<#variant_types as arrow2_convert::serialize::ArrowSerialize>::MutableArrayType as arrow2::array::MutableArray>::reserve(&mut self.#variant_names, additional);)*
-                self.types.reserve(additional);
-                #offsets_reserve
+                #(
+                    <#mutable_child_array_types as arrow2::array::MutableArray>::reserve(&mut self.#child_names, additional);
+                )*
+                #own_reserve
+            }
+        }
+
+        impl From<#mutable_array_name> for Box<dyn arrow2::array::Array> {
+            fn from(mut array: #mutable_array_name) -> Self {
+                <#mutable_array_name as arrow2::array::MutableArray>::as_box(&mut array)
             }
         }
-    };
 
-    let field_arrow_serialize_impl = quote! {
         impl arrow2_convert::serialize::ArrowSerialize for #original_name {
             type MutableArrayType = #mutable_array_name;
 
@@ -390,20 +544,17 @@ pub fn expand_serialize(input: DeriveEnum) -> TokenStream {
                 array.try_push(Some(v))
             }
         }
-    };
-
-    TokenStream::from_iter([
-        array_decl,
-        array_impl,
-        array_try_push_impl,
-        array_default_impl,
-        array_try_extend_impl,
-        array_mutable_array_impl,
-        field_arrow_serialize_impl,
-    ])
+    }
 }
 
 pub fn expand_deserialize(input: DeriveEnum) -> TokenStream {
+    if input.is_index {
+        return expand_index_deserialize(input);
+    }
+    if input.is_struct {
+        return expand_struct_deserialize(input);
+    }
+
     let Common {
         original_name,
         original_name_str,
@@ -418,28 +569,36 @@ pub fn expand_deserialize(input: DeriveEnum) -> TokenStream {
     let iterator_name = &input.common.iterator_name();
 
     // For unit variants, return the variant directly. For non-unit variants, get the slice of the underlying field array
-    // and deserialize to the variant type.
-    let iter_next_match_block = {
+    // and deserialize to the variant type. A null payload is a null row, which is how `None` rows are serialized.
+    let row_match_block = {
         let candidates = variants.iter()
                     .zip(&variant_indices)
                     .zip(&variant_types)
                     .map(|((v, lit_idx), variant_type)| {
                         let name = &v.syn.ident;
+                        let name_str = format!("{name}");
                         if v.is_unit {
                             quote! {
                                 #lit_idx => {
-                                    Some(Some(#original_name::#name))
+                                    Ok(Some(#original_name::#name))
                                 }
                             }
                         }
                         else {
                             quote! {
                                 #lit_idx => {
+                                    let field = &arr.fields()[type_idx];
+                                    if offset >= field.len() {
+                                        return Err(arrow2_convert::error::Error::Deserialize(format!(
+                                            "Invalid offset {} for variant `{}::{}` at row {}", offset, #original_name_str, #name_str, index
+                                        )));
+                                    }
+                                    let slice = field.sliced(offset, 1);
                                     let mut slice_iter = <<#variant_type as arrow2_convert::deserialize::ArrowDeserialize> ::ArrayType as arrow2_convert::deserialize::ArrowArray> ::iter_from_array_ref(slice.deref());
-                                    let v = slice_iter
+                                    Ok(slice_iter
                                         .next()
-                                        .unwrap_or_else(|| panic!("Invalid offset for {}", #lit_idx));
-                                    Some(<#variant_type as arrow2_convert::deserialize::ArrowDeserialize>::arrow_deserialize(v).map(|v| #original_name::#name(v)))
+                                        .and_then(<#variant_type as arrow2_convert::deserialize::ArrowDeserialize>::arrow_deserialize)
+                                        .map(#original_name::#name))
                                 }
                             }
                         }
@@ -448,6 +607,41 @@ pub fn expand_deserialize(input: DeriveEnum) -> TokenStream {
         quote! { #(#candidates)* }
     };
 
+    // The variant named in the error for a null row that can't be read as null
+    let variant_names_by_index = variants
+        .iter()
+        .zip(&variant_indices)
+        .map(|(v, lit_idx)| {
+            let name_str = format!("{}", v.syn.ident);
+            quote!(#lit_idx => #name_str,)
+        })
+        .collect::<Vec<_>>();
+
+    // The payload of each variant is checked once per array, since the union may have been built by hand
+    let payload_checks = variants
+        .iter()
+        .zip(&variant_types)
+        .enumerate()
+        .filter(|(_, (v, _))| !v.is_unit)
+        .map(|(field_idx, (v, variant_type))| {
+            let name_str = format!("{}", v.syn.ident);
+            quote! {
+                let field = arr.fields().get(#field_idx).ok_or_else(|| arrow2_convert::error::Error::Deserialize(format!(
+                    "Missing the payload of variant `{}::{}`", #original_name_str, #name_str
+                )))?;
+                if let Some((path, expected, found)) = <#variant_type as arrow2_convert::deserialize::ArrowDeserialize>::data_type_mismatch(field.data_type()) {
+                    let path = if path.is_empty() { #name_str.to_string() } else { format!("{}.{}", #name_str, path) };
+                    return Err(arrow2_convert::error::Error::SchemaMismatch {
+                        path,
+                        expected: Box::new(expected),
+                        found: Box::new(found),
+                    });
+                }
+                <#variant_type as arrow2_convert::deserialize::ArrowDeserialize>::validate_array(field.as_ref())?;
+            }
+        })
+        .collect::<Vec<_>>();
+
     let array_decl = quote! {
         #visibility struct #array_name
         {}
@@ -461,7 +655,7 @@ pub fn expand_deserialize(input: DeriveEnum) -> TokenStream {
             #[inline]
             fn iter_from_array_ref<'a>(b: &'a dyn arrow2::array::Array)  -> <&'a Self as IntoIterator>::IntoIter
             {
-                let arr = b.as_any().downcast_ref::<arrow2::array::UnionArray>().unwrap();
+                let arr = b.as_any().downcast_ref::<arrow2::array::UnionArray>().expect("the array was checked by `validate_array`");
 
                 #iterator_name {
                     arr,
@@ -497,18 +691,22 @@ pub fn expand_deserialize(input: DeriveEnum) -> TokenStream {
 
             #[inline]
             fn next(&mut self) -> Option<Self::Item> {
-                use core::ops::Deref;
-                let Some(next_index) = self.index_iter.next() else {
-                    return None;
-                };
-                let (type_idx, offset) = self.arr.index(next_index);
-                let slice = self.arr.fields()[type_idx].sliced(offset, 1);
-                match type_idx {
-                    #iter_next_match_block
-                    _ => panic!("Invalid type for {}", #original_name_str)
+                let next_index = self.index_iter.next()?;
+                // a row that can't be read is reported rather than read as null.
+                // Use `try_union_into_collection` to get the error instead.
+                match <#original_name as arrow2_convert::deserialize::ArrowDeserializeUnion>::try_deserialize_nullable_union_row(self.arr, next_index) {
+                    Ok(v) => Some(v),
+                    Err(e) => panic!("{}", e),
                 }
             }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.index_iter.size_hint()
+            }
         }
+
+        impl<'a> ExactSizeIterator for #iterator_name<'a> {}
     };
 
     let field_arrow_deserialize_impl = quote! {
@@ -519,6 +717,56 @@ pub fn expand_deserialize(input: DeriveEnum) -> TokenStream {
             fn arrow_deserialize<'a>(v: Option<Self>) -> Option<Self> {
                 v
             }
+
+            #[inline]
+            fn arrow_deserialize_internal<'a>(v: Option<Self>) -> Self {
+                v.unwrap_or_else(|| panic!("A null row of `{}` can only be deserialized into an `Option`", #original_name_str))
+            }
+
+            fn validate_array(array: &dyn arrow2::array::Array) -> arrow2_convert::error::Result<()> {
+                let arr = array
+                    .as_any()
+                    .downcast_ref::<arrow2::array::UnionArray>()
+                    .ok_or_else(|| arrow2_convert::error::Error::Deserialize("Expected a union array".to_string()))?;
+                <Self as arrow2_convert::deserialize::ArrowDeserializeUnion>::validate_union(arr)
+            }
+        }
+    };
+
+    let field_arrow_deserialize_union_impl = quote! {
+        impl arrow2_convert::deserialize::ArrowDeserializeUnion for #original_name {
+            fn validate_union(arr: &arrow2::array::UnionArray) -> arrow2_convert::error::Result<()> {
+                #(#payload_checks)*
+                Ok(())
+            }
+
+            fn try_deserialize_union_row(arr: &arrow2::array::UnionArray, index: usize) -> arrow2_convert::error::Result<Self> {
+                Self::try_deserialize_nullable_union_row(arr, index)?.ok_or_else(|| {
+                    let variant = match arr.index(index).0 {
+                        #(#variant_names_by_index)*
+                        _ => unreachable!("the type was checked"),
+                    };
+                    arrow2_convert::error::Error::Deserialize(format!(
+                        "Failed to deserialize variant `{}::{}` at row {}", #original_name_str, variant, index
+                    ))
+                })
+            }
+
+            fn try_deserialize_nullable_union_row(arr: &arrow2::array::UnionArray, index: usize) -> arrow2_convert::error::Result<Option<Self>> {
+                use core::ops::Deref;
+                if index >= arr.len() {
+                    return Err(arrow2_convert::error::Error::Deserialize(format!(
+                        "Row {} is out of bounds for `{}`", index, #original_name_str
+                    )));
+                }
+                let (type_idx, offset) = arr.index(index);
+                match type_idx {
+                    #row_match_block
+                    _ => Err(arrow2_convert::error::Error::Deserialize(format!(
+                        "Invalid type {} for `{}` at row {}", type_idx, #original_name_str, index
+                    )))
+                }
+            }
         }
     };
 
@@ -529,5 +777,443 @@ pub fn expand_deserialize(input: DeriveEnum) -> TokenStream {
         array_iterator_decl,
         array_iterator_iterator_impl,
         field_arrow_deserialize_impl,
+        field_arrow_deserialize_union_impl,
     ])
 }
+
+// Unit-only enums with type "i8" are stored as the type id of each variant in an `Int8` array,
+// which is much more compact than a union of boolean fields.
+
+fn expand_index_field(input: DeriveEnum) -> TokenStream {
+    let original_name = &input.common.name;
+    let data_type_impl = input
+        .common
+        .extension_data_type(quote!(arrow2::datatypes::DataType::Int8));
+
+    quote! {
+        impl arrow2_convert::field::ArrowField for #original_name {
+            type Type = Self;
+
+            fn data_type() -> arrow2::datatypes::DataType {
+                #data_type_impl
+            }
+        }
+
+        arrow2_convert::arrow_enable_vec_for_type!(#original_name);
+    }
+}
+
+fn expand_index_serialize(input: DeriveEnum) -> TokenStream {
+    let Common {
+        original_name,
+        variant_names,
+        variant_type_ids,
+        ..
+    } = (&input).into();
+
+    quote! {
+        impl arrow2_convert::serialize::ArrowSerialize for #original_name {
+            type MutableArrayType = arrow2::array::MutablePrimitiveArray<i8>;
+
+            #[inline]
+            fn new_array() -> Self::MutableArrayType {
+                Self::MutableArrayType::from(<Self as arrow2_convert::field::ArrowField>::data_type())
+            }
+
+            #[inline]
+            fn arrow_serialize(v: &Self, array: &mut Self::MutableArrayType) -> arrow2::error::Result<()> {
+                use arrow2::array::TryPush;
+                let type_id = match v {
+                    #(#original_name::#variant_names => #variant_type_ids,)*
+                };
+                array.try_push(Some(type_id))
+            }
+        }
+    }
+}
+
+fn expand_index_deserialize(input: DeriveEnum) -> TokenStream {
+    let Common {
+        original_name,
+        original_name_str,
+        variant_names,
+        variant_type_ids,
+        ..
+    } = (&input).into();
+
+    quote! {
+        impl arrow2_convert::deserialize::ArrowDeserialize for #original_name {
+            type ArrayType = arrow2::array::PrimitiveArray<i8>;
+
+            #[inline]
+            fn arrow_deserialize<'a>(v: Option<&i8>) -> Option<Self> {
+                match *v? {
+                    #(#variant_type_ids => Some(#original_name::#variant_names),)*
+                    tag => panic!("Invalid tag {} for `{}`", tag, #original_name_str),
+                }
+            }
+
+            fn validate_array(array: &dyn arrow2::array::Array) -> arrow2_convert::error::Result<()> {
+                let tags = array
+                    .as_any()
+                    .downcast_ref::<arrow2::array::PrimitiveArray<i8>>()
+                    .ok_or_else(|| arrow2_convert::error::Error::Deserialize("Expected an `Int8` array".to_string()))?;
+                for (index, tag) in tags.iter().enumerate() {
+                    match tag {
+                        Some(tag) if ![#(#variant_type_ids),*].contains(tag) => {
+                            return Err(arrow2_convert::error::Error::Deserialize(format!(
+                                "Invalid tag {} for `{}` at row {}", tag, #original_name_str, index
+                            )));
+                        }
+                        _ => {}
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+// Enums with type "struct" are stored as a struct of the `Int8` type id of each variant, named `tag`, and a
+// nullable field per non-unit variant holding its payload. Unit variants are only stored in the tag.
+
+/// The names, field names and types of the variants that have a payload field
+fn struct_payloads<'a>(
+    common: &Common<'a>,
+) -> (
+    Vec<proc_macro2::Ident>,
+    Vec<syn::LitStr>,
+    Vec<&'a syn::TypePath>,
+) {
+    let mut names = vec![];
+    let mut names_str = vec![];
+    let mut types = vec![];
+    for ((v, name_str), ty) in common
+        .variants
+        .iter()
+        .zip(&common.variant_names_str)
+        .zip(&common.variant_types)
+    {
+        if !v.is_unit {
+            names.push(v.syn.ident.clone());
+            names_str.push(name_str.clone());
+            types.push(*ty);
+        }
+    }
+    (names, names_str, types)
+}
+
+fn expand_struct_field(input: DeriveEnum) -> TokenStream {
+    let common: Common = (&input).into();
+    let original_name = common.original_name;
+    let (_, payload_names_str, payload_types) = struct_payloads(&common);
+
+    let data_type_impl = input.common.extension_data_type(quote! {
+        arrow2::datatypes::DataType::Struct(vec![
+            arrow2::datatypes::Field::new("tag", arrow2::datatypes::DataType::Int8, false),
+            #(
+                arrow2::datatypes::Field::new(#payload_names_str, <#payload_types as arrow2_convert::field::ArrowField>::data_type(), true),
+            )*
+        ])
+    });
+
+    quote! {
+        impl arrow2_convert::field::ArrowField for #original_name {
+            type Type = Self;
+
+            fn data_type() -> arrow2::datatypes::DataType {
+                #data_type_impl
+            }
+        }
+
+        arrow2_convert::arrow_enable_vec_for_type!(#original_name);
+    }
+}
+
+fn expand_struct_serialize(input: DeriveEnum) -> TokenStream {
+    let common: Common = (&input).into();
+    let Common {
+        original_name,
+        visibility,
+        variants,
+        variant_type_ids,
+        ..
+    } = &common;
+    let (payload_names, _, payload_types) = struct_payloads(&common);
+
+    let mutable_array_name = &input.common.mutable_array_name();
+    let mutable_payload_array_types = payload_types
+        .iter()
+        .map(|field_type| quote_spanned!( field_type.span() => <#field_type as arrow2_convert::serialize::ArrowSerialize>::MutableArrayType))
+        .collect::<Vec<TokenStream>>();
+
+    // Every row pushes the tag, the payload of the matched variant, and a null for all the other payloads
+    let try_push_match_blocks = variants
+        .iter()
+        .zip(variant_type_ids)
+        .map(|(v, type_id)| {
+            let name = &v.syn.ident;
+            let push_none = payload_names
+                .iter()
+                .zip(&mutable_payload_array_types)
+                .filter(|(payload_name, _)| *payload_name != name)
+                .map(|(payload_name, array_type)| {
+                    quote!(<#array_type as MutableArray>::push_null(&mut self.#payload_name);)
+                });
+            if v.is_unit {
+                quote! {
+                    #original_name::#name => {
+                        #(#push_none)*
+                        self.tag.push(Some(#type_id));
+                    }
+                }
+            } else {
+                let variant_type = &v.field_type;
+                quote! {
+                    #original_name::#name(v) => {
+                        <#variant_type as arrow2_convert::serialize::ArrowSerialize>::arrow_serialize(v, &mut self.#name)?;
+                        #(#push_none)*
+                        self.tag.push(Some(#type_id));
+                    }
+                }
+            }
+        })
+        .collect::<Vec<TokenStream>>();
+
+    let first_type_id = &variant_type_ids[0];
+
+    let try_push = quote! {
+        match item {
+            Some(i) => {
+                match i.borrow() {
+                    #(
+                        #try_push_match_blocks
+                    )*
+                }
+                match &mut self.validity {
+                    Some(validity) => validity.push(true),
+                    None => {}
+                }
+            },
+            None => {
+                #(
+                    <#mutable_payload_array_types as MutableArray>::push_null(&mut self.#payload_names);
+                )*
+                self.tag.push(Some(#first_type_id));
+                match &mut self.validity {
+                    Some(validity) => validity.push(false),
+                    None => {
+                        self.init_validity();
+                    }
+                }
+            }
+        }
+    };
+
+    expand_mutable_array(MutableArrayParts {
+        original_name,
+        visibility,
+        mutable_array_name,
+        child_names: &payload_names,
+        child_types: &payload_types,
+        own_fields: quote! {
+            tag: arrow2::array::MutablePrimitiveArray<i8>,
+            validity: Option<arrow2::bitmap::MutableBitmap>,
+        },
+        own_init: quote! {
+            tag: arrow2::array::MutablePrimitiveArray::<i8>::new(),
+            validity: None,
+        },
+        own_reset: quote! {
+            let (data_type, mut tags, _) = std::mem::take(&mut self.tag).into_inner();
+            tags.clear();
+            self.tag = arrow2::array::MutablePrimitiveArray::try_new(data_type, tags, None).unwrap();
+            if let Some(validity) = &mut self.validity {
+                validity.clear();
+            }
+        },
+        own_reserve: quote! {
+            self.tag.reserve(additional);
+            if let Some(validity) = &mut self.validity {
+                validity.reserve(additional);
+            }
+        },
+        own_shrink_to_fit: quote! {
+            self.tag.shrink_to_fit();
+            if let Some(validity) = &mut self.validity {
+                validity.shrink_to_fit();
+            }
+        },
+        reset_doc: "Removes all rows while keeping the allocated tags and validity, so that the array can be reused \
+            for the next batch. The payload arrays are replaced by new, empty arrays.",
+        len: quote!(self.tag.len()),
+        validity: quote!(self.validity.as_ref()),
+        try_push,
+        into_array: quote! {
+            let mut fields = vec![
+                <arrow2::array::MutablePrimitiveArray<i8> as arrow2::array::MutableArray>::as_box(&mut self.tag),
+            ];
+            fields.extend(values);
+            Box::new(arrow2::array::StructArray::new(
+                <#original_name as arrow2_convert::field::ArrowField>::data_type(),
+                fields,
+                std::mem::take(&mut self.validity).map(|x| x.into()),
+            ))
+        },
+        methods: quote! {
+            fn init_validity(&mut self) {
+                let mut validity = arrow2::bitmap::MutableBitmap::new();
+                validity.extend_constant(<Self as arrow2::array::MutableArray>::len(self), true);
+                validity.set(<Self as arrow2::array::MutableArray>::len(self) - 1, false);
+                self.validity = Some(validity)
+            }
+        },
+    })
+}
+
+fn expand_struct_deserialize(input: DeriveEnum) -> TokenStream {
+    let common: Common = (&input).into();
+    let Common {
+        original_name,
+        original_name_str,
+        visibility,
+        variants,
+        variant_type_ids,
+        ..
+    } = &common;
+    let (payload_names, _, payload_types) = struct_payloads(&common);
+
+    let array_name = &input.common.array_name();
+    let iterator_name = &input.common.iterator_name();
+    let payload_indices = (1..=payload_names.len())
+        .map(|idx| syn::LitInt::new(&format!("{idx}"), proc_macro2::Span::call_site()))
+        .collect::<Vec<_>>();
+
+    let tag_match_arms = variants
+        .iter()
+        .zip(variant_type_ids)
+        .map(|(v, type_id)| {
+            let name = &v.syn.ident;
+            if v.is_unit {
+                quote!(#type_id => Some(#original_name::#name),)
+            } else {
+                let variant_type = &v.field_type;
+                quote! {
+                    #type_id => <#variant_type as arrow2_convert::deserialize::ArrowDeserialize>::arrow_deserialize(#name)
+                        .map(#original_name::#name),
+                }
+            }
+        });
+
+    quote! {
+        #visibility struct #array_name
+        {}
+
+        impl arrow2_convert::deserialize::ArrowArray for #array_name
+        {
+            type BaseArrayType = arrow2::array::StructArray;
+
+            #[inline]
+            fn iter_from_array_ref<'a>(b: &'a dyn arrow2::array::Array)  -> <&'a Self as IntoIterator>::IntoIter
+            {
+                use core::ops::Deref;
+                let arr = b.as_any().downcast_ref::<arrow2::array::StructArray>().expect("the array was checked by `validate_array`");
+                let values = arr.values();
+                #iterator_name {
+                    arr,
+                    index_iter: 0..arr.len(),
+                    tag: values[0].as_any().downcast_ref::<arrow2::array::PrimitiveArray<i8>>().expect("the array was checked by `validate_array`").iter(),
+                    #(
+                        #payload_names: <<#payload_types as arrow2_convert::deserialize::ArrowDeserialize>::ArrayType as arrow2_convert::deserialize::ArrowArray>::iter_from_array_ref(values[#payload_indices].deref()),
+                    )*
+                }
+            }
+        }
+
+        impl<'a> IntoIterator for &'a #array_name
+        {
+            type Item = Option<#original_name>;
+            type IntoIter = #iterator_name<'a>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                unimplemented!("Use iter_from_array_ref");
+            }
+        }
+
+        #[allow(non_snake_case)]
+        #visibility struct #iterator_name<'a> {
+            arr: &'a arrow2::array::StructArray,
+            index_iter: std::ops::Range<usize>,
+            tag: <&'a arrow2::array::PrimitiveArray<i8> as IntoIterator>::IntoIter,
+            #(
+                #payload_names: <&'a <#payload_types as arrow2_convert::deserialize::ArrowDeserialize>::ArrayType as IntoIterator>::IntoIter,
+            )*
+        }
+
+        impl<'a> Iterator for #iterator_name<'a> {
+            type Item = Option<#original_name>;
+
+            #[inline]
+            #[allow(non_snake_case)]
+            fn next(&mut self) -> Option<Self::Item> {
+                let index = self.index_iter.next()?;
+                let tag = self.tag.next()?;
+                #(
+                    let #payload_names = self.#payload_names.next()?;
+                )*
+                let tag = match tag {
+                    Some(tag) if self.arr.is_valid(index) => *tag,
+                    _ => return Some(None),
+                };
+                Some(match tag {
+                    #(#tag_match_arms)*
+                    _ => unreachable!("the tags were checked by `validate_array`"),
+                })
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                self.index_iter.size_hint()
+            }
+        }
+
+        impl<'a> ExactSizeIterator for #iterator_name<'a> {}
+
+        impl arrow2_convert::deserialize::ArrowDeserialize for #original_name {
+            type ArrayType = #array_name;
+
+            #[inline]
+            fn arrow_deserialize<'a>(v: Option<Self>) -> Option<Self> {
+                v
+            }
+
+            fn validate_array(array: &dyn arrow2::array::Array) -> arrow2_convert::error::Result<()> {
+                use core::ops::Deref;
+                let arr = array
+                    .as_any()
+                    .downcast_ref::<arrow2::array::StructArray>()
+                    .ok_or_else(|| arrow2_convert::error::Error::Deserialize("Expected a struct array".to_string()))?;
+                let values = arr.values();
+                let tags = values[0]
+                    .as_any()
+                    .downcast_ref::<arrow2::array::PrimitiveArray<i8>>()
+                    .ok_or_else(|| arrow2_convert::error::Error::Deserialize("Expected an `Int8` tag".to_string()))?;
+                // the tags of null rows aren't read
+                for (index, tag) in tags.iter().enumerate() {
+                    match tag {
+                        Some(tag) if arr.is_valid(index) && ![#(#variant_type_ids),*].contains(tag) => {
+                            return Err(arrow2_convert::error::Error::Deserialize(format!(
+                                "Invalid tag {} for `{}` at row {}", tag, #original_name_str, index
+                            )));
+                        }
+                        _ => {}
+                    }
+                }
+                #(
+                    <#payload_types as arrow2_convert::deserialize::ArrowDeserialize>::validate_array(values[#payload_indices].deref())?;
+                )*
+                Ok(())
+            }
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use proc_macro2::TokenStream;
-use proc_macro_error::abort;
+use proc_macro_error::{abort, abort_if_dirty, emit_error};
 use quote::{format_ident, quote, quote_spanned};
+use syn::ext::IdentExt;
 use syn::spanned::Spanned;
 
 use super::input::*;
@@ -10,6 +11,11 @@ struct Common<'a> {
     visibility: &'a syn::Visibility,
     field_members: Vec<syn::Member>,
     field_idents: Vec<syn::Ident>,
+    field_names_str: Vec<syn::LitStr>,
+    field_nullable: Vec<Option<syn::LitBool>>,
+    field_item_nullable: Vec<Option<syn::LitBool>>,
+    field_default: Vec<bool>,
+    field_flatten: Vec<bool>,
     skipped_field_names: Vec<syn::Member>,
     field_indices: Vec<syn::LitInt>,
     field_types: Vec<&'a syn::TypePath>,
@@ -20,21 +26,22 @@ impl<'a> From<&'a DeriveStruct> for Common<'a> {
         let original_name = &input.common.name;
         let visibility = &input.common.visibility;
 
-        let (skipped_fields, fields): (Vec<_>, Vec<_>) =
-            input.fields.iter().partition(|field| field.skip);
-
-        let field_members = fields
+        // Members are numbered by their position in the struct, including skipped fields
+        let (skipped_fields, fields): (Vec<_>, Vec<_>) = input
+            .fields
             .iter()
             .enumerate()
             .map(|(id, field)| {
-                field
+                let member = field
                     .syn
                     .ident
                     .as_ref()
                     .cloned()
-                    .map_or_else(|| syn::Member::Unnamed(id.into()), syn::Member::Named)
+                    .map_or_else(|| syn::Member::Unnamed(id.into()), syn::Member::Named);
+                (field, member)
             })
-            .collect::<Vec<_>>();
+            .partition(|(field, _)| field.skip);
+        let (fields, field_members): (Vec<_>, Vec<_>) = fields.into_iter().unzip();
 
         let field_idents = field_members
             .iter()
@@ -45,23 +52,69 @@ impl<'a> From<&'a DeriveStruct> for Common<'a> {
             })
             .collect::<Vec<_>>();
 
-        let skipped_field_names = skipped_fields
+        // The arrow field names, taking `rename` into account. Duplicates would make the schema ambiguous.
+        let field_names_str = fields
             .iter()
-            .enumerate()
-            .map(|(id, field)| {
-                field
-                    .syn
-                    .ident
-                    .as_ref()
-                    .cloned()
-                    .map_or_else(|| syn::Member::Unnamed(id.into()), syn::Member::Named)
+            .zip(&field_members)
+            .map(|(field, member)| match (&field.rename, member) {
+                (Some(rename), _) => rename.clone(),
+                (None, syn::Member::Named(ident)) => {
+                    syn::LitStr::new(&ident.unraw().to_string(), ident.span())
+                }
+                (None, syn::Member::Unnamed(index)) => {
+                    syn::LitStr::new(&format!("field_{}", index.index), field.syn.span())
+                }
             })
             .collect::<Vec<_>>();
 
+        let field_flatten = fields
+            .iter()
+            .map(|field| field.flatten.is_some())
+            .collect::<Vec<_>>();
+
+        // Flattened fields have no column of their own, their columns are checked when the data type is built
+        let column_names_str = field_names_str
+            .iter()
+            .zip(&field_flatten)
+            .filter(|(_, flatten)| !**flatten)
+            .map(|(name, _)| name)
+            .collect::<Vec<_>>();
+        for name in &column_names_str {
+            if column_names_str
+                .iter()
+                .filter(|n| n.value() == name.value())
+                .count()
+                > 1
+            {
+                emit_error!(name.span(), "Duplicate arrow field name `{}`", name.value());
+            }
+        }
+        abort_if_dirty();
+
+        let field_nullable = fields
+            .iter()
+            .map(|field| field.nullable.clone())
+            .collect::<Vec<_>>();
+
+        let field_item_nullable = fields
+            .iter()
+            .map(|field| field.item_nullable.clone())
+            .collect::<Vec<_>>();
+
+        let field_default = fields
+            .iter()
+            .map(|field| field.default.is_some())
+            .collect::<Vec<_>>();
+
+        let skipped_field_names = skipped_fields
+            .into_iter()
+            .map(|(_, member)| member)
+            .collect::<Vec<_>>();
+
         if field_members.is_empty() {
             abort!(
                 original_name.span(),
-                "Expected struct to have more than one field"
+                "Expected struct to have at least one field"
             );
         }
 
@@ -86,6 +139,11 @@ impl<'a> From<&'a DeriveStruct> for Common<'a> {
             visibility,
             field_members,
             field_idents,
+            field_names_str,
+            field_nullable,
+            field_item_nullable,
+            field_default,
+            field_flatten,
             skipped_field_names,
             field_indices,
             field_types,
@@ -93,38 +151,106 @@ impl<'a> From<&'a DeriveStruct> for Common<'a> {
     }
 }
 
+/// Returns the generics of a transparent struct, with `predicates` on the delegated field added to the where clause.
+fn transparent_generics(
+    input: &DeriveStruct,
+    predicates: Vec<syn::WherePredicate>,
+) -> syn::Generics {
+    let mut generics = input.common.generics.clone();
+    if !predicates.is_empty() {
+        generics.make_where_clause().predicates.extend(predicates);
+    }
+    generics
+}
+
+/// Returns the declared type of the field a transparent struct delegates to, which can differ from
+/// the field type when the `type` attribute is used.
+fn transparent_declared_type(input: &DeriveStruct) -> &syn::Type {
+    &input
+        .fields
+        .iter()
+        .find(|field| !field.skip)
+        .unwrap()
+        .syn
+        .ty
+}
+
 pub fn expand_field(input: DeriveStruct) -> TokenStream {
     let Common {
         original_name,
-        field_members,
-        //field_names_str,
+        field_names_str,
+        field_nullable,
+        field_item_nullable,
+        field_flatten,
         field_types,
         ..
     } = (&input).into();
 
     let data_type_impl = {
-        if input.fields.len() == 1 && input.is_transparent {
+        if input.is_transparent {
             // Special case for single-field (tuple) structs
-            let field = &input.fields[0];
-            let ty = &field.field_type;
+            let ty = &field_types[0];
             quote! (
                 <#ty as arrow2_convert::field::ArrowField>::data_type()
             )
         } else {
-            let field_names = field_members.iter().map(|field| match field {
-                syn::Member::Named(ident) => format_ident!("{}", ident),
-                syn::Member::Unnamed(index) => format_ident!("field_{}", index),
-            });
-            quote!(arrow2::datatypes::DataType::Struct(vec![
-                #(
-                    <#field_types as arrow2_convert::field::ArrowField>::field(stringify!(#field_names)),
-                )*
-            ]))
+            let fields = field_types
+                .iter()
+                .zip(&field_names_str)
+                .zip(&field_nullable)
+                .zip(&field_item_nullable)
+                .map(|(((ty, name), nullable), item_nullable)| {
+                    if nullable.is_none() && item_nullable.is_none() {
+                        return quote!(
+                            <#ty as arrow2_convert::field::ArrowField>::field(#name)
+                        );
+                    }
+                    let data_type = match item_nullable {
+                        Some(item_nullable) => quote!(
+                            arrow2_convert::field::list_with_item_nullable(<#ty as arrow2_convert::field::ArrowField>::data_type(), #item_nullable)
+                        ),
+                        None => quote!(<#ty as arrow2_convert::field::ArrowField>::data_type()),
+                    };
+                    let nullable = match nullable {
+                        Some(nullable) => quote!(#nullable),
+                        None => quote!(<#ty as arrow2_convert::field::ArrowField>::is_nullable()),
+                    };
+                    quote!(arrow2::datatypes::Field::new(#name, #data_type, #nullable))
+                });
+            let fields = if field_flatten.contains(&true) {
+                // The fields of flattened structs are inlined, and checked for name collisions
+                let groups = fields.zip(field_types.iter().zip(&field_flatten)).map(
+                    |(field, (ty, flatten))| {
+                        if *flatten {
+                            quote!(arrow2_convert::field::struct_fields(<#ty as arrow2_convert::field::ArrowField>::data_type()))
+                        } else {
+                            quote!(vec![#field])
+                        }
+                    },
+                );
+                quote!(arrow2_convert::field::flatten_fields(vec![#(#groups,)*]))
+            } else {
+                quote!(vec![#(#fields,)*])
+            };
+            input
+                .common
+                .extension_data_type(quote!(arrow2::datatypes::DataType::Struct(#fields)))
         }
     };
 
+    let generics = if input.is_transparent {
+        let ty = &field_types[0];
+        transparent_generics(
+            &input,
+            vec![syn::parse_quote!(#ty: arrow2_convert::field::ArrowField)],
+        )
+    } else {
+        input.common.generics.clone()
+    };
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
     quote!(
-        impl arrow2_convert::field::ArrowField for #original_name {
+        impl #impl_generics arrow2_convert::field::ArrowField for #original_name #ty_generics #where_clause {
             type Type = Self;
 
             fn data_type() -> arrow2::datatypes::DataType {
@@ -132,7 +258,7 @@ pub fn expand_field(input: DeriveStruct) -> TokenStream {
             }
         }
 
-        arrow2_convert::arrow_enable_vec_for_type!(#original_name);
+        impl #impl_generics arrow2_convert::field::ArrowEnableVecForType for #original_name #ty_generics #where_clause {}
     )
 }
 
@@ -142,6 +268,8 @@ pub fn expand_serialize(input: DeriveStruct) -> TokenStream {
         visibility,
         field_members: field_names,
         field_idents,
+        field_item_nullable,
+        field_flatten,
         field_types,
         ..
     } = (&input).into();
@@ -154,7 +282,13 @@ pub fn expand_serialize(input: DeriveStruct) -> TokenStream {
         .map(|field_type| quote_spanned!( field_type.span() => <#field_type as arrow2_convert::serialize::ArrowSerialize>::MutableArrayType))
         .collect::<Vec<TokenStream>>();
 
+    let mutable_array_doc = format!(
+        "A mutable arrow `StructArray` of [`{original_name}`], which is its `ArrowSerialize::MutableArrayType`.\n\n\
+        Rows can be pushed with `arrow2::array::TryPush` and the array converted with `arrow2::array::MutableArray::as_box`."
+    );
+
     let array_decl = quote! {
+        #[doc = #mutable_array_doc]
         #[derive(Debug)]
         #visibility struct #mutable_array_name {
             #(
@@ -167,6 +301,7 @@ pub fn expand_serialize(input: DeriveStruct) -> TokenStream {
 
     let array_impl = quote! {
         impl #mutable_array_name {
+            /// Creates an empty array.
             pub fn new() -> Self {
                 Self {
                     #(#field_idents: <#field_types as arrow2_convert::serialize::ArrowSerialize>::new_array(),)*
@@ -175,6 +310,15 @@ pub fn expand_serialize(input: DeriveStruct) -> TokenStream {
                 }
             }
 
+            /// Removes all rows while keeping the allocated validity, so that the array can be reused for the next
+            /// batch. The field arrays are replaced by new, empty arrays.
+            pub fn reset(&mut self) {
+                #(self.#field_idents = <#field_types as arrow2_convert::serialize::ArrowSerialize>::new_array();)*
+                if let Some(validity) = &mut self.validity {
+                    validity.clear();
+                }
+            }
+
             fn init_validity(&mut self) {
                 let mut validity = arrow2::bitmap::MutableBitmap::new();
                 validity.extend_constant(<Self as arrow2::array::MutableArray>::len(self), true);
@@ -240,6 +384,39 @@ pub fn expand_serialize(input: DeriveStruct) -> TokenStream {
 
     let first_ident = &field_idents[0];
 
+    // List fields with `item_nullable` are rebuilt with the child nullability of the declared data type
+    let field_values = field_idents
+        .iter()
+        .zip(&mutable_field_array_types)
+        .zip(&field_item_nullable)
+        .map(|((ident, array_type), item_nullable)| {
+            let value = quote!(<#array_type as arrow2::array::MutableArray>::as_box(&mut self.#ident));
+            match item_nullable {
+                Some(item_nullable) => quote!(
+                    arrow2_convert::serialize::arrow_array_with_item_nullable(#value, #item_nullable)
+                ),
+                None => value,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // The child arrays of flattened fields are inlined into the child arrays of the struct
+    let values = if field_flatten.contains(&true) {
+        let groups = field_values
+            .iter()
+            .zip(&field_flatten)
+            .map(|(value, flatten)| {
+                if *flatten {
+                    quote!(arrow2_convert::serialize::struct_array_values(#value))
+                } else {
+                    quote!(vec![#value])
+                }
+            });
+        quote!([#(#groups,)*].into_iter().flatten().collect::<Vec<_>>())
+    } else {
+        quote!(vec![#(#field_values,)*])
+    };
+
     let array_mutable_array_impl = quote! {
         impl arrow2::array::MutableArray for #mutable_array_name {
             fn data_type(&self) -> &arrow2::datatypes::DataType {
@@ -255,9 +432,7 @@ pub fn expand_serialize(input: DeriveStruct) -> TokenStream {
             }
 
             fn as_box(&mut self) -> Box<dyn arrow2::array::Array> {
-                let values = vec![#(
-                    <#mutable_field_array_types as arrow2::array::MutableArray>::as_box(&mut self.#field_idents),
-                )*];
+                let values = #values;
 
                     Box::new(arrow2::array::StructArray::new(
                     <#original_name as arrow2_convert::field::ArrowField>::data_type().clone(),
@@ -267,9 +442,7 @@ pub fn expand_serialize(input: DeriveStruct) -> TokenStream {
             }
 
             fn as_arc(&mut self) -> std::sync::Arc<dyn arrow2::array::Array> {
-                let values = vec![#(
-                    <#mutable_field_array_types as arrow2::array::MutableArray>::as_box(&mut self.#field_idents),
-                )*];
+                let values = #values;
 
                     std::sync::Arc::new(arrow2::array::StructArray::new(
                     <#original_name as arrow2_convert::field::ArrowField>::data_type().clone(),
@@ -310,11 +483,20 @@ pub fn expand_serialize(input: DeriveStruct) -> TokenStream {
     };
 
     // Special case for single-field (tuple) structs.
-    if input.fields.len() == 1 && input.is_transparent {
+    if input.is_transparent {
         let first_type = &field_types[0];
+        let declared_type = transparent_declared_type(&input);
+        let generics = transparent_generics(
+            &input,
+            vec![
+                syn::parse_quote!(#first_type: arrow2_convert::serialize::ArrowSerialize + arrow2_convert::field::ArrowField<Type = #declared_type>),
+            ],
+        );
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
         // Everything delegates to first field.
         quote! {
-            impl arrow2_convert::serialize::ArrowSerialize for #original_name {
+            impl #impl_generics arrow2_convert::serialize::ArrowSerialize for #original_name #ty_generics #where_clause {
                 type MutableArrayType = <#first_type as arrow2_convert::serialize::ArrowSerialize>::MutableArrayType;
 
                 #[inline]
@@ -329,6 +511,14 @@ pub fn expand_serialize(input: DeriveStruct) -> TokenStream {
             }
         }
     } else {
+        let array_into_box_impl = quote! {
+            impl From<#mutable_array_name> for Box<dyn arrow2::array::Array> {
+                fn from(mut array: #mutable_array_name) -> Self {
+                    <#mutable_array_name as arrow2::array::MutableArray>::as_box(&mut array)
+                }
+            }
+        };
+
         let field_arrow_serialize_impl = quote! {
             impl arrow2_convert::serialize::ArrowSerialize for #original_name {
                 type MutableArrayType = #mutable_array_name;
@@ -352,6 +542,7 @@ pub fn expand_serialize(input: DeriveStruct) -> TokenStream {
             array_try_push_impl,
             array_try_extend_impl,
             array_mutable_array_impl,
+            array_into_box_impl,
             field_arrow_serialize_impl,
         ])
     }
@@ -363,43 +554,145 @@ pub fn expand_deserialize(input: DeriveStruct) -> TokenStream {
         visibility,
         field_members: field_names,
         field_idents,
+        field_names_str,
+        field_nullable,
+        field_item_nullable,
+        field_default,
+        field_flatten,
         skipped_field_names,
         field_indices,
         field_types,
         ..
     } = (&input).into();
 
+    // Fields that are declared nullable but aren't optional in Rust are deserialized to their default when null
+    let field_deserializers = field_types
+        .iter()
+        .zip(&field_idents)
+        .zip(&field_nullable)
+        .zip(&field_default)
+        .map(|(((ty, ident), nullable), default)| {
+            let deserialize = |v: TokenStream| match nullable {
+                Some(nullable) if nullable.value => quote!(
+                    <#ty as arrow2_convert::deserialize::ArrowDeserialize>::arrow_deserialize(#v).unwrap_or_default()
+                ),
+                _ => quote!(
+                    <#ty as arrow2_convert::deserialize::ArrowDeserialize>::arrow_deserialize_internal(#v)
+                ),
+            };
+            // Fields with a default are wrapped in an `Option` that is `None` when the field is missing
+            if *default {
+                let deserialize = deserialize(quote!(v));
+                quote!(match #ident {
+                    Some(v) => #deserialize,
+                    None => std::default::Default::default(),
+                })
+            } else {
+                deserialize(quote!(#ident))
+            }
+        })
+        .collect::<Vec<_>>();
+
     let array_name = &input.common.array_name();
     let iterator_name = &input.common.iterator_name();
-    let is_tuple_struct = matches!(field_names[0], syn::Member::Unnamed(_));
 
     let array_decl = quote! {
         #visibility struct #array_name
         {}
     };
 
+    // Child arrays are either looked up by name, which tolerates extra and reordered fields, or by position.
+    // Flattened fields shift the positions of the fields after them, so their structs also look up fields by name,
+    // and the columns of a flattened field are looked up by name among the columns of its parent.
+    let has_flatten = field_flatten.contains(&true);
+    let field_lookups = field_types
+        .iter()
+        .zip(&field_names_str)
+        .zip(&field_indices)
+        .zip(&field_default)
+        .zip(&field_flatten)
+        .map(|((((ty, name), index), default), flatten)| {
+            let array_type = quote!(<#ty as arrow2_convert::deserialize::ArrowDeserialize>::ArrayType);
+            let iter_from_array_ref = quote!(<#array_type as arrow2_convert::deserialize::ArrowArray>::iter_from_array_ref);
+            let position = quote!(fields.iter().position(|f| f.name == #name));
+            if *flatten {
+                quote!(<#array_type>::iter_from_columns(fields, values, validity, len, true))
+            } else if *default {
+                quote!(#position.map(|i| #iter_from_array_ref(values[i].deref())))
+            } else if input.match_by_name || has_flatten {
+                quote!(#iter_from_array_ref(values[#position.expect("the data type was checked")].deref()))
+            } else {
+                quote!(#iter_from_array_ref(values[if by_name { #position.expect("the data type was checked") } else { #index }].deref()))
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let field_iterator_types = field_types
+        .iter()
+        .zip(&field_default)
+        .map(|(ty, default)| {
+            let iter = quote!(<&'a <#ty as arrow2_convert::deserialize::ArrowDeserialize>::ArrayType as IntoIterator>::IntoIter);
+            if *default {
+                quote!(Option<#iter>)
+            } else {
+                iter
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let field_nexts = field_idents
+        .iter()
+        .zip(&field_default)
+        .map(|(ident, default)| {
+            if *default {
+                quote!(self.#ident.as_mut().map_or(Some(None), |i| i.next().map(Some)))
+            } else {
+                quote!(self.#ident.next())
+            }
+        })
+        .collect::<Vec<_>>();
+
     let array_impl = quote! {
-        impl arrow2_convert::deserialize::ArrowArray for #array_name
+        impl #array_name
         {
-            type BaseArrayType = arrow2::array::StructArray;
-
+            /// For internal use by the derive macro.
+            ///
+            /// Returns an iterator over the rows of a struct array with the given columns. The columns are
+            /// looked up by name if `by_name` is set, which lets a flattened struct read its columns from its parent.
+            #[doc(hidden)]
             #[inline]
-            fn iter_from_array_ref<'a>(b: &'a dyn arrow2::array::Array)  -> <&'a Self as IntoIterator>::IntoIter
+            #[allow(unused_variables)]
+            pub fn iter_from_columns<'a>(
+                fields: &'a [arrow2::datatypes::Field],
+                values: &'a [Box<dyn arrow2::array::Array>],
+                validity: Option<&'a arrow2::bitmap::Bitmap>,
+                len: usize,
+                by_name: bool,
+            ) -> #iterator_name<'a>
             {
                 use core::ops::Deref;
-                let arr = b.as_any().downcast_ref::<arrow2::array::StructArray>().unwrap();
-                let values = arr.values();
-                let validity = arr.validity();
-                // for now do a straight comp
                 #iterator_name {
                     #(
-                        #field_idents: <<#field_types as arrow2_convert::deserialize::ArrowDeserialize>::ArrayType as arrow2_convert::deserialize::ArrowArray>::iter_from_array_ref(values[#field_indices].deref()),
+                        #field_idents: #field_lookups,
                     )*
-                    has_validity: validity.as_ref().is_some(),
-                    validity_iter: validity.as_ref().map(|x| x.iter()).unwrap_or_else(|| arrow2::bitmap::utils::BitmapIter::new(&[], 0, 0))
+                    has_validity: validity.is_some(),
+                    validity_iter: validity.map(|x| x.iter()).unwrap_or_else(|| arrow2::bitmap::utils::BitmapIter::new(&[], 0, 0)),
+                    remaining: len,
                 }
             }
         }
+
+        impl arrow2_convert::deserialize::ArrowArray for #array_name
+        {
+            type BaseArrayType = arrow2::array::StructArray;
+
+            #[inline]
+            fn iter_from_array_ref<'a>(b: &'a dyn arrow2::array::Array)  -> <&'a Self as IntoIterator>::IntoIter
+            {
+                let arr = b.as_any().downcast_ref::<arrow2::array::StructArray>().expect("the array was checked by `validate_array`");
+                Self::iter_from_columns(arr.fields(), arr.values(), arr.validity(), arr.len(), false)
+            }
+        }
     };
 
     let array_into_iterator_impl = quote! {
@@ -417,26 +710,19 @@ pub fn expand_deserialize(input: DeriveStruct) -> TokenStream {
     let iterator_decl = quote! {
         #visibility struct #iterator_name<'a> {
             #(
-                #field_idents: <&'a <#field_types as arrow2_convert::deserialize::ArrowDeserialize>::ArrayType as IntoIterator>::IntoIter,
+                #field_idents: #field_iterator_types,
             )*
             validity_iter: arrow2::bitmap::utils::BitmapIter<'a>,
-            has_validity: bool
+            has_validity: bool,
+            remaining: usize,
         }
     };
 
-    let struct_inst: syn::Pat = if is_tuple_struct {
-        // If the fields are unnamed, we create a tuple-struct
-        syn::parse_quote! {
-            #original_name (
-                #(<#field_types as arrow2_convert::deserialize::ArrowDeserialize>::arrow_deserialize_internal(#field_idents),)*
-            )
-        }
-    } else {
-        syn::parse_quote! {
-            #original_name {
-                #(#field_names: <#field_types as arrow2_convert::deserialize::ArrowDeserialize>::arrow_deserialize_internal(#field_idents),)*
-                #(#skipped_field_names: std::default::Default::default(),)*
-            }
+    // Tuple structs can also be created with braces, using the field positions as members
+    let struct_inst: syn::Expr = syn::parse_quote! {
+        #original_name {
+            #(#field_names: #field_deserializers,)*
+            #(#skipped_field_names: std::default::Default::default(),)*
         }
     };
 
@@ -447,7 +733,7 @@ pub fn expand_deserialize(input: DeriveStruct) -> TokenStream {
                 if let (#(
                     Some(#field_idents),
                 )*) = (
-                    #(self.#field_idents.next(),)*
+                    #(#field_nexts,)*
                 )
                 { Some(#struct_inst) }
                 else { None }
@@ -455,7 +741,7 @@ pub fn expand_deserialize(input: DeriveStruct) -> TokenStream {
 
             #[inline]
             fn consume_next(&mut self) {
-                #(let _ = self.#field_idents.next();)*
+                #(let _ = #field_nexts;)*
             }
         }
     };
@@ -466,40 +752,134 @@ pub fn expand_deserialize(input: DeriveStruct) -> TokenStream {
 
             #[inline]
             fn next(&mut self) -> Option<Self::Item> {
-                if !self.has_validity {
+                // missing `default` fields never run out, so the length of the array bounds the iteration
+                if self.remaining == 0 {
+                    return None;
+                }
+                let next = if !self.has_validity {
                     self.return_next().map(|y| Some(y))
                 }
                 else {
                     let is_valid = self.validity_iter.next();
                     is_valid.map(|x| if x { self.return_next() } else { self.consume_next(); None })
+                };
+                if next.is_some() {
+                    self.remaining -= 1;
                 }
+                next
+            }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.remaining, Some(self.remaining))
             }
         }
+
+        impl<'a> ExactSizeIterator for #iterator_name<'a> {}
     };
 
     // Special case for single-field (tuple) structs.
-    if input.fields.len() == 1 && input.is_transparent {
+    if input.is_transparent {
         let first_type = &field_types[0];
+        let first_name = &field_names[0];
 
-        let deser_body_mapper = if is_tuple_struct {
-            quote! { #original_name }
-        } else {
-            let first_name = &field_names[0];
-            quote! { |v| #original_name { #first_name: v } }
+        let deser_body_mapper = quote! {
+            |v| #original_name {
+                #first_name: v,
+                #(#skipped_field_names: std::default::Default::default(),)*
+            }
         };
 
+        let declared_type = transparent_declared_type(&input);
+        let generics = transparent_generics(
+            &input,
+            vec![
+                syn::parse_quote!(#first_type: arrow2_convert::deserialize::ArrowDeserialize + arrow2_convert::field::ArrowField<Type = #declared_type>),
+                syn::parse_quote!(for<'b> &'b <#first_type as arrow2_convert::deserialize::ArrowDeserialize>::ArrayType: IntoIterator),
+            ],
+        );
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
         // Everything delegates to first field.
         quote! {
-            impl arrow2_convert::deserialize::ArrowDeserialize for #original_name {
+            impl #impl_generics arrow2_convert::deserialize::ArrowDeserialize for #original_name #ty_generics #where_clause {
                 type ArrayType = <#first_type as arrow2_convert::deserialize::ArrowDeserialize>::ArrayType;
 
                 #[inline]
                 fn arrow_deserialize<'a>(v: <&Self::ArrayType as IntoIterator>::Item) -> Option<Self> {
                     <#first_type as arrow2_convert::deserialize::ArrowDeserialize>::arrow_deserialize(v).map(#deser_body_mapper)
                 }
+
+                #[inline]
+                fn validate_array(array: &dyn arrow2::array::Array) -> arrow2_convert::error::Result<()> {
+                    <#first_type as arrow2_convert::deserialize::ArrowDeserialize>::validate_array(array)
+                }
             }
         }
     } else {
+        // Fields with `item_nullable` are compared with their declared data type
+        let field_mismatch = field_types
+            .iter()
+            .zip(&field_item_nullable)
+            .map(|(ty, item_nullable)| match item_nullable {
+                Some(item_nullable) => quote!(
+                    |found| arrow2_convert::deserialize::find_data_type_mismatch(
+                        &arrow2_convert::field::list_with_item_nullable(<#ty as arrow2_convert::field::ArrowField>::data_type(), #item_nullable),
+                        found,
+                    )
+                ),
+                None => quote!(<#ty as arrow2_convert::deserialize::ArrowDeserialize>::data_type_mismatch),
+            })
+            .collect::<Vec<_>>();
+        let data_type_mismatch_impl = if input.match_by_name {
+            let deny_unknown_fields = input.deny_unknown_fields;
+            quote! {
+                fn data_type_mismatch(found: &arrow2::datatypes::DataType) -> Option<arrow2_convert::deserialize::DataTypeMismatch> {
+                    arrow2_convert::deserialize::find_struct_mismatch_by_name(
+                        &<Self as arrow2_convert::field::ArrowField>::data_type(),
+                        found,
+                        &[#(#field_mismatch,)*],
+                        &[#(#field_default,)*],
+                        #deny_unknown_fields,
+                    )
+                }
+            }
+        } else if !has_flatten {
+            // The fields are checked by their own types, so that nested structs can match their fields by name
+            quote! {
+                fn data_type_mismatch(found: &arrow2::datatypes::DataType) -> Option<arrow2_convert::deserialize::DataTypeMismatch> {
+                    arrow2_convert::deserialize::find_nested_mismatch(
+                        &<Self as arrow2_convert::field::ArrowField>::data_type(),
+                        found,
+                        &[#(#field_mismatch,)*],
+                    )
+                }
+            }
+        } else {
+            quote! {}
+        };
+
+        // The children are looked up the same way as when iterating. Missing `default` fields have nothing to check.
+        let field_validations = field_types
+            .iter()
+            .zip(&field_names_str)
+            .zip(&field_indices)
+            .zip(&field_default)
+            .zip(&field_flatten)
+            .map(|((((ty, name), index), default), flatten)| {
+                let validate_array = quote!(<#ty as arrow2_convert::deserialize::ArrowDeserialize>::validate_array);
+                if *flatten {
+                    quote!(#validate_array(&arrow2_convert::deserialize::flattened_struct_array(arr, <#ty as arrow2_convert::field::ArrowField>::data_type()))?;)
+                } else if *default || input.match_by_name || has_flatten {
+                    quote!(if let Some(i) = arr.fields().iter().position(|f| f.name == #name) {
+                        #validate_array(values[i].as_ref())?;
+                    })
+                } else {
+                    quote!(#validate_array(values[#index].as_ref())?;)
+                }
+            })
+            .collect::<Vec<_>>();
+
         let field_arrow_deserialize_impl = quote! {
             impl arrow2_convert::deserialize::ArrowDeserialize for #original_name {
                 type ArrayType = #array_name;
@@ -508,6 +888,18 @@ pub fn expand_deserialize(input: DeriveStruct) -> TokenStream {
                 fn arrow_deserialize<'a>(v: Option<Self>) -> Option<Self> {
                     v
                 }
+
+                #data_type_mismatch_impl
+
+                fn validate_array(array: &dyn arrow2::array::Array) -> arrow2_convert::error::Result<()> {
+                    let arr = array
+                        .as_any()
+                        .downcast_ref::<arrow2::array::StructArray>()
+                        .ok_or_else(|| arrow2_convert::error::Error::Deserialize("Expected a struct array".to_string()))?;
+                    let values = arr.values();
+                    #(#field_validations)*
+                    Ok(())
+                }
             }
         };
 
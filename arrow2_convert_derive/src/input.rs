@@ -6,16 +6,37 @@ use syn::{DeriveInput, Ident, Lit, Meta, MetaNameValue, Visibility};
 pub const ARROW_FIELD: &str = "arrow_field";
 pub const FIELD_TYPE: &str = "type";
 pub const FIELD_SKIP: &str = "skip";
+pub const FIELD_AS_DAYS_INT: &str = "as_days_int";
+pub const FIELD_LARGE_LIST: &str = "large_list";
+pub const FIELD_LARGE_BINARY: &str = "large_binary";
+pub const FIELD_RENAME: &str = "rename";
+pub const FIELD_NULLABLE: &str = "nullable";
+pub const FIELD_ITEM_NULLABLE: &str = "item_nullable";
+pub const FIELD_DEFAULT: &str = "default";
+pub const FIELD_FLATTEN: &str = "flatten";
+pub const VARIANT_TYPE_ID: &str = "type_id";
 pub const UNION_TYPE: &str = "type";
 pub const UNION_TYPE_SPARSE: &str = "sparse";
 pub const UNION_TYPE_DENSE: &str = "dense";
+pub const ENUM_TYPE_INDEX: &str = "i8";
+pub const ENUM_TYPE_STRUCT: &str = "struct";
 pub const TRANSPARENT: &str = "transparent";
+pub const MATCH_BY_NAME: &str = "match_by_name";
+pub const DENY_UNKNOWN_FIELDS: &str = "deny_unknown_fields";
+pub const EXTENSION_NAME: &str = "extension_name";
+pub const EXTENSION_METADATA: &str = "extension_metadata";
 
 pub struct DeriveCommon {
     /// The input name
     pub name: Ident,
     /// The overall visibility
     pub visibility: Visibility,
+    /// The generic parameters, only supported on transparent structs
+    pub generics: syn::Generics,
+    /// The name of the Arrow extension type wrapping the data type
+    pub extension_name: Option<syn::LitStr>,
+    /// The metadata of the Arrow extension type
+    pub extension_metadata: Option<syn::LitStr>,
 }
 
 pub struct DeriveStruct {
@@ -23,6 +44,10 @@ pub struct DeriveStruct {
     /// The list of fields in the struct
     pub fields: Vec<DeriveField>,
     pub is_transparent: bool,
+    /// Whether fields are looked up by name rather than position when deserializing
+    pub match_by_name: bool,
+    /// Whether columns that don't match any field are rejected when looking up fields by name
+    pub deny_unknown_fields: bool,
 }
 
 pub struct DeriveEnum {
@@ -30,40 +55,104 @@ pub struct DeriveEnum {
     /// The list of variants in the enum
     pub variants: Vec<DeriveVariant>,
     pub is_dense: bool,
+    /// Whether the enum only has unit variants and is stored as the `Int8` type id of each variant
+    pub is_index: bool,
+    /// Whether the enum is stored as a struct of an `Int8` tag and a nullable field per variant payload
+    pub is_struct: bool,
 }
 
 /// All container attributes
 pub struct ContainerAttrs {
     pub is_dense: Option<bool>,
+    pub is_index: Option<Span>,
+    pub is_struct: Option<Span>,
     pub transparent: Option<Span>,
+    pub match_by_name: Option<Span>,
+    pub deny_unknown_fields: Option<Span>,
+    pub extension_name: Option<syn::LitStr>,
+    pub extension_metadata: Option<syn::LitStr>,
 }
 
 /// All field attributes
 pub struct FieldAttrs {
     pub field_type: Option<syn::Type>,
     pub skip: bool,
+    pub as_days_int: Option<Span>,
+    pub large_list: Option<Span>,
+    pub large_binary: Option<Span>,
+    pub rename: Option<syn::LitStr>,
+    pub nullable: Option<syn::LitBool>,
+    pub item_nullable: Option<syn::LitBool>,
+    pub default: Option<Span>,
+    pub flatten: Option<Span>,
+    pub type_id: Option<syn::LitInt>,
 }
 
 pub struct DeriveField {
     pub syn: syn::Field,
     pub field_type: syn::Type,
     pub skip: bool,
+    /// The arrow field name, if overridden via `rename`
+    pub rename: Option<syn::LitStr>,
+    /// The nullability of the arrow field, if overridden via `nullable`
+    pub nullable: Option<syn::LitBool>,
+    /// The nullability of the child of a list field, if overridden via `item_nullable`
+    pub item_nullable: Option<syn::LitBool>,
+    /// Whether the field is filled with its `Default` value when missing from the array
+    pub default: Option<Span>,
+    /// Whether the fields of the struct in this field are inlined into the parent struct
+    pub flatten: Option<Span>,
 }
 
 pub struct DeriveVariant {
     pub syn: syn::Variant,
     pub field_type: syn::Type,
     pub is_unit: bool,
+    /// The union type id, if overridden via `type_id`
+    pub type_id: Option<syn::LitInt>,
+    /// The arrow field name, if overridden via `rename`
+    pub rename: Option<syn::LitStr>,
 }
 
 impl DeriveCommon {
-    pub fn from_ast(input: &DeriveInput, _container_attrs: &ContainerAttrs) -> DeriveCommon {
+    pub fn from_ast(input: &DeriveInput, container_attrs: &ContainerAttrs) -> DeriveCommon {
+        if let (None, Some(metadata)) = (
+            &container_attrs.extension_name,
+            &container_attrs.extension_metadata,
+        ) {
+            abort!(
+                metadata.span(),
+                "'extension_metadata' requires the 'extension_name' attribute"
+            );
+        }
         DeriveCommon {
             name: input.ident.clone(),
             visibility: input.vis.clone(),
+            generics: input.generics.clone(),
+            extension_name: container_attrs.extension_name.clone(),
+            extension_metadata: container_attrs.extension_metadata.clone(),
         }
     }
 
+    /// Wraps the generated data type in the Arrow extension type given by the `extension_name` attribute, if any.
+    pub fn extension_data_type(
+        &self,
+        data_type: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        let Some(name) = &self.extension_name else {
+            return data_type;
+        };
+        let metadata = match &self.extension_metadata {
+            Some(metadata) => quote::quote!(Some(#metadata.to_string())),
+            None => quote::quote!(None),
+        };
+        quote::quote!(arrow2::datatypes::DataType::Extension(
+            #name.to_string(),
+            Box::new(#data_type),
+            #metadata
+        ))
+    }
+
     pub fn mutable_array_name(&self) -> Ident {
         Ident::new(&format!("Mutable{}Array", self.name), Span::call_site())
     }
@@ -80,7 +169,13 @@ impl DeriveCommon {
 impl ContainerAttrs {
     pub fn from_ast(attrs: &[syn::Attribute]) -> ContainerAttrs {
         let mut is_dense: Option<bool> = None;
+        let mut is_index: Option<Span> = None;
+        let mut is_struct: Option<Span> = None;
         let mut is_transparent: Option<Span> = None;
+        let mut match_by_name: Option<Span> = None;
+        let mut deny_unknown_fields: Option<Span> = None;
+        let mut extension_name: Option<syn::LitStr> = None;
+        let mut extension_metadata: Option<syn::LitStr> = None;
 
         for attr in attrs {
             if let Ok(meta) = attr.parse_meta() {
@@ -101,6 +196,13 @@ impl ContainerAttrs {
                                             UNION_TYPE_SPARSE => {
                                                 is_dense = Some(false);
                                             }
+                                            ENUM_TYPE_INDEX => {
+                                                is_dense = Some(false);
+                                                is_index = Some(string.span());
+                                            }
+                                            ENUM_TYPE_STRUCT => {
+                                                is_struct = Some(string.span());
+                                            }
                                             _ => {
                                                 abort!(path.span(), "Unexpected value for mode");
                                             }
@@ -111,6 +213,30 @@ impl ContainerAttrs {
                                         is_transparent = Some(path.span());
                                     }
 
+                                    Meta::Path(path) if path.is_ident(MATCH_BY_NAME) => {
+                                        match_by_name = Some(path.span());
+                                    }
+
+                                    Meta::Path(path) if path.is_ident(DENY_UNKNOWN_FIELDS) => {
+                                        deny_unknown_fields = Some(path.span());
+                                    }
+
+                                    Meta::NameValue(MetaNameValue {
+                                        lit: Lit::Str(string),
+                                        path,
+                                        ..
+                                    }) if path.is_ident(EXTENSION_NAME) => {
+                                        extension_name = Some(string);
+                                    }
+
+                                    Meta::NameValue(MetaNameValue {
+                                        lit: Lit::Str(string),
+                                        path,
+                                        ..
+                                    }) if path.is_ident(EXTENSION_METADATA) => {
+                                        extension_metadata = Some(string);
+                                    }
+
                                     _ => {
                                         abort!(meta.span(), "Unexpected attribute");
                                     }
@@ -124,7 +250,13 @@ impl ContainerAttrs {
 
         ContainerAttrs {
             is_dense,
+            is_index,
+            is_struct,
             transparent: is_transparent,
+            match_by_name,
+            deny_unknown_fields,
+            extension_name,
+            extension_metadata,
         }
     }
 }
@@ -133,6 +265,15 @@ impl FieldAttrs {
     pub fn from_ast(input: &[syn::Attribute]) -> FieldAttrs {
         let mut field_type: Option<syn::Type> = None;
         let mut skip = false;
+        let mut as_days_int: Option<Span> = None;
+        let mut large_list: Option<Span> = None;
+        let mut large_binary: Option<Span> = None;
+        let mut rename: Option<syn::LitStr> = None;
+        let mut nullable: Option<syn::LitBool> = None;
+        let mut item_nullable: Option<syn::LitBool> = None;
+        let mut default: Option<Span> = None;
+        let mut flatten: Option<Span> = None;
+        let mut type_id: Option<syn::LitInt> = None;
 
         for attr in input {
             if let Ok(meta) = attr.parse_meta() {
@@ -146,10 +287,53 @@ impl FieldAttrs {
                                         path,
                                         ..
                                     }) if path.is_ident(FIELD_TYPE) => {
-                                        field_type =
-                                            Some(syn::parse_str(&string.value()).unwrap_or_abort());
+                                        // parse via the literal so that errors point at the override
+                                        field_type = Some(string.parse().unwrap_or_abort());
+                                    }
+                                    Meta::NameValue(MetaNameValue {
+                                        lit: Lit::Str(string),
+                                        path,
+                                        ..
+                                    }) if path.is_ident(FIELD_RENAME) => {
+                                        rename = Some(string);
+                                    }
+                                    Meta::NameValue(MetaNameValue {
+                                        lit: Lit::Bool(value),
+                                        path,
+                                        ..
+                                    }) if path.is_ident(FIELD_NULLABLE) => {
+                                        nullable = Some(value);
+                                    }
+                                    Meta::NameValue(MetaNameValue {
+                                        lit: Lit::Bool(value),
+                                        path,
+                                        ..
+                                    }) if path.is_ident(FIELD_ITEM_NULLABLE) => {
+                                        item_nullable = Some(value);
+                                    }
+                                    Meta::NameValue(MetaNameValue {
+                                        lit: Lit::Int(value),
+                                        path,
+                                        ..
+                                    }) if path.is_ident(VARIANT_TYPE_ID) => {
+                                        type_id = Some(value);
                                     }
                                     Meta::Path(path) if path.is_ident(FIELD_SKIP) => skip = true,
+                                    Meta::Path(path) if path.is_ident(FIELD_DEFAULT) => {
+                                        default = Some(path.span());
+                                    }
+                                    Meta::Path(path) if path.is_ident(FIELD_FLATTEN) => {
+                                        flatten = Some(path.span());
+                                    }
+                                    Meta::Path(path) if path.is_ident(FIELD_AS_DAYS_INT) => {
+                                        as_days_int = Some(path.span());
+                                    }
+                                    Meta::Path(path) if path.is_ident(FIELD_LARGE_LIST) => {
+                                        large_list = Some(path.span());
+                                    }
+                                    Meta::Path(path) if path.is_ident(FIELD_LARGE_BINARY) => {
+                                        large_binary = Some(path.span());
+                                    }
                                     _ => {
                                         abort!(meta.span(), "Unexpected attribute");
                                     }
@@ -161,32 +345,321 @@ impl FieldAttrs {
             }
         }
 
-        FieldAttrs { field_type, skip }
+        FieldAttrs {
+            field_type,
+            skip,
+            as_days_int,
+            large_list,
+            large_binary,
+            rename,
+            nullable,
+            item_nullable,
+            default,
+            flatten,
+            type_id,
+        }
+    }
+
+    /// Returns the type used to convert the field to arrow, taking into account any type overrides.
+    pub fn resolve_type(&self, ty: &syn::Type) -> syn::Type {
+        if let Some(span) = self.as_days_int {
+            if self.field_type.is_some() {
+                abort!(span, "'as_days_int' cannot be combined with 'type'");
+            }
+            // Preserve the nullability of the field
+            if option_inner(ty).is_some() {
+                syn::parse_quote!(Option<arrow2_convert::field::NaiveDateAsDays>)
+            } else {
+                syn::parse_quote!(arrow2_convert::field::NaiveDateAsDays)
+            }
+        } else if self.large_list.is_some() || self.large_binary.is_some() {
+            for (span, name) in [
+                (self.large_list, FIELD_LARGE_LIST),
+                (self.large_binary, FIELD_LARGE_BINARY),
+            ] {
+                if let (Some(span), true) = (span, self.field_type.is_some()) {
+                    abort!(span, "'{}' cannot be combined with 'type'", name);
+                }
+            }
+            // Preserve the nullability of the field
+            let (ty_inner, is_option) = match option_inner(ty) {
+                Some(inner) => (inner, true),
+                None => (ty, false),
+            };
+            let resolved = self.resolve_large_type(ty_inner);
+            if is_option {
+                syn::parse_quote!(Option<#resolved>)
+            } else {
+                resolved
+            }
+        } else if let Some(field_type) = &self.field_type {
+            check_override_type(field_type, ty);
+            field_type.clone()
+        } else {
+            ty.clone()
+        }
+    }
+}
+
+impl FieldAttrs {
+    /// Returns the override type of a non-optional field with `large_list` and/or `large_binary`. `large_binary`
+    /// applies to `Vec<u8>` fields, or to the items of `Vec<Vec<u8>>` fields.
+    fn resolve_large_type(&self, ty: &syn::Type) -> syn::Type {
+        if let (Some(_), None, true) = (self.large_binary, self.large_list, is_u8_vec(ty)) {
+            return syn::parse_quote!(arrow2_convert::field::LargeBinary);
+        }
+        let Some(item) = generic_inner(ty, "Vec") else {
+            match (self.large_list, self.large_binary) {
+                (Some(span), _) => abort!(span, "'large_list' is only supported on Vec<T> fields"),
+                (None, Some(span)) => abort!(
+                    span,
+                    "'large_binary' is only supported on Vec<u8> and Vec<Vec<u8>> fields"
+                ),
+                (None, None) => unreachable!(),
+            }
+        };
+        let item: syn::Type = match self.large_binary {
+            Some(span) => {
+                let (item_inner, is_option) = match option_inner(item) {
+                    Some(inner) => (inner, true),
+                    None => (item, false),
+                };
+                if !is_u8_vec(item_inner) {
+                    abort!(
+                        span,
+                        "'large_binary' is only supported on Vec<u8> and Vec<Vec<u8>> fields"
+                    );
+                }
+                if is_option {
+                    syn::parse_quote!(Option<arrow2_convert::field::LargeBinary>)
+                } else {
+                    syn::parse_quote!(arrow2_convert::field::LargeBinary)
+                }
+            }
+            None => item.clone(),
+        };
+        if self.large_list.is_some() {
+            syn::parse_quote!(arrow2_convert::field::LargeVec<#item>)
+        } else {
+            syn::parse_quote!(Vec<#item>)
+        }
+    }
+}
+
+/// Returns whether `ty` is `Vec<u8>`.
+fn is_u8_vec(ty: &syn::Type) -> bool {
+    matches!(generic_inner(ty, "Vec").and_then(last_segment), Some(segment) if segment.ident == "u8")
+}
+
+/// Returns the Rust type produced by one of the placeholder override types, for example `Vec<u8>` for `LargeBinary`.
+fn placeholder_produced_type(ident: &Ident) -> Option<(&'static str, &'static str)> {
+    match ident.to_string().as_str() {
+        "LargeBinary" | "FixedSizeBinary" | "U8List" => Some(("Vec", "Vec<u8>")),
+        "LargeString" => Some(("String", "String")),
+        "LargeVec" | "FixedSizeVec" => Some(("Vec", "Vec<_>")),
+        "I128" | "DecimalBinary" => Some(("i128", "i128")),
+        "Decimal256" => Some(("BigDecimal", "BigDecimal")),
+        "NaiveDateAsDays" | "Date64" => Some(("NaiveDate", "NaiveDate")),
+        "Timestamp" => Some(("NaiveDateTime", "NaiveDateTime")),
+        "DoubleOption" => Some(("Option", "Option<Option<_>>")),
+        _ => None,
+    }
+}
+
+/// Types that can't be aliases of the type produced by a placeholder, used to avoid false positives.
+const KNOWN_TYPES: &[&str] = &[
+    "String",
+    "Vec",
+    "Box",
+    "Buffer",
+    "HashMap",
+    "BTreeMap",
+    "NaiveDate",
+    "NaiveDateTime",
+    "bool",
+    "char",
+    "u8",
+    "u16",
+    "u32",
+    "u64",
+    "i8",
+    "i16",
+    "i32",
+    "i64",
+    "i128",
+    "f32",
+    "f64",
+];
+
+fn last_segment(ty: &syn::Type) -> Option<&syn::PathSegment> {
+    match ty {
+        syn::Type::Path(path) => path.path.segments.last(),
+        _ => None,
+    }
+}
+
+fn option_inner(ty: &syn::Type) -> Option<&syn::Type> {
+    generic_inner(ty, "Option")
+}
+
+/// Returns `T` if `ty` is the generic type `name<T>`, for example `Vec<T>`.
+fn generic_inner<'a>(ty: &'a syn::Type, name: &str) -> Option<&'a syn::Type> {
+    match last_segment(ty) {
+        Some(segment) if segment.ident == name => match &segment.arguments {
+            syn::PathArguments::AngleBracketed(args) => match args.args.first() {
+                Some(syn::GenericArgument::Type(inner)) => Some(inner),
+                _ => None,
+            },
+            _ => None,
+        },
+        _ => None,
     }
 }
 
+fn type_to_string(ty: &syn::Type) -> String {
+    quote::quote!(#ty).to_string().replace(' ', "")
+}
+
+/// Checks that a placeholder override type produces the Rust type of the field, where this can be determined
+/// syntactically. This gives a clearer error than the trait resolution failures in the generated code.
+fn check_override_type(override_type: &syn::Type, field_type: &syn::Type) {
+    let (override_inner, field_inner) =
+        match (option_inner(override_type), option_inner(field_type)) {
+            (Some(o), Some(f)) => (o, f),
+            _ => (override_type, field_type),
+        };
+    let Some((produced_ident, produced)) =
+        last_segment(override_inner).and_then(|s| placeholder_produced_type(&s.ident))
+    else {
+        return;
+    };
+    let Some(field_ident) = last_segment(field_inner).map(|s| s.ident.to_string()) else {
+        return;
+    };
+    if field_ident == produced_ident || !KNOWN_TYPES.contains(&field_ident.as_str()) {
+        return;
+    }
+
+    let produced = if std::ptr::eq(override_inner, override_type) {
+        produced.to_string()
+    } else {
+        format!("Option<{produced}>")
+    };
+    abort!(
+        override_type.span(),
+        "override type {} produces {}, but field is {}",
+        type_to_string(override_type),
+        produced,
+        type_to_string(field_type)
+    );
+}
+
 impl DeriveStruct {
     pub fn from_ast(input: &DeriveInput, ast: &syn::DataStruct) -> DeriveStruct {
         let container_attrs = ContainerAttrs::from_ast(&input.attrs);
         let common = DeriveCommon::from_ast(input, &container_attrs);
 
+        let mut fields = ast
+            .fields
+            .iter()
+            .map(DeriveField::from_ast)
+            .collect::<Vec<_>>();
+
+        // `PhantomData` fields carry no data, so they are skipped and created with `Default`
+        for field in &mut fields {
+            if matches!(last_segment(&field.syn.ty), Some(s) if s.ident == "PhantomData") {
+                field.skip = true;
+            }
+        }
+
         let is_transparent = if let Some(span) = container_attrs.transparent {
-            if ast.fields.len() > 1 {
-                abort!(span, "'transparent' is only supported on length-1 structs!");
+            if fields.iter().filter(|field| !field.skip).count() != 1 {
+                abort!(
+                    span,
+                    "'transparent' is only supported on structs with exactly one field that isn't skipped or PhantomData"
+                );
             }
             true
         } else {
             false
         };
 
+        // Transparent structs only delegate to their field, so the field's bounds can be added to the impls
+        if !is_transparent && !input.generics.params.is_empty() {
+            abort!(
+                input.generics.span(),
+                "generic parameters are only supported on structs with the 'transparent' attribute"
+            );
+        }
+
+        // Transparent structs use the mutable array of their field, which has the field's data type
+        if let (Some(name), true) = (&container_attrs.extension_name, is_transparent) {
+            abort!(
+                name.span(),
+                "'extension_name' cannot be combined with 'transparent'"
+            );
+        }
+
+        if let (Some(item_nullable), true) = (
+            fields.iter().find_map(|field| field.item_nullable.as_ref()),
+            is_transparent,
+        ) {
+            abort!(
+                item_nullable.span(),
+                "'item_nullable' cannot be combined with 'transparent'"
+            );
+        }
+
+        if let (Some(span), true) = (
+            fields
+                .iter()
+                .filter(|field| !field.skip)
+                .find_map(|field| field.flatten),
+            is_transparent,
+        ) {
+            abort!(span, "'flatten' cannot be combined with 'transparent'");
+        }
+
+        // The children compared by name are generated per field, but a flattened field has several columns
+        if let (Some(span), true) = (
+            fields
+                .iter()
+                .filter(|field| !field.skip)
+                .find_map(|field| field.flatten),
+            container_attrs.match_by_name.is_some(),
+        ) {
+            abort!(span, "'flatten' cannot be combined with 'match_by_name'");
+        }
+
+        if let (Some(span), true) = (container_attrs.match_by_name, is_transparent) {
+            abort!(
+                span,
+                "'match_by_name' cannot be combined with 'transparent'"
+            );
+        }
+
+        // Missing and unknown fields can only be detected when fields are looked up by name
+        if container_attrs.match_by_name.is_none() {
+            if let Some(span) = container_attrs.deny_unknown_fields {
+                abort!(
+                    span,
+                    "'deny_unknown_fields' requires the 'match_by_name' struct attribute"
+                );
+            }
+            if let Some(span) = fields.iter().find_map(|field| field.default) {
+                abort!(
+                    span,
+                    "'default' requires the 'match_by_name' struct attribute"
+                );
+            }
+        }
+
         DeriveStruct {
             common,
-            fields: ast
-                .fields
-                .iter()
-                .map(DeriveField::from_ast)
-                .collect::<Vec<_>>(),
+            fields,
             is_transparent,
+            match_by_name: container_attrs.match_by_name.is_some(),
+            deny_unknown_fields: container_attrs.deny_unknown_fields.is_some(),
         }
     }
 }
@@ -195,29 +668,175 @@ impl DeriveEnum {
     pub fn from_ast(input: &DeriveInput, ast: &syn::DataEnum) -> DeriveEnum {
         let container_attrs = ContainerAttrs::from_ast(&input.attrs);
         let common = DeriveCommon::from_ast(input, &container_attrs);
+        if let Some(span) = container_attrs.match_by_name {
+            abort!(span, "'match_by_name' is only supported on structs");
+        }
+        if let Some(span) = container_attrs.deny_unknown_fields {
+            abort!(span, "'deny_unknown_fields' is only supported on structs");
+        }
+        if !input.generics.params.is_empty() {
+            abort!(
+                input.generics.span(),
+                "generic parameters are not supported on enums"
+            );
+        }
+        let mut variants = ast
+            .variants
+            .iter()
+            .map(DeriveVariant::from_ast)
+            .collect::<Vec<_>>();
+
+        // Explicit discriminants are used as the type ids of variants without a `type_id`. Like rust,
+        // variants without a discriminant follow the previous variant.
+        if variants.iter().any(|v| v.syn.discriminant.is_some()) {
+            let mut discriminant = Some(0);
+            for variant in &mut variants {
+                let span = match &variant.syn.discriminant {
+                    Some((_, expr)) => {
+                        discriminant = discriminant_value(expr);
+                        expr.span()
+                    }
+                    None => variant.syn.ident.span(),
+                };
+                if variant.type_id.is_none() {
+                    match discriminant {
+                        Some(d) if (0..=127).contains(&d) => {
+                            variant.type_id = Some(syn::LitInt::new(&d.to_string(), span));
+                        }
+                        Some(d) => abort!(
+                            span,
+                            "Discriminant {} can't be used as a union type id, which must be between 0 and 127",
+                            d
+                        ),
+                        None => abort!(
+                            span,
+                            "Discriminant must be an integer literal to be used as a union type id, or 'type_id' must be set"
+                        ),
+                    }
+                }
+                discriminant = discriminant.map(|d| d + 1);
+            }
+        }
+
+        // Union type ids must fit in a non-negative i8 and be unique
+        let mut type_ids = Vec::new();
+        for (idx, variant) in variants.iter().enumerate() {
+            let (type_id, span) = match &variant.type_id {
+                Some(lit) => match lit.base10_parse::<i8>() {
+                    Ok(id) if id >= 0 => (id, lit.span()),
+                    _ => abort!(lit.span(), "'type_id' must be between 0 and 127"),
+                },
+                None => (idx as i8, variant.syn.ident.span()),
+            };
+            if type_ids.contains(&type_id) {
+                abort!(span, "Duplicate union type id {}", type_id);
+            }
+            type_ids.push(type_id);
+        }
+
+        // Only the variant can be stored in an index, so there must be no payloads
+        if container_attrs.is_index.is_some() {
+            if let Some(variant) = variants.iter().find(|v| !v.is_unit) {
+                abort!(
+                    variant.syn.span(),
+                    "enums with type \"i8\" only support unit variants"
+                );
+            }
+        }
+
+        // The tag is stored next to the payload fields, so no payload can share its name
+        if container_attrs.is_struct.is_some() {
+            if let Some(variant) = variants.iter().find(|v| {
+                !v.is_unit
+                    && match &v.rename {
+                        Some(rename) => rename.value() == "tag",
+                        None => v.syn.ident == "tag",
+                    }
+            }) {
+                abort!(
+                    variant.syn.span(),
+                    "enums with type \"struct\" can't have a payload field named `tag`"
+                );
+            }
+        }
 
         DeriveEnum {
             common,
-            variants: ast
-                .variants
-                .iter()
-                .map(DeriveVariant::from_ast)
-                .collect::<Vec<_>>(),
-            is_dense: container_attrs
-                .is_dense
-                .unwrap_or_else(|| abort!(input.span(), "Missing mode attribute for enum")),
+            variants,
+            is_index: container_attrs.is_index.is_some(),
+            is_struct: container_attrs.is_struct.is_some(),
+            // enums without a mode attribute are dense, which only stores the payload of the selected variant
+            is_dense: container_attrs.is_dense.unwrap_or(true),
         }
     }
 }
 
+/// Returns the value of an integer literal enum discriminant, which may be negative.
+fn discriminant_value(expr: &syn::Expr) -> Option<i128> {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => lit.base10_parse().ok(),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => discriminant_value(expr).map(|d| -d),
+        syn::Expr::Group(syn::ExprGroup { expr, .. })
+        | syn::Expr::Paren(syn::ExprParen { expr, .. }) => discriminant_value(expr),
+        _ => None,
+    }
+}
+
 impl DeriveField {
     pub fn from_ast(input: &syn::Field) -> DeriveField {
         let attrs = FieldAttrs::from_ast(&input.attrs);
+        if let Some(type_id) = &attrs.type_id {
+            abort!(
+                type_id.span(),
+                "'type_id' is only supported on enum variants"
+            );
+        }
+
+        // The flattened struct's own fields provide the names, nullability and types of the columns
+        if let Some(span) = attrs.flatten {
+            let conflicting = [
+                (attrs.field_type.is_some(), FIELD_TYPE),
+                (attrs.as_days_int.is_some(), FIELD_AS_DAYS_INT),
+                (attrs.large_list.is_some(), FIELD_LARGE_LIST),
+                (attrs.large_binary.is_some(), FIELD_LARGE_BINARY),
+                (attrs.rename.is_some(), FIELD_RENAME),
+                (attrs.nullable.is_some(), FIELD_NULLABLE),
+                (attrs.item_nullable.is_some(), FIELD_ITEM_NULLABLE),
+                (attrs.default.is_some(), FIELD_DEFAULT),
+            ];
+            if let Some((_, name)) = conflicting.iter().find(|(set, _)| *set) {
+                abort!(span, "'flatten' cannot be combined with '{}'", name);
+            }
+            if option_inner(&input.ty).is_some() {
+                abort!(span, "'flatten' is not supported on Option fields");
+            }
+        }
+
+        // A `None` can't be stored in a non-nullable field
+        if let Some(nullable) = &attrs.nullable {
+            if !nullable.value && option_inner(&input.ty).is_some() {
+                abort!(
+                    nullable.span(),
+                    "'nullable = false' is not supported on Option fields"
+                );
+            }
+        }
 
         DeriveField {
             syn: input.clone(),
-            field_type: attrs.field_type.unwrap_or_else(|| input.ty.clone()),
+            field_type: attrs.resolve_type(&input.ty),
             skip: attrs.skip,
+            rename: attrs.rename,
+            nullable: attrs.nullable,
+            item_nullable: attrs.item_nullable,
+            default: attrs.default,
+            flatten: attrs.flatten,
         }
     }
 }
@@ -225,6 +844,24 @@ impl DeriveField {
 impl DeriveVariant {
     pub fn from_ast(input: &syn::Variant) -> DeriveVariant {
         let attrs = FieldAttrs::from_ast(&input.attrs);
+        if let Some(nullable) = &attrs.nullable {
+            abort!(
+                nullable.span(),
+                "'nullable' is not supported on enum variants"
+            );
+        }
+        if let Some(item_nullable) = &attrs.item_nullable {
+            abort!(
+                item_nullable.span(),
+                "'item_nullable' is not supported on enum variants"
+            );
+        }
+        if let Some(span) = attrs.default {
+            abort!(span, "'default' is not supported on enum variants");
+        }
+        if let Some(span) = attrs.flatten {
+            abort!(span, "'flatten' is not supported on enum variants");
+        }
 
         let (is_unit, field_type) = match &input.fields {
             syn::Fields::Named(_f) => {
@@ -241,8 +878,10 @@ impl DeriveVariant {
         };
         DeriveVariant {
             syn: input.clone(),
-            field_type: attrs.field_type.unwrap_or_else(|| field_type.clone()),
+            field_type: attrs.resolve_type(&field_type),
             is_unit,
+            type_id: attrs.type_id,
+            rename: attrs.rename,
         }
     }
 }